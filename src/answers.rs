@@ -0,0 +1,617 @@
+use super::*;
+use armor::AvailableArmor;
+use backgrounds::AvailableBackgrounds;
+use character::{AbilityName, AbilityScoreMethod, AbilityScores, Alignment, Language, Skill, Tool};
+use classes::{AvailableClasses, AvailableSubclasses};
+use feats::AvailableFeats;
+use races::{AvailableRaces, AvailableSubraces};
+use serde::{de, Deserialize, Deserializer};
+use std::{collections::HashMap, error::Error, fmt, fs, io, path::Path};
+use util::EnumIter;
+
+/// Choices made ahead of time, so the wizard can build or level up a character without asking
+/// for them. Anything left out is asked for, or given a default when there's no one to ask.
+///
+/// Answers can be read from a TOML or JSON file, naming choices the way they're shown in the
+/// wizard. Each answer is used up when its choice comes around, so editing a page from the review
+/// page asks again. Running the whole wizard from answers with a fixed seed always builds the same
+/// character:
+///
+/// ```
+/// use dnd_character_builder::answers::Answers;
+/// use dnd_character_builder::character::{AbilityName, Skill};
+/// use dnd_character_builder::controller::App;
+///
+/// let answers = Answers::from_toml(
+///     r#"
+///     seed = 7
+///     name = "Elara Moonwhisper"
+///     race = "elf"
+///     subrace = "high-elf"
+///     class = "wizard"
+///     ability_score_method = "standard-array"
+///     ability_score_order = ["intelligence", "dexterity", "constitution", "wisdom", "charisma", "strength"]
+///     skills = ["arcana", "history"]
+///     feat = "none"
+///     "#,
+/// )
+/// .unwrap();
+///
+/// let mut app = App::new();
+/// app.set_answers(answers);
+/// app.set_interactive(false);
+/// app.run();
+///
+/// let character_sheet = app.character_sheet();
+/// assert_eq!(character_sheet.name, "Elara Moonwhisper");
+/// assert_eq!(&character_sheet["class"], "Wizard");
+/// assert_eq!(character_sheet.base_ability_scores.get(AbilityName::Intelligence), 15);
+/// assert_eq!(character_sheet.base_ability_scores.get(AbilityName::Strength), 8);
+/// assert!(character_sheet.skill_proficiencies.contains(Skill::Arcana));
+/// assert!(character_sheet.feats.is_empty());
+/// ```
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Answers {
+    /// The seed for every roll, so a scripted character can be built again exactly.
+    pub seed: Option<u64>,
+
+    pub name: Option<String>,
+
+    #[serde(deserialize_with = "choice")]
+    pub race: Option<AvailableRaces>,
+    #[serde(deserialize_with = "choice")]
+    pub subrace: Option<AvailableSubraces>,
+    /// Where to put racial ability score increases of the player's choice, such as a half-elf's.
+    #[serde(deserialize_with = "choices")]
+    pub racial_ability_increases: Vec<AbilityName>,
+    #[serde(deserialize_with = "choice")]
+    pub alignment: Option<Alignment>,
+
+    #[serde(deserialize_with = "choice")]
+    pub class: Option<AvailableClasses>,
+    #[serde(deserialize_with = "choice")]
+    pub subclass: Option<AvailableSubclasses>,
+
+    #[serde(deserialize_with = "choice")]
+    pub ability_score_method: Option<AbilityScoreMethod>,
+    /// Scores to use as they are instead of generating them. Legal point buy scores count as
+    /// bought when the method is point buy.
+    #[serde(deserialize_with = "ability_scores")]
+    pub ability_scores: Option<AbilityScores>,
+    /// The abilities to assign the generated scores to, from highest score to lowest.
+    #[serde(deserialize_with = "choices")]
+    pub ability_score_order: Vec<AbilityName>,
+
+    #[serde(deserialize_with = "choice")]
+    pub background: Option<AvailableBackgrounds>,
+    /// Tool proficiencies to take where the background offers a choice.
+    #[serde(deserialize_with = "choices")]
+    pub tools: Vec<Tool>,
+    #[serde(deserialize_with = "choices")]
+    pub languages: Vec<Language>,
+
+    /// Skill proficiencies to take wherever there's a choice, in the order they come up.
+    #[serde(deserialize_with = "choices")]
+    pub skills: Vec<Skill>,
+    #[serde(deserialize_with = "choices")]
+    pub expertise: Vec<Skill>,
+
+    pub cantrips: Vec<String>,
+    pub spells: Vec<String>,
+
+    /// The feat to take, or "none" for no feat.
+    #[serde(deserialize_with = "choice_or_none")]
+    pub feat: Option<Option<AvailableFeats>>,
+    /// The ability to increase when a feat offers a choice.
+    #[serde(deserialize_with = "choice")]
+    pub feat_ability: Option<AbilityName>,
+
+    /// Roll starting gold instead of taking the starting equipment.
+    pub starting_gold: Option<bool>,
+    /// The lettered option to take for each starting equipment choice, e.g. ["a", "b"].
+    pub equipment: Vec<char>,
+    /// Items to take where the starting equipment allows any of a group, e.g. "longsword".
+    pub items: Vec<String>,
+
+    /// The armor to wear, or "none" to go without.
+    #[serde(deserialize_with = "choice_or_none")]
+    pub armor: Option<Option<AvailableArmor>>,
+    pub shield: Option<bool>,
+
+    pub personality_traits: Vec<String>,
+    pub ideal: Option<String>,
+    pub bond: Option<String>,
+    pub flaw: Option<String>,
+    pub backstory: Option<String>,
+    pub age: Option<String>,
+    pub height: Option<String>,
+    pub weight: Option<String>,
+    pub eyes: Option<String>,
+    pub hair: Option<String>,
+    pub skin: Option<String>,
+
+    /// The class to gain a level in when levelling up.
+    #[serde(deserialize_with = "choice")]
+    pub level_up_class: Option<AvailableClasses>,
+    /// Roll for hit points on levelling up instead of taking the average.
+    pub roll_hit_points: Option<bool>,
+    /// The abilities to raise at an ability score improvement: one ability gets +2, two get +1
+    /// each.
+    #[serde(deserialize_with = "choices")]
+    pub ability_score_improvements: Vec<AbilityName>,
+}
+
+impl Answers {
+    pub fn from_toml(toml: &str) -> Result<Answers, AnswersError> {
+        return Ok(toml::from_str(toml)?);
+    }
+
+    pub fn from_json(json: &str) -> Result<Answers, AnswersError> {
+        return Ok(serde_json::from_str(json)?);
+    }
+
+    /// Read answers from a file, as JSON if it ends in ".json" and as TOML otherwise.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Answers, AnswersError> {
+        let contents = fs::read_to_string(&path)?;
+        return match path
+            .as_ref()
+            .extension()
+            .and_then(|extension| extension.to_str())
+        {
+            Some(extension) if extension.eq_ignore_ascii_case("json") => {
+                Answers::from_json(&contents)
+            }
+            _ => Answers::from_toml(&contents),
+        };
+    }
+
+    /// Fill in anything these answers leave out from another set of answers.
+    pub fn or(self, other: Answers) -> Answers {
+        return Answers {
+            seed: self.seed.or(other.seed),
+            name: self.name.or(other.name),
+            race: self.race.or(other.race),
+            subrace: self.subrace.or(other.subrace),
+            racial_ability_increases: list(
+                self.racial_ability_increases,
+                other.racial_ability_increases,
+            ),
+            alignment: self.alignment.or(other.alignment),
+            class: self.class.or(other.class),
+            subclass: self.subclass.or(other.subclass),
+            ability_score_method: self.ability_score_method.or(other.ability_score_method),
+            ability_scores: self.ability_scores.or(other.ability_scores),
+            ability_score_order: list(self.ability_score_order, other.ability_score_order),
+            background: self.background.or(other.background),
+            tools: list(self.tools, other.tools),
+            languages: list(self.languages, other.languages),
+            skills: list(self.skills, other.skills),
+            expertise: list(self.expertise, other.expertise),
+            cantrips: list(self.cantrips, other.cantrips),
+            spells: list(self.spells, other.spells),
+            feat: self.feat.or(other.feat),
+            feat_ability: self.feat_ability.or(other.feat_ability),
+            starting_gold: self.starting_gold.or(other.starting_gold),
+            equipment: list(self.equipment, other.equipment),
+            items: list(self.items, other.items),
+            armor: self.armor.or(other.armor),
+            shield: self.shield.or(other.shield),
+            personality_traits: list(self.personality_traits, other.personality_traits),
+            ideal: self.ideal.or(other.ideal),
+            bond: self.bond.or(other.bond),
+            flaw: self.flaw.or(other.flaw),
+            backstory: self.backstory.or(other.backstory),
+            age: self.age.or(other.age),
+            height: self.height.or(other.height),
+            weight: self.weight.or(other.weight),
+            eyes: self.eyes.or(other.eyes),
+            hair: self.hair.or(other.hair),
+            skin: self.skin.or(other.skin),
+            level_up_class: self.level_up_class.or(other.level_up_class),
+            roll_hit_points: self.roll_hit_points.or(other.roll_hit_points),
+            ability_score_improvements: list(
+                self.ability_score_improvements,
+                other.ability_score_improvements,
+            ),
+        };
+    }
+
+    /// Check for answers that can't all be used together, which the wizard would otherwise
+    /// quietly ask about again.
+    pub fn check(&self) -> Result<(), String> {
+        if let (Some(race), Some(subrace)) = (self.race, self.subrace) {
+            if !race.get_subraces().contains(&subrace) {
+                return Err(format!("{} isn't a subrace of {}", subrace, race));
+            }
+        }
+        if let (Some(class), Some(subclass)) = (self.class, self.subclass) {
+            if subclass.get_class() != class {
+                return Err(format!("{} isn't a {} subclass", subclass, class));
+            }
+        }
+        if self.ability_scores.is_some() && !self.ability_score_order.is_empty() {
+            return Err("give either ability scores or an order to assign them in".to_string());
+        }
+        let order = &self.ability_score_order;
+        let abilities = AbilityName::iter();
+        if !order.is_empty()
+            && (order.len() != abilities.len()
+                || abilities.iter().any(|ability| !order.contains(ability)))
+        {
+            return Err(format!(
+                "the ability score order must name each of the {} abilities once",
+                abilities.len()
+            ));
+        }
+        return Ok(());
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(io::Error),
+    Toml(toml::de::Error),
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnswersError::Io(error) => write!(f, "{}", error),
+            AnswersError::Toml(error) => write!(f, "invalid answers file: {}", error),
+            AnswersError::Json(error) => write!(f, "invalid answers file: {}", error),
+        }
+    }
+}
+
+impl Error for AnswersError {}
+
+impl From<io::Error> for AnswersError {
+    fn from(error: io::Error) -> Self {
+        return AnswersError::Io(error);
+    }
+}
+
+impl From<toml::de::Error> for AnswersError {
+    fn from(error: toml::de::Error) -> Self {
+        return AnswersError::Toml(error);
+    }
+}
+
+impl From<serde_json::Error> for AnswersError {
+    fn from(error: serde_json::Error) -> Self {
+        return AnswersError::Json(error);
+    }
+}
+
+fn list<T>(answers: Vec<T>, other: Vec<T>) -> Vec<T> {
+    return match answers.is_empty() {
+        true => other,
+        false => answers,
+    };
+}
+
+// Answers files name their choices the way players would, e.g. "half-elf" rather than "HalfElf".
+
+fn choice<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: EnumIter + fmt::Debug + fmt::Display,
+{
+    return match Option::<String>::deserialize(deserializer)? {
+        Some(value) => parse_choice("value", &value)
+            .map(Some)
+            .map_err(de::Error::custom),
+        None => Ok(None),
+    };
+}
+
+fn choices<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: EnumIter + fmt::Debug + fmt::Display,
+{
+    return Vec::<String>::deserialize(deserializer)?
+        .iter()
+        .map(|value| parse_choice("value", value).map_err(de::Error::custom))
+        .collect();
+}
+
+/// A choice that can also be "none", which is an answer in itself.
+fn choice_or_none<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: EnumIter + fmt::Debug + fmt::Display,
+{
+    return match Option::<String>::deserialize(deserializer)? {
+        Some(value) if normalize(&value) == "none" => Ok(Some(None)),
+        Some(value) => parse_choice("value", &value)
+            .map(|choice| Some(Some(choice)))
+            .map_err(de::Error::custom),
+        None => Ok(None),
+    };
+}
+
+fn ability_scores<'de, D>(deserializer: D) -> Result<Option<AbilityScores>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let scores = match Option::<HashMap<String, i8>>::deserialize(deserializer)? {
+        Some(scores) => scores,
+        None => return Ok(None),
+    };
+    let mut ability_scores = AbilityScores::default();
+    for (ability, score) in scores {
+        let ability = parse_choice("ability", &ability).map_err(de::Error::custom)?;
+        if !(3..=20).contains(&score) {
+            return Err(de::Error::custom(format!(
+                "{} of {} is outside 3 to 20",
+                ability, score
+            )));
+        }
+        ability_scores.set(ability, score);
+    }
+    return Ok(Some(ability_scores));
+}
+
+/// A value that doesn't name any of the options for a choice.
+#[derive(Debug, PartialEq, Eq)]
+pub struct InvalidChoice {
+    pub kind: &'static str,
+    pub value: String,
+    pub options: Vec<String>,
+}
+
+impl fmt::Display for InvalidChoice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "unknown {} {:?}; expected one of {}",
+            self.kind,
+            self.value,
+            self.options.join(", ")
+        )
+    }
+}
+
+impl Error for InvalidChoice {}
+
+/// Find the option a player named, ignoring case, spaces, hyphens and underscores, so that
+/// "half-elf", "Half Elf" and "HalfElf" all name the same race.
+///
+/// ```
+/// use dnd_character_builder::answers::parse_choice;
+/// use dnd_character_builder::races::AvailableRaces;
+///
+/// assert_eq!(parse_choice("race", "half elf"), Ok(AvailableRaces::HalfElf));
+/// assert!(parse_choice::<AvailableRaces>("race", "orc").is_err());
+/// ```
+pub fn parse_choice<T: EnumIter + fmt::Debug + fmt::Display>(
+    kind: &'static str,
+    value: &str,
+) -> Result<T, InvalidChoice> {
+    let wanted = normalize(value);
+    let options = T::iter();
+    let names = options
+        .iter()
+        .map(|option| option.to_string().to_lowercase())
+        .collect::<Vec<String>>();
+    return match options
+        .into_iter()
+        .find(|option| normalize(&format!("{:?}", option)) == wanted)
+    {
+        Some(option) => Ok(option),
+        None => Err(InvalidChoice {
+            kind,
+            value: value.to_string(),
+            options: names,
+        }),
+    };
+}
+
+/// Reduce a name to lowercase letters and digits, so names can be compared however they're
+/// written.
+pub fn normalize(name: &str) -> String {
+    return name
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(|c| c.to_lowercase())
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use character::{CharacterSheet, Proficiencies, Source};
+    use controller::App;
+
+    fn run(answers: Answers) -> CharacterSheet {
+        answers.check().unwrap();
+        let mut app = App::with_seed(1);
+        app.set_answers(answers);
+        app.set_interactive(false);
+        app.run();
+        return app.character_sheet().clone();
+    }
+
+    fn source(skills: &Proficiencies<Skill>, skill: Skill) -> Option<Source> {
+        return skills
+            .iter()
+            .find(|proficiency| proficiency.value == skill)
+            .map(|proficiency| proficiency.source);
+    }
+
+    fn scores(character_sheet: &CharacterSheet) -> Vec<(AbilityName, i8)> {
+        return character_sheet.ability_scores.get_sorted();
+    }
+
+    #[test]
+    fn example_answers_file() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples/answers.toml");
+        let character_sheet = run(Answers::load(path).unwrap());
+
+        assert_eq!(character_sheet.name, "Elara Moonwhisper");
+        assert_eq!(character_sheet.race, AvailableRaces::Elf);
+        assert_eq!(character_sheet.subrace, Some(AvailableSubraces::HighElf));
+        assert_eq!(character_sheet.get_class(), AvailableClasses::Wizard);
+        assert_eq!(character_sheet.get_level(), 1);
+        assert_eq!(character_sheet.background, AvailableBackgrounds::Sage);
+
+        // the standard array, plus 2 Dexterity for elves and 1 Intelligence for high elves
+        assert_eq!(
+            scores(&character_sheet),
+            vec![
+                (AbilityName::Strength, 8),
+                (AbilityName::Dexterity, 16),
+                (AbilityName::Constitution, 13),
+                (AbilityName::Intelligence, 16),
+                (AbilityName::Wisdom, 12),
+                (AbilityName::Charisma, 10),
+            ]
+        );
+
+        let skills = &character_sheet.skill_proficiencies;
+        for skill in [
+            Skill::Perception,
+            Skill::Arcana,
+            Skill::History,
+            Skill::Investigation,
+            Skill::Insight,
+        ] {
+            assert!(skills.contains(skill), "{} is missing", skill);
+        }
+        assert_eq!(skills.values().len(), 5);
+        let saves = &character_sheet.saving_throw_proficiencies;
+        assert!(saves.contains(AbilityName::Intelligence));
+        assert!(saves.contains(AbilityName::Wisdom));
+        for language in [Language::Draconic, Language::Dwarvish, Language::Giant] {
+            assert!(character_sheet.languages.contains(language));
+        }
+
+        // a d6 hit die at 1st level, plus 1 for Constitution 13
+        assert_eq!(character_sheet.get_max_hit_points(), 7);
+        assert!(character_sheet.feats.is_empty());
+    }
+
+    #[test]
+    fn json_answers() {
+        let answers = Answers::from_json(
+            r#"{
+                "name": "Bo",
+                "race": "human",
+                "subrace": "variant-human",
+                "racial_ability_increases": ["strength", "constitution"],
+                "alignment": "neutral-good",
+                "class": "fighter",
+                "ability_score_method": "point-buy",
+                "ability_scores": {
+                    "strength": 15,
+                    "dexterity": 13,
+                    "constitution": 14,
+                    "intelligence": 8,
+                    "wisdom": 12,
+                    "charisma": 10
+                },
+                "background": "soldier",
+                "skills": ["perception", "acrobatics", "survival"],
+                "feat": "tough",
+                "armor": "chain-mail",
+                "shield": true
+            }"#,
+        )
+        .unwrap();
+        let character_sheet = run(answers);
+
+        assert_eq!(character_sheet.race, AvailableRaces::Human);
+        assert_eq!(
+            character_sheet.subrace,
+            Some(AvailableSubraces::VariantHuman)
+        );
+        assert_eq!(character_sheet.alignment, Alignment::NeutralGood);
+        assert_eq!(character_sheet.get_class(), AvailableClasses::Fighter);
+        assert!(character_sheet.point_buy);
+        assert_eq!(
+            scores(&character_sheet),
+            vec![
+                (AbilityName::Strength, 16),
+                (AbilityName::Dexterity, 13),
+                (AbilityName::Constitution, 15),
+                (AbilityName::Intelligence, 8),
+                (AbilityName::Wisdom, 12),
+                (AbilityName::Charisma, 10),
+            ]
+        );
+
+        // a variant human's skill, the soldier's skills and the fighter's picks
+        let skills = &character_sheet.skill_proficiencies;
+        assert_eq!(source(skills, Skill::Perception), Some(Source::Race));
+        assert_eq!(source(skills, Skill::Athletics), Some(Source::Background));
+        assert_eq!(
+            source(skills, Skill::Intimidation),
+            Some(Source::Background)
+        );
+        assert_eq!(source(skills, Skill::Acrobatics), Some(Source::Class));
+        assert_eq!(source(skills, Skill::Survival), Some(Source::Class));
+        assert_eq!(skills.values().len(), 5);
+        let saves = &character_sheet.saving_throw_proficiencies;
+        assert!(saves.contains(AbilityName::Strength));
+        assert!(saves.contains(AbilityName::Constitution));
+
+        assert_eq!(character_sheet.feats, vec![AvailableFeats::Tough]);
+        assert_eq!(character_sheet.starting_feat, Some(AvailableFeats::Tough));
+        // a d10 hit die at 1st level, plus 2 for Constitution 15 and 2 for Tough
+        assert_eq!(character_sheet.get_max_hit_points(), 14);
+        assert_eq!(character_sheet.armor, Some(AvailableArmor::ChainMail));
+        assert!(character_sheet.shield);
+    }
+
+    #[test]
+    fn toml_and_json_answers_match() {
+        let toml = Answers::from_toml(
+            r#"
+            name = "Wren"
+            race = "halfling"
+            subrace = "lightfoot-halfling"
+            class = "rogue"
+            ability_score_method = "standard-array"
+            ability_score_order = ["dexterity", "intelligence", "constitution", "wisdom", "charisma", "strength"]
+            background = "urchin"
+            "#,
+        )
+        .unwrap();
+        let json = Answers::from_json(
+            r#"{
+                "name": "Wren",
+                "race": "Halfling",
+                "subrace": "Lightfoot Halfling",
+                "class": "Rogue",
+                "ability_score_method": "StandardArray",
+                "ability_score_order": ["Dexterity", "Intelligence", "Constitution", "Wisdom", "Charisma", "Strength"],
+                "background": "Urchin"
+            }"#,
+        )
+        .unwrap();
+        let toml = run(toml);
+        let json = run(json);
+
+        assert_eq!(toml.subrace, Some(AvailableSubraces::LightfootHalfling));
+        assert_eq!(json.subrace, toml.subrace);
+        assert_eq!(scores(&json), scores(&toml));
+        assert_eq!(toml.ability_scores.get(AbilityName::Dexterity), 17);
+        assert_eq!(
+            json.skill_proficiencies.values(),
+            toml.skill_proficiencies.values()
+        );
+        assert_eq!(json.get_max_hit_points(), toml.get_max_hit_points());
+    }
+
+    #[test]
+    fn rejects_unknown_answers() {
+        assert!(Answers::from_toml("colour = \"blue\"").is_err());
+        assert!(Answers::from_toml("race = \"wookiee\"").is_err());
+        assert!(Answers::from_json(r#"{"ability_scores": {"strength": 21}}"#).is_err());
+        let answers = Answers::from_toml("race = \"elf\"\nsubrace = \"hill-dwarf\"").unwrap();
+        assert!(answers.check().is_err());
+    }
+}
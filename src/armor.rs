@@ -0,0 +1,233 @@
+use super::*;
+use character::{AbilityName, ArmorCategory, CharacterSheet};
+use classes::AvailableClasses;
+use enum_iterator::{all, Sequence};
+use feats::AvailableFeats;
+use serde::{Deserialize, Serialize};
+use std::{cmp, fmt};
+use util::{EnumIter, EnumString};
+
+/// The bonus to AC granted by carrying a shield.
+pub const SHIELD_BONUS: i8 = 2;
+
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Sequence, EnumIter, EnumString, Serialize, Deserialize,
+)]
+pub enum AvailableArmor {
+    Padded,
+    Leather,
+    StuddedLeather,
+    Hide,
+    ChainShirt,
+    ScaleMail,
+    Breastplate,
+    HalfPlate,
+    RingMail,
+    ChainMail,
+    Splint,
+    Plate,
+}
+
+impl AvailableArmor {
+    pub fn get_category(&self) -> ArmorCategory {
+        match *self {
+            AvailableArmor::Padded | AvailableArmor::Leather | AvailableArmor::StuddedLeather => {
+                ArmorCategory::Light
+            }
+            AvailableArmor::Hide
+            | AvailableArmor::ChainShirt
+            | AvailableArmor::ScaleMail
+            | AvailableArmor::Breastplate
+            | AvailableArmor::HalfPlate => ArmorCategory::Medium,
+            AvailableArmor::RingMail
+            | AvailableArmor::ChainMail
+            | AvailableArmor::Splint
+            | AvailableArmor::Plate => ArmorCategory::Heavy,
+        }
+    }
+
+    pub fn get_base_armor_class(&self) -> i8 {
+        match *self {
+            AvailableArmor::Padded | AvailableArmor::Leather => 11,
+            AvailableArmor::StuddedLeather | AvailableArmor::Hide => 12,
+            AvailableArmor::ChainShirt => 13,
+            AvailableArmor::ScaleMail | AvailableArmor::Breastplate | AvailableArmor::RingMail => {
+                14
+            }
+            AvailableArmor::HalfPlate => 15,
+            AvailableArmor::ChainMail => 16,
+            AvailableArmor::Splint => 17,
+            AvailableArmor::Plate => 18,
+        }
+    }
+
+    /// The most Dexterity modifier this armor lets through: unlimited for light armor, 2 for
+    /// medium armor and none at all for heavy armor.
+    pub fn get_max_dexterity_bonus(&self) -> Option<i8> {
+        match self.get_category() {
+            ArmorCategory::Medium => Some(2),
+            ArmorCategory::Heavy => Some(0),
+            _ => None,
+        }
+    }
+
+    /// The Strength score needed to move at full speed in this armor.
+    pub fn get_strength_requirement(&self) -> Option<i8> {
+        match *self {
+            AvailableArmor::ChainMail => Some(13),
+            AvailableArmor::Splint | AvailableArmor::Plate => Some(15),
+            _ => None,
+        }
+    }
+
+    pub fn has_stealth_disadvantage(&self) -> bool {
+        return matches!(
+            *self,
+            AvailableArmor::Padded
+                | AvailableArmor::ScaleMail
+                | AvailableArmor::HalfPlate
+                | AvailableArmor::RingMail
+                | AvailableArmor::ChainMail
+                | AvailableArmor::Splint
+                | AvailableArmor::Plate
+        );
+    }
+}
+
+/// A computed armor class along with where each point of it comes from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArmorClass {
+    pub total: i8,
+    pub breakdown: Vec<(String, i8)>,
+}
+
+impl fmt::Display for ArmorClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let breakdown = self
+            .breakdown
+            .iter()
+            .map(|(source, value)| format!("{} {}", source, value))
+            .collect::<Vec<String>>()
+            .join(" + ");
+        write!(f, "{} ({})", self.total, breakdown)
+    }
+}
+
+impl ArmorClass {
+    fn new(breakdown: Vec<(String, i8)>) -> ArmorClass {
+        let total = breakdown.iter().map(|(_, value)| value).sum();
+        return ArmorClass { total, breakdown };
+    }
+}
+
+/// Work out a character's armor class from their equipped armor and shield, their ability
+/// scores and any unarmored defense from their class. When several calculations apply, the best
+/// one is used.
+pub fn get_armor_class(character_sheet: &CharacterSheet) -> ArmorClass {
+    let ability_scores = &character_sheet.ability_scores;
+    let dexterity = ability_scores.get_modifier(AbilityName::Dexterity);
+    let shield = character_sheet.shield;
+
+    let mut breakdown = match character_sheet.armor {
+        Some(armor) => {
+            let mut breakdown = vec![(armor.to_string(), armor.get_base_armor_class())];
+            let dexterity = match armor.get_max_dexterity_bonus() {
+                // medium armor masters wear their armor more nimbly
+                Some(_)
+                    if armor.get_category() == ArmorCategory::Medium
+                        && character_sheet
+                            .feats
+                            .contains(&AvailableFeats::MediumArmorMaster) =>
+                {
+                    cmp::min(dexterity, 3)
+                }
+                Some(max) => cmp::min(dexterity, max),
+                None => dexterity,
+            };
+            if armor.get_category() != ArmorCategory::Heavy {
+                breakdown.push(("Dexterity".to_string(), dexterity));
+            }
+            breakdown
+        }
+        None => {
+            let unarmored = vec![
+                ("Base".to_string(), 10),
+                ("Dexterity".to_string(), dexterity),
+            ];
+            let mut options = vec![unarmored.clone()];
+            if character_sheet.get_class_level(AvailableClasses::Barbarian) > 0 {
+                let mut option = unarmored.clone();
+                option.push((
+                    "Constitution".to_string(),
+                    ability_scores.get_modifier(AbilityName::Constitution),
+                ));
+                options.push(option);
+            }
+            // a monk loses unarmored defense when carrying a shield
+            if character_sheet.get_class_level(AvailableClasses::Monk) > 0 && !shield {
+                let mut option = unarmored;
+                option.push((
+                    "Wisdom".to_string(),
+                    ability_scores.get_modifier(AbilityName::Wisdom),
+                ));
+                options.push(option);
+            }
+            options
+                .into_iter()
+                .max_by_key(|option| option.iter().map(|(_, value)| value).sum::<i8>())
+                .unwrap()
+        }
+    };
+
+    if shield {
+        breakdown.push(("Shield".to_string(), SHIELD_BONUS));
+    }
+
+    return ArmorClass::new(breakdown);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wearing(armor: AvailableArmor, dexterity: i8) -> CharacterSheet {
+        let mut character_sheet = CharacterSheet::new();
+        character_sheet.armor = Some(armor);
+        character_sheet
+            .ability_scores
+            .set(AbilityName::Dexterity, dexterity);
+        return character_sheet;
+    }
+
+    #[test]
+    fn medium_armor_caps_dexterity_at_two() {
+        let character_sheet = wearing(AvailableArmor::HalfPlate, 18);
+        assert_eq!(get_armor_class(&character_sheet).total, 17);
+    }
+
+    #[test]
+    fn medium_armor_master_caps_dexterity_at_three() {
+        let mut character_sheet = wearing(AvailableArmor::HalfPlate, 18);
+        character_sheet
+            .feats
+            .push(AvailableFeats::MediumArmorMaster);
+        assert_eq!(get_armor_class(&character_sheet).total, 18);
+
+        // the feat does nothing for a lower Dexterity, or for other armor
+        let mut character_sheet = wearing(AvailableArmor::HalfPlate, 14);
+        character_sheet
+            .feats
+            .push(AvailableFeats::MediumArmorMaster);
+        assert_eq!(get_armor_class(&character_sheet).total, 17);
+        let mut character_sheet = wearing(AvailableArmor::Leather, 18);
+        character_sheet
+            .feats
+            .push(AvailableFeats::MediumArmorMaster);
+        assert_eq!(get_armor_class(&character_sheet).total, 15);
+        let mut character_sheet = wearing(AvailableArmor::Plate, 18);
+        character_sheet
+            .feats
+            .push(AvailableFeats::MediumArmorMaster);
+        assert_eq!(get_armor_class(&character_sheet).total, 18);
+    }
+}
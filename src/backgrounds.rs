@@ -1,3 +1,13 @@
+use super::*;
+use character::*;
+use enum_iterator::{all, Sequence};
+use equipment::{ItemGroup, StartingItem, Weapon};
+use serde::{Deserialize, Serialize};
+use util::{EnumIter, EnumString};
+
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Sequence, EnumIter, EnumString, Serialize, Deserialize,
+)]
 pub enum AvailableBackgrounds {
     Acolyte,
     Charlatan,
@@ -13,3 +23,526 @@ pub enum AvailableBackgrounds {
     Soldier,
     Urchin,
 }
+
+impl AvailableBackgrounds {
+    pub fn get_skill_proficiencies(&self) -> Vec<Skill> {
+        match *self {
+            AvailableBackgrounds::Acolyte => vec![Skill::Insight, Skill::Religion],
+            AvailableBackgrounds::Charlatan => vec![Skill::Deception, Skill::SleightOfHand],
+            AvailableBackgrounds::Criminal => vec![Skill::Deception, Skill::Stealth],
+            AvailableBackgrounds::Entertainer => vec![Skill::Acrobatics, Skill::Performance],
+            AvailableBackgrounds::FolkHero => vec![Skill::AnimalHandling, Skill::Survival],
+            AvailableBackgrounds::GuildArtisan => vec![Skill::Insight, Skill::Persuasion],
+            AvailableBackgrounds::Hermit => vec![Skill::Medicine, Skill::Religion],
+            AvailableBackgrounds::Noble => vec![Skill::History, Skill::Persuasion],
+            AvailableBackgrounds::Outlander => vec![Skill::Athletics, Skill::Survival],
+            AvailableBackgrounds::Sage => vec![Skill::Arcana, Skill::History],
+            AvailableBackgrounds::Sailor => vec![Skill::Athletics, Skill::Perception],
+            AvailableBackgrounds::Soldier => vec![Skill::Athletics, Skill::Intimidation],
+            AvailableBackgrounds::Urchin => vec![Skill::SleightOfHand, Skill::Stealth],
+        }
+    }
+
+    /// Each entry is a single tool proficiency. When an entry has more than one option the
+    /// player chooses one of them (e.g. "one type of gaming set").
+    pub fn get_tool_proficiencies(&self) -> Vec<Vec<Tool>> {
+        match *self {
+            AvailableBackgrounds::Acolyte => vec![],
+            AvailableBackgrounds::Charlatan => {
+                vec![vec![Tool::DisguiseKit], vec![Tool::ForgeryKit]]
+            }
+            AvailableBackgrounds::Criminal => vec![Tool::gaming_sets(), vec![Tool::ThievesTools]],
+            AvailableBackgrounds::Entertainer => {
+                vec![vec![Tool::DisguiseKit], Tool::musical_instruments()]
+            }
+            AvailableBackgrounds::FolkHero => {
+                vec![Tool::artisans_tools(), vec![Tool::VehiclesLand]]
+            }
+            AvailableBackgrounds::GuildArtisan => vec![Tool::artisans_tools()],
+            AvailableBackgrounds::Hermit => vec![vec![Tool::HerbalismKit]],
+            AvailableBackgrounds::Noble => vec![Tool::gaming_sets()],
+            AvailableBackgrounds::Outlander => vec![Tool::musical_instruments()],
+            AvailableBackgrounds::Sage => vec![],
+            AvailableBackgrounds::Sailor => {
+                vec![vec![Tool::NavigatorsTools], vec![Tool::VehiclesWater]]
+            }
+            AvailableBackgrounds::Soldier => vec![Tool::gaming_sets(), vec![Tool::VehiclesLand]],
+            AvailableBackgrounds::Urchin => {
+                vec![vec![Tool::DisguiseKit], vec![Tool::ThievesTools]]
+            }
+        }
+    }
+
+    /// The number of languages of the player's choice granted by the background.
+    pub fn get_language_count(&self) -> usize {
+        match *self {
+            AvailableBackgrounds::Acolyte | AvailableBackgrounds::Sage => 2,
+            AvailableBackgrounds::GuildArtisan
+            | AvailableBackgrounds::Hermit
+            | AvailableBackgrounds::Noble
+            | AvailableBackgrounds::Outlander => 1,
+            _ => 0,
+        }
+    }
+
+    pub fn get_equipment(&self) -> Vec<StartingItem> {
+        match *self {
+            AvailableBackgrounds::Acolyte => vec![
+                StartingItem::gear("Holy symbol", 1),
+                StartingItem::gear("Prayer book", 1),
+                StartingItem::gear("Stick of incense", 5),
+                StartingItem::gear("Vestments", 1),
+                StartingItem::gear("Common clothes", 1),
+                StartingItem::gear("Pouch", 1),
+            ],
+            AvailableBackgrounds::Charlatan => vec![
+                StartingItem::gear("Fine clothes", 1),
+                StartingItem::tool(Tool::DisguiseKit),
+                StartingItem::gear("Tools of the con", 1),
+                StartingItem::gear("Pouch", 1),
+            ],
+            AvailableBackgrounds::Criminal => vec![
+                StartingItem::gear("Crowbar", 1),
+                StartingItem::gear("Dark common clothes with hood", 1),
+                StartingItem::gear("Pouch", 1),
+            ],
+            AvailableBackgrounds::Entertainer => vec![
+                StartingItem::any(ItemGroup::MusicalInstrument, 1),
+                StartingItem::gear("Favor of an admirer", 1),
+                StartingItem::gear("Costume", 1),
+                StartingItem::gear("Pouch", 1),
+            ],
+            AvailableBackgrounds::FolkHero => vec![
+                StartingItem::any(ItemGroup::ArtisansTools, 1),
+                StartingItem::gear("Shovel", 1),
+                StartingItem::gear("Iron pot", 1),
+                StartingItem::gear("Common clothes", 1),
+                StartingItem::gear("Pouch", 1),
+            ],
+            AvailableBackgrounds::GuildArtisan => vec![
+                StartingItem::any(ItemGroup::ArtisansTools, 1),
+                StartingItem::gear("Letter of introduction from your guild", 1),
+                StartingItem::gear("Traveler's clothes", 1),
+                StartingItem::gear("Pouch", 1),
+            ],
+            AvailableBackgrounds::Hermit => vec![
+                StartingItem::gear("Scroll case stuffed with notes", 1),
+                StartingItem::gear("Winter blanket", 1),
+                StartingItem::gear("Common clothes", 1),
+                StartingItem::tool(Tool::HerbalismKit),
+            ],
+            AvailableBackgrounds::Noble => vec![
+                StartingItem::gear("Fine clothes", 1),
+                StartingItem::gear("Signet ring", 1),
+                StartingItem::gear("Scroll of pedigree", 1),
+                StartingItem::gear("Purse", 1),
+            ],
+            AvailableBackgrounds::Outlander => vec![
+                StartingItem::weapon(Weapon::Quarterstaff, 1),
+                StartingItem::gear("Hunting trap", 1),
+                StartingItem::gear("Trophy from an animal you killed", 1),
+                StartingItem::gear("Traveler's clothes", 1),
+                StartingItem::gear("Pouch", 1),
+            ],
+            AvailableBackgrounds::Sage => vec![
+                StartingItem::gear("Bottle of black ink", 1),
+                StartingItem::gear("Quill", 1),
+                StartingItem::gear("Small knife", 1),
+                StartingItem::gear("Letter from a dead colleague", 1),
+                StartingItem::gear("Common clothes", 1),
+                StartingItem::gear("Pouch", 1),
+            ],
+            AvailableBackgrounds::Sailor => vec![
+                StartingItem::weapon(Weapon::Club, 1),
+                StartingItem::gear("Silk rope (50 feet)", 1),
+                StartingItem::gear("Lucky charm", 1),
+                StartingItem::gear("Common clothes", 1),
+                StartingItem::gear("Pouch", 1),
+            ],
+            AvailableBackgrounds::Soldier => vec![
+                StartingItem::gear("Insignia of rank", 1),
+                StartingItem::gear("Trophy from a fallen enemy", 1),
+                StartingItem::tool(Tool::DiceSet),
+                StartingItem::gear("Common clothes", 1),
+                StartingItem::gear("Pouch", 1),
+            ],
+            AvailableBackgrounds::Urchin => vec![
+                StartingItem::gear("Small knife", 1),
+                StartingItem::gear("Map of the city you grew up in", 1),
+                StartingItem::gear("Pet mouse", 1),
+                StartingItem::gear("Token to remember your parents by", 1),
+                StartingItem::gear("Common clothes", 1),
+                StartingItem::gear("Pouch", 1),
+            ],
+        }
+    }
+
+    /// The gold pieces the background's equipment comes with.
+    pub fn get_starting_gold(&self) -> u32 {
+        match *self {
+            AvailableBackgrounds::Noble => 25,
+            AvailableBackgrounds::Acolyte
+            | AvailableBackgrounds::Charlatan
+            | AvailableBackgrounds::Criminal
+            | AvailableBackgrounds::Entertainer
+            | AvailableBackgrounds::GuildArtisan => 15,
+            AvailableBackgrounds::FolkHero
+            | AvailableBackgrounds::Outlander
+            | AvailableBackgrounds::Sage
+            | AvailableBackgrounds::Sailor
+            | AvailableBackgrounds::Soldier
+            | AvailableBackgrounds::Urchin => 10,
+            AvailableBackgrounds::Hermit => 5,
+        }
+    }
+
+    pub fn get_feature(&self) -> Feature {
+        match *self {
+            AvailableBackgrounds::Acolyte => Feature::new(
+                "Shelter of the Faithful",
+                "You and your companions can expect free healing and care at a temple, shrine or other established presence of your faith.",
+            ),
+            AvailableBackgrounds::Charlatan => Feature::new(
+                "False Identity",
+                "You have created a second identity that includes documentation, established acquaintances and disguises.",
+            ),
+            AvailableBackgrounds::Criminal => Feature::new(
+                "Criminal Contact",
+                "You have a reliable and trustworthy contact who acts as your liaison to a network of other criminals.",
+            ),
+            AvailableBackgrounds::Entertainer => Feature::new(
+                "By Popular Demand",
+                "You can always find a place to perform, receiving free lodging and food of a modest or comfortable standard.",
+            ),
+            AvailableBackgrounds::FolkHero => Feature::new(
+                "Rustic Hospitality",
+                "Common folk will shelter you and hide you from the law or anyone else searching for you.",
+            ),
+            AvailableBackgrounds::GuildArtisan => Feature::new(
+                "Guild Membership",
+                "Your guild offers you lodging and food if necessary, and will support you in legal matters.",
+            ),
+            AvailableBackgrounds::Hermit => Feature::new(
+                "Discovery",
+                "The quiet seclusion of your extended hermitage gave you access to a unique and powerful discovery.",
+            ),
+            AvailableBackgrounds::Noble => Feature::new(
+                "Position of Privilege",
+                "You are welcome in high society, and people assume you have the right to be wherever you are.",
+            ),
+            AvailableBackgrounds::Outlander => Feature::new(
+                "Wanderer",
+                "You have an excellent memory for maps and geography, and can find food and fresh water for up to five people each day.",
+            ),
+            AvailableBackgrounds::Sage => Feature::new(
+                "Researcher",
+                "When you don't know a piece of lore, you often know where and from whom you can obtain it.",
+            ),
+            AvailableBackgrounds::Sailor => Feature::new(
+                "Ship's Passage",
+                "You can secure free passage on a sailing ship for yourself and your companions.",
+            ),
+            AvailableBackgrounds::Soldier => Feature::new(
+                "Military Rank",
+                "Soldiers loyal to your former military organization still recognize your authority and influence.",
+            ),
+            AvailableBackgrounds::Urchin => Feature::new(
+                "City Secrets",
+                "You know the secret patterns and flow of cities, and can travel between any two locations in a city twice as fast.",
+            ),
+        }
+    }
+
+    pub fn get_personality_traits(&self) -> Vec<&'static str> {
+        match *self {
+            AvailableBackgrounds::Acolyte => vec![
+                "I quote sacred texts in almost every situation.",
+                "I am tolerant of other faiths and respect their rites.",
+                "I see omens in every event and action.",
+                "Nothing can shake my optimistic attitude.",
+            ],
+            AvailableBackgrounds::Charlatan => vec![
+                "I fall in and out of love easily, and am always pursuing someone.",
+                "I have a joke for every occasion, especially the wrong ones.",
+                "Flattery is my preferred trick for getting what I want.",
+                "I lie about almost everything, even when there's no reason to.",
+            ],
+            AvailableBackgrounds::Criminal => vec![
+                "I always have a plan for what to do when things go wrong.",
+                "I am always calm, no matter the situation.",
+                "The first thing I do in a new place is note the exits.",
+                "I would rather make a new friend than a new enemy.",
+            ],
+            AvailableBackgrounds::Entertainer => vec![
+                "I know a story relevant to almost every situation.",
+                "Whenever I come to a new place, I collect local rumors and songs.",
+                "I love a good insult, even one directed at me.",
+                "I get bitter if I'm not the center of attention.",
+            ],
+            AvailableBackgrounds::FolkHero => vec![
+                "I judge people by their actions, not their words.",
+                "If someone is in trouble, I'm always ready to lend help.",
+                "I have a strong sense of fair play.",
+                "I'm confident in my own abilities and do what I can to instill confidence in others.",
+            ],
+            AvailableBackgrounds::GuildArtisan => vec![
+                "I believe that anything worth doing is worth doing right.",
+                "I'm a snob who looks down on those who can't appreciate fine art.",
+                "I always want to know how things work.",
+                "I'm full of witty aphorisms and have a proverb for every occasion.",
+            ],
+            AvailableBackgrounds::Hermit => vec![
+                "I've been isolated for so long that I rarely speak.",
+                "I am utterly serene, even in the face of disaster.",
+                "I connect everything that happens to me to a grand, cosmic plan.",
+                "I often get lost in my own thoughts and contemplation.",
+            ],
+            AvailableBackgrounds::Noble => vec![
+                "My eloquent flattery makes everyone I talk to feel important.",
+                "The common folk love me for my kindness and generosity.",
+                "I take great pains to always look my best.",
+                "Don't mistake my manners for weakness; I expect to be obeyed.",
+            ],
+            AvailableBackgrounds::Outlander => vec![
+                "I'm driven by a wanderlust that led me away from home.",
+                "I watch over my friends as if they were a litter of newborn pups.",
+                "I feel far more comfortable around animals than people.",
+                "I was, in fact, raised by wolves.",
+            ],
+            AvailableBackgrounds::Sage => vec![
+                "I use polysyllabic words that convey the impression of great erudition.",
+                "I've read every book in the world's greatest libraries, or like to boast that I have.",
+                "I'm willing to listen to every side of an argument before I make my own judgment.",
+                "I'm convinced that people are always trying to steal my secrets.",
+            ],
+            AvailableBackgrounds::Sailor => vec![
+                "My friends know they can rely on me, no matter what.",
+                "I work hard so that I can play hard when the work is done.",
+                "I enjoy sailing into new ports and making new friends over a flagon of ale.",
+                "I stretch the truth for the sake of a good story.",
+            ],
+            AvailableBackgrounds::Soldier => vec![
+                "I'm always polite and respectful.",
+                "I'm haunted by memories of war.",
+                "I can stare down a hell hound without flinching.",
+                "I face problems head-on; a simple, direct solution is the best path to success.",
+            ],
+            AvailableBackgrounds::Urchin => vec![
+                "I hide scraps of food and trinkets away in my pockets.",
+                "I ask a lot of questions.",
+                "I like to squeeze into small places where no one else can get to me.",
+                "I sleep with my back to a wall or tree, with everything I own wrapped in a bundle.",
+            ],
+        }
+    }
+
+    pub fn get_ideals(&self) -> Vec<&'static str> {
+        match *self {
+            AvailableBackgrounds::Acolyte => vec![
+                "Tradition. The ancient traditions of worship must be preserved.",
+                "Charity. I always try to help those in need, no matter the cost.",
+                "Faith. I trust that my deity will guide my actions.",
+            ],
+            AvailableBackgrounds::Charlatan => vec![
+                "Independence. I am a free spirit; no one tells me what to do.",
+                "Fairness. I never target people who can't afford to lose a few coins.",
+                "Creativity. I never run the same con twice.",
+            ],
+            AvailableBackgrounds::Criminal => vec![
+                "Honor. I don't steal from others in the trade.",
+                "Freedom. Chains are meant to be broken, as are those who would forge them.",
+                "Greed. I will do whatever it takes to become wealthy.",
+            ],
+            AvailableBackgrounds::Entertainer => vec![
+                "Beauty. When I perform, I make the world better than it was.",
+                "Tradition. The stories of old must be remembered and retold.",
+                "Creativity. The world is in need of new ideas and bold action.",
+            ],
+            AvailableBackgrounds::FolkHero => vec![
+                "Respect. People deserve to be treated with dignity and respect.",
+                "Fairness. No one should get preferential treatment before the law.",
+                "Destiny. Nothing and no one can steer me away from my higher calling.",
+            ],
+            AvailableBackgrounds::GuildArtisan => vec![
+                "Community. It is the duty of all civilized people to strengthen their community.",
+                "Generosity. My talents were given to me so that I could benefit the world.",
+                "Aspiration. I work hard to be the best there is at my craft.",
+            ],
+            AvailableBackgrounds::Hermit => vec![
+                "Greater Good. My gifts are meant to be shared with all.",
+                "Self-Knowledge. If you know yourself, there's nothing left to know.",
+                "Solitude. Peace is found in quiet reflection, away from the noise of the world.",
+            ],
+            AvailableBackgrounds::Noble => vec![
+                "Respect. Respect is due to me because of my position.",
+                "Responsibility. It is my duty to protect and care for the people beneath me.",
+                "Family. Blood runs thicker than water.",
+            ],
+            AvailableBackgrounds::Outlander => vec![
+                "Change. Life is like the seasons, in constant change.",
+                "Honor. If I dishonor myself, I dishonor my whole clan.",
+                "Nature. The natural world is more important than all the constructs of civilization.",
+            ],
+            AvailableBackgrounds::Sage => vec![
+                "Knowledge. The path to power and self-improvement is through knowledge.",
+                "Logic. Emotions must not cloud our logical thinking.",
+                "Beauty. What is beautiful points us beyond itself toward what is true.",
+            ],
+            AvailableBackgrounds::Sailor => vec![
+                "Respect. The thing that keeps a ship together is mutual respect between captain and crew.",
+                "Freedom. The sea is freedom, the freedom to go anywhere and do anything.",
+                "Mastery. I'm a predator, and the other ships on the sea are my prey.",
+            ],
+            AvailableBackgrounds::Soldier => vec![
+                "Greater Good. Our lot is to lay down our lives in defense of others.",
+                "Responsibility. I do what I must and obey just authority.",
+                "Might. In life as in war, the stronger force wins.",
+            ],
+            AvailableBackgrounds::Urchin => vec![
+                "Respect. All people, rich or poor, deserve respect.",
+                "Community. We have to take care of each other, because no one else is going to do it.",
+                "Change. The low are lifted up, and the high and mighty are brought down.",
+            ],
+        }
+    }
+
+    pub fn get_bonds(&self) -> Vec<&'static str> {
+        match *self {
+            AvailableBackgrounds::Acolyte => vec![
+                "I would die to recover an ancient relic of my faith that was lost long ago.",
+                "I owe my life to the priest who took me in when my parents died.",
+                "Everything I do is for the common people.",
+            ],
+            AvailableBackgrounds::Charlatan => vec![
+                "I fleeced the wrong person and must work to ensure they never cross my path again.",
+                "I owe everything to my mentor, a horrible person who's probably rotting in jail.",
+                "Somewhere out there, I have a child who doesn't know me.",
+            ],
+            AvailableBackgrounds::Criminal => vec![
+                "I'm trying to pay off an old debt I owe to a generous benefactor.",
+                "My ill-gotten gains go to support my family.",
+                "Someone I loved died because of a mistake I made. That will never happen again.",
+            ],
+            AvailableBackgrounds::Entertainer => vec![
+                "My instrument is my most treasured possession, and it reminds me of someone I love.",
+                "Someone stole my precious instrument, and someday I'll get it back.",
+                "I want to be famous, whatever it takes.",
+            ],
+            AvailableBackgrounds::FolkHero => vec![
+                "I have a family, but I have no idea where they are.",
+                "I worked the land, I love the land, and I will protect the land.",
+                "I protect those who cannot protect themselves.",
+            ],
+            AvailableBackgrounds::GuildArtisan => vec![
+                "The workshop where I learned my trade is the most important place in the world to me.",
+                "I created a great work for someone, and then found them unworthy to receive it.",
+                "I owe my guild a great debt for forging me into the person I am today.",
+            ],
+            AvailableBackgrounds::Hermit => vec![
+                "Nothing is more important than the other members of my hermitage.",
+                "I entered seclusion to hide from the ones who might still be hunting me.",
+                "I'm still seeking the enlightenment I pursued in my seclusion.",
+            ],
+            AvailableBackgrounds::Noble => vec![
+                "I will face any challenge to win the approval of my family.",
+                "My house's alliance with another noble family must be sustained at all costs.",
+                "Nothing is more important than the other members of my family.",
+            ],
+            AvailableBackgrounds::Outlander => vec![
+                "My family, clan, or tribe is the most important thing in my life.",
+                "An injury to the unspoiled wilderness of my home is an injury to me.",
+                "I will bring terrible wrath down on the evildoers who destroyed my homeland.",
+            ],
+            AvailableBackgrounds::Sage => vec![
+                "It is my duty to protect my students.",
+                "I have an ancient text that holds terrible secrets that must not fall into the wrong hands.",
+                "I've been searching my whole life for the answer to a certain question.",
+            ],
+            AvailableBackgrounds::Sailor => vec![
+                "I'm loyal to my captain first, everything else second.",
+                "The ship is most important; crewmates and captains come and go.",
+                "I'll always remember my first ship.",
+            ],
+            AvailableBackgrounds::Soldier => vec![
+                "I would still lay down my life for the people I served with.",
+                "Someone saved my life on the battlefield. To this day, I will never leave a friend behind.",
+                "I fight for those who cannot fight for themselves.",
+            ],
+            AvailableBackgrounds::Urchin => vec![
+                "My town or city is my home, and I'll fight to defend it.",
+                "I sponsor an orphanage to keep others from enduring what I was forced to endure.",
+                "I owe my survival to another urchin who taught me to live on the streets.",
+            ],
+        }
+    }
+
+    pub fn get_flaws(&self) -> Vec<&'static str> {
+        match *self {
+            AvailableBackgrounds::Acolyte => vec![
+                "I judge others harshly, and myself even more severely.",
+                "I put too much trust in those who wield power within my temple's hierarchy.",
+                "I am inflexible in my thinking.",
+            ],
+            AvailableBackgrounds::Charlatan => vec![
+                "I can't resist swindling people who are more powerful than me.",
+                "I'm too greedy for my own good.",
+                "I can't resist a pretty face.",
+            ],
+            AvailableBackgrounds::Criminal => vec![
+                "When I see something valuable, I can't think about anything but how to steal it.",
+                "When faced with a choice between money and my friends, I usually choose the money.",
+                "I turn tail and run when things look bad.",
+            ],
+            AvailableBackgrounds::Entertainer => vec![
+                "I'll do anything to win fame and renown.",
+                "I'm a sucker for a pretty face.",
+                "I have trouble keeping my true feelings hidden.",
+            ],
+            AvailableBackgrounds::FolkHero => vec![
+                "The tyrant who rules my land will stop at nothing to see me killed.",
+                "I'm convinced of the significance of my destiny, and blind to my shortcomings.",
+                "I have trouble trusting in my allies.",
+            ],
+            AvailableBackgrounds::GuildArtisan => vec![
+                "I'll do anything to get my hands on something rare or priceless.",
+                "I'm quick to assume that someone is trying to cheat me.",
+                "I'm never satisfied with what I have; I always want more.",
+            ],
+            AvailableBackgrounds::Hermit => vec![
+                "Now that I've returned to the world, I enjoy its delights a little too much.",
+                "I harbor dark, bloodthirsty thoughts that my isolation failed to quell.",
+                "I am dogmatic in my thoughts and philosophy.",
+            ],
+            AvailableBackgrounds::Noble => vec![
+                "I secretly believe that everyone is beneath me.",
+                "I hide a truly scandalous secret that could ruin my family forever.",
+                "I too often hear veiled insults and threats in every word addressed to me.",
+            ],
+            AvailableBackgrounds::Outlander => vec![
+                "I am too enamored of ale, wine, and other intoxicants.",
+                "There's no room for caution in a life lived to the fullest.",
+                "I remember every insult I've received and nurse a silent resentment.",
+            ],
+            AvailableBackgrounds::Sage => vec![
+                "I am easily distracted by the promise of information.",
+                "Most people scream and run when they see a demon. I stop and take notes.",
+                "I overlook obvious solutions in favor of complicated ones.",
+            ],
+            AvailableBackgrounds::Sailor => vec![
+                "I follow orders, even if I think they're wrong.",
+                "I'll say anything to avoid having to do extra work.",
+                "I can't help but pocket loose coins and other trinkets I come across.",
+            ],
+            AvailableBackgrounds::Soldier => vec![
+                "The monstrous enemy we faced in battle still leaves me quivering with fear.",
+                "I have little respect for anyone who is not a proven warrior.",
+                "I obey the law, even if the law causes misery.",
+            ],
+            AvailableBackgrounds::Urchin => vec![
+                "If I'm outnumbered, I will run away from a fight.",
+                "Gold seems like a lot of money to me, and I'll do just about anything for more of it.",
+                "I will never fully trust anyone other than myself.",
+            ],
+        }
+    }
+}
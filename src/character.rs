@@ -1,22 +1,60 @@
 use super::*;
-use classes::AvailableClasses;
+use armor::{get_armor_class, AvailableArmor};
+use backgrounds::AvailableBackgrounds;
+use classes::{
+    get_pact_slots, get_spell_slots, AvailableClasses, AvailableSubclasses, SpellcastingProgression,
+};
+use dice::{DiceExpression, DiceRoll, Keep};
 use enum_iterator::{all, Sequence};
+use equipment::Inventory;
+use feats::{AvailableFeats, FeatEffect};
 use num::Integer;
-use races::AvailableRaces;
+use races::{AvailableRaces, AvailableSubraces};
 use rand::Rng;
+use roll_log::RollLog;
+use serde::{ser::SerializeMap, Deserialize, Deserializer, Serialize, Serializer};
+use spells::Level as SpellLevel;
 use std::ops::Index;
-use std::{collections::HashMap, error::Error, io};
+use std::{cmp, collections::HashMap, error::Error, fmt, io};
 use util::{EnumIter, EnumString};
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
 pub struct CharacterSheet {
     pub name: Name,
     pub race: AvailableRaces,
+    pub subrace: Option<AvailableSubraces>,
     pub alignment: Alignment,
-    pub class: AvailableClasses,
-    pub level: Level,
+    /// The levels taken in each class, starting with the character's 1st level class.
+    pub classes: Vec<ClassLevel>,
     pub experience_points: ExperiencePoints,
     pub ability_scores: AbilityScores,
+    pub base_ability_scores: AbilityScores,
+    pub ability_score_increases: Vec<AbilityScoreIncrease>,
     pub point_buy: bool,
+    /// Every roll made for the character, so the DM can check them.
+    pub roll_log: RollLog,
+    pub background: AvailableBackgrounds,
+    pub skill_proficiencies: Proficiencies<Skill>,
+    pub skill_expertise: Proficiencies<Skill>,
+    pub saving_throw_proficiencies: Proficiencies<AbilityName>,
+    /// One hit die per character level.
+    pub hit_dice: Vec<Dice>,
+    /// The hit points gained from the hit die at each level, before the Constitution modifier.
+    pub hit_point_gains: Vec<i8>,
+    pub armor: Option<AvailableArmor>,
+    pub shield: bool,
+    pub tool_proficiencies: Proficiencies<Tool>,
+    pub languages: Proficiencies<Language>,
+    pub inventory: Inventory,
+    pub features: Vec<Feature>,
+    pub cantrips: Vec<String>,
+    pub spells: Vec<String>,
+    pub armor_proficiencies: Proficiencies<ArmorCategory>,
+    pub feats: Vec<AvailableFeats>,
+    /// The feat taken at 1st level, as opposed to in place of an ability score improvement.
+    pub starting_feat: Option<AvailableFeats>,
+    pub bio: Bio,
 }
 
 impl CharacterSheet {
@@ -24,17 +62,38 @@ impl CharacterSheet {
         return CharacterSheet {
             name: "".to_string(),
             race: AvailableRaces::Human,
+            subrace: None,
             alignment: Alignment::TrueNeutral,
-            class: AvailableClasses::Barbarian,
-            level: 1,
+            classes: vec![ClassLevel::new(AvailableClasses::Barbarian)],
             experience_points: 0,
             ability_scores: AbilityScores::default(),
+            base_ability_scores: AbilityScores::default(),
+            ability_score_increases: Vec::new(),
             point_buy: false,
+            roll_log: RollLog::default(),
+            background: AvailableBackgrounds::Acolyte,
+            skill_proficiencies: Proficiencies::default(),
+            skill_expertise: Proficiencies::default(),
+            saving_throw_proficiencies: Proficiencies::default(),
+            hit_dice: Vec::new(),
+            hit_point_gains: Vec::new(),
+            armor: None,
+            shield: false,
+            tool_proficiencies: Proficiencies::default(),
+            languages: Proficiencies::default(),
+            inventory: Inventory::default(),
+            features: Vec::new(),
+            cantrips: Vec::new(),
+            spells: Vec::new(),
+            armor_proficiencies: Proficiencies::default(),
+            feats: Vec::new(),
+            starting_feat: None,
+            bio: Bio::default(),
         };
     }
 
     pub fn keys() -> Vec<&'static str> {
-        return vec![
+        let mut keys = vec![
             "name",
             "race",
             "alignment",
@@ -42,7 +101,276 @@ impl CharacterSheet {
             "level",
             "experience_points",
             "ability_scores",
+            "hit_points",
+            "hit_dice",
+            "armor_class",
+            "spell_slots",
         ];
+        keys.extend(AbilityName::iter().iter().map(|ability| ability.save_key()));
+        keys.extend(Skill::iter().iter().map(|skill| skill.key()));
+        keys.extend(vec![
+            "background",
+            "tool_proficiencies",
+            "languages",
+            "equipment",
+            "features",
+            "cantrips",
+            "spells",
+            "armor_proficiencies",
+            "feats",
+            "personality_traits",
+            "ideal",
+            "bond",
+            "flaw",
+            "backstory",
+            "age",
+            "height",
+            "weight",
+            "eyes",
+            "hair",
+            "skin",
+        ]);
+        return keys;
+    }
+
+    /// Award experience points. Returns the level the character can now advance to, if they
+    /// have enough experience to go beyond their current level.
+    pub fn award_experience(&mut self, experience_points: ExperiencePoints) -> Option<Level> {
+        self.experience_points = self.experience_points.saturating_add(experience_points);
+        let level = get_level_for_experience(self.experience_points);
+        if level > self.get_level() {
+            return Some(level);
+        }
+        return None;
+    }
+
+    /// Whether the character has the experience to advance to the next level.
+    pub fn can_level_up(&self) -> bool {
+        return self.get_level() < get_level_for_experience(self.experience_points);
+    }
+
+    pub fn get_proficiency_bonus(&self) -> i8 {
+        return 2 + (cmp::max(self.get_level(), 1) - 1) / 4;
+    }
+
+    /// The class the character took at 1st level, which decides their saving throws, starting
+    /// skills and starting equipment.
+    pub fn get_class(&self) -> AvailableClasses {
+        return match self.classes.first() {
+            Some(class_level) => class_level.class,
+            None => AvailableClasses::Barbarian,
+        };
+    }
+
+    /// The character's total level across all of their classes.
+    pub fn get_level(&self) -> Level {
+        return self
+            .classes
+            .iter()
+            .map(|class_level| class_level.level)
+            .sum();
+    }
+
+    pub fn get_subclass(&self, class: AvailableClasses) -> Option<AvailableSubclasses> {
+        return self
+            .classes
+            .iter()
+            .find(|class_level| class_level.class == class)
+            .and_then(|class_level| class_level.subclass);
+    }
+
+    pub fn set_subclass(&mut self, subclass: AvailableSubclasses) {
+        let class = subclass.get_class();
+        if let Some(class_level) = self
+            .classes
+            .iter_mut()
+            .find(|class_level| class_level.class == class)
+        {
+            class_level.subclass = Some(subclass);
+        }
+    }
+
+    pub fn get_class_level(&self, class: AvailableClasses) -> Level {
+        return match self
+            .classes
+            .iter()
+            .find(|class_level| class_level.class == class)
+        {
+            Some(class_level) => class_level.level,
+            None => 0,
+        };
+    }
+
+    /// Gain a level in a class, adding the class if the character doesn't have it yet.
+    pub fn add_class_level(&mut self, class: AvailableClasses) {
+        match self
+            .classes
+            .iter_mut()
+            .find(|class_level| class_level.class == class)
+        {
+            Some(class_level) => class_level.level += 1,
+            None => self.classes.push(ClassLevel::new(class)),
+        }
+    }
+
+    /// Whether the character can take a level in a new class: they must meet the ability score
+    /// prerequisites of both the new class and every class they already have.
+    pub fn can_multiclass_into(&self, class: AvailableClasses) -> bool {
+        if self.get_class_level(class) > 0 {
+            return true;
+        }
+        return self
+            .classes
+            .iter()
+            .map(|class_level| class_level.class)
+            .chain(std::iter::once(class))
+            .all(|class| class.meets_multiclass_prerequisites(&self.ability_scores));
+    }
+
+    /// The ability the character casts spells with: their 1st level class's, or its
+    /// subclass's for an eldritch knight or arcane trickster.
+    pub fn get_spellcasting_ability(&self) -> Option<AbilityName> {
+        let class = self.get_class();
+        return class.get_spellcasting_ability().or(self
+            .get_subclass(class)
+            .and_then(|subclass| subclass.get_spellcasting_ability()));
+    }
+
+    /// The number of spell slots of each level from 1st to 9th. A single class uses its own
+    /// progression, while several spellcasting classes share slots by combined caster level.
+    /// Warlock pact magic slots are kept separately, see `get_pact_slots`.
+    pub fn get_spell_slots(&self) -> [usize; 9] {
+        let casters = self
+            .classes
+            .iter()
+            .filter_map(
+                |class_level| match class_level.get_spellcasting_progression() {
+                    Some(SpellcastingProgression::Pact) | None => None,
+                    Some(progression) => Some((progression, class_level.level)),
+                },
+            )
+            .collect::<Vec<(SpellcastingProgression, Level)>>();
+        let caster_level = match casters.as_slice() {
+            [(SpellcastingProgression::Half, level)] if *level >= 2 => (level + 1) / 2,
+            [(SpellcastingProgression::Third, level)] if *level >= 3 => (level + 2) / 3,
+            _ => casters
+                .iter()
+                .map(|(progression, level)| match progression {
+                    SpellcastingProgression::Full => *level,
+                    SpellcastingProgression::Third => level / 3,
+                    _ => level / 2,
+                })
+                .sum(),
+        };
+        return get_spell_slots(caster_level);
+    }
+
+    /// The number and level of warlock pact magic slots, if the character has warlock levels.
+    pub fn get_pact_slots(&self) -> Option<(usize, SpellLevel)> {
+        return get_pact_slots(self.get_class_level(AvailableClasses::Warlock));
+    }
+
+    /// Record the hit die and hit points gained for a new level.
+    pub fn add_hit_die(&mut self, die: Dice, hit_points: i8) {
+        self.hit_dice.push(die);
+        self.hit_point_gains.push(hit_points);
+    }
+
+    /// The hit point maximum: each level's hit die gain plus the Constitution modifier (at least
+    /// 1 per level), plus any per-level bonuses from feats.
+    pub fn get_max_hit_points(&self) -> i32 {
+        let constitution = self.ability_scores.get_modifier(AbilityName::Constitution);
+        let levels = self.hit_point_gains.len() as i32;
+        let mut hit_points = self
+            .hit_point_gains
+            .iter()
+            .map(|gain| cmp::max(1, gain + constitution) as i32)
+            .sum::<i32>();
+
+        if let Some(subrace) = self.subrace {
+            hit_points += subrace.get_hit_points_per_level() as i32 * levels;
+        }
+        for feat in &self.feats {
+            for effect in feat.get_effects() {
+                if let FeatEffect::HitPointsPerLevel(bonus) = effect {
+                    hit_points += bonus as i32 * levels;
+                }
+            }
+        }
+
+        return hit_points;
+    }
+
+    /// The hit dice pool, grouped by die, e.g. "2d10 + 1d8".
+    pub fn get_hit_dice_pool(&self) -> String {
+        let mut pool: Vec<(Dice, usize)> = Vec::new();
+        for die in &self.hit_dice {
+            match pool.iter_mut().find(|(existing, _)| existing == die) {
+                Some((_, count)) => *count += 1,
+                None => pool.push((*die, 1)),
+            }
+        }
+        return pool
+            .iter()
+            .map(|(die, count)| format!("{}{}", count, die))
+            .collect::<Vec<String>>()
+            .join(" + ");
+    }
+
+    pub fn get_saving_throw_modifier(&self, ability: AbilityName) -> i8 {
+        let modifier = self.ability_scores.get_modifier(ability);
+        if self.saving_throw_proficiencies.contains(ability) {
+            return modifier + self.get_proficiency_bonus();
+        }
+        return modifier;
+    }
+
+    /// The ability modifier plus the proficiency bonus if proficient, or twice the proficiency
+    /// bonus with expertise.
+    pub fn get_skill_modifier(&self, skill: Skill) -> i8 {
+        let modifier = self.ability_scores.get_modifier(skill.get_ability());
+        if self.skill_expertise.contains(skill) {
+            return modifier + self.get_proficiency_bonus() * 2;
+        }
+        if self.skill_proficiencies.contains(skill) {
+            return modifier + self.get_proficiency_bonus();
+        }
+        return modifier;
+    }
+
+    /// Whether the character can cast at least one spell.
+    pub fn can_cast_spells(&self) -> bool {
+        return !self.cantrips.is_empty() || !self.spells.is_empty();
+    }
+
+    /// Remove every proficiency, language and ability score increase granted by a source, so
+    /// that the choice which granted them can be made again.
+    pub fn remove_grants(&mut self, source: Source) {
+        self.skill_proficiencies.remove_source(source);
+        self.skill_expertise.remove_source(source);
+        self.saving_throw_proficiencies.remove_source(source);
+        self.tool_proficiencies.remove_source(source);
+        self.languages.remove_source(source);
+        self.armor_proficiencies.remove_source(source);
+        self.ability_score_increases
+            .retain(|increase| increase.source != source);
+    }
+
+    /// Recalculate the values derived from the player's choices. Ability scores are the base
+    /// scores plus every increase, to a maximum of 20.
+    pub fn recompute(&mut self) {
+        let mut ability_scores = self.base_ability_scores.clone();
+        for increase in &self.ability_score_increases {
+            let score = ability_scores.get(increase.ability);
+            ability_scores.set(increase.ability, cmp::min(20, score + increase.increase));
+        }
+        self.ability_scores = ability_scores;
+    }
+}
+
+impl Default for CharacterSheet {
+    fn default() -> Self {
+        return CharacterSheet::new();
     }
 }
 
@@ -53,21 +381,128 @@ impl Index<&str> for CharacterSheet {
         /// return a member of this struct with the given name
         let x: String = match index {
             "name" => self.name.to_string(),
-            "race" => self.race.to_string(),
+            "race" => match self.subrace {
+                Some(subrace) => subrace.to_string(),
+                None => self.race.to_string(),
+            },
             "alignment" => self.alignment.to_string(),
-            "class" => self.class.to_string(),
-            "level" => self.level.to_string(),
+            "class" => match self.classes.as_slice() {
+                [class_level] => match class_level.subclass {
+                    Some(subclass) => format!("{} ({})", class_level.class, subclass),
+                    None => class_level.class.to_string(),
+                },
+                classes => classes
+                    .iter()
+                    .map(|class_level| class_level.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" / "),
+            },
+            "level" => self.get_level().to_string(),
+            "spell_slots" => {
+                let mut slots = self
+                    .get_spell_slots()
+                    .iter()
+                    .zip(SPELL_LEVEL_NAMES.iter())
+                    .filter(|(count, _)| **count > 0)
+                    .map(|(count, name)| format!("{}: {}", name, count))
+                    .collect::<Vec<String>>();
+                if let Some((count, level)) = self.get_pact_slots() {
+                    slots.push(format!(
+                        "Pact: {} x {}",
+                        count,
+                        SPELL_LEVEL_NAMES[level as usize - 1]
+                    ));
+                }
+                slots.join(", ")
+            }
             "experience_points" => self.experience_points.to_string(),
             "ability_scores" => self.ability_scores.to_string(),
+            "hit_points" => self.get_max_hit_points().to_string(),
+            "hit_dice" => self.get_hit_dice_pool(),
+            "armor_class" => get_armor_class(self).to_string(),
             "point_buy" => self.point_buy.to_string(),
-            _ => "".to_string(),
+            "background" => self.background.to_string(),
+            "skill_proficiencies" => self.skill_proficiencies.to_string(),
+            "tool_proficiencies" => self.tool_proficiencies.to_string(),
+            "languages" => self.languages.to_string(),
+            "equipment" => self.inventory.to_string(),
+            "features" => join(self.features.iter().map(|feature| &feature.name)),
+            "cantrips" => self.cantrips.join(", "),
+            "spells" => self.spells.join(", "),
+            "armor_proficiencies" => self.armor_proficiencies.to_string(),
+            "feats" => join(&self.feats),
+            "personality_traits" => self.bio.personality_traits.join(" "),
+            "ideal" => self.bio.ideal.to_string(),
+            "bond" => self.bio.bond.to_string(),
+            "flaw" => self.bio.flaw.to_string(),
+            "backstory" => self.bio.backstory.to_string(),
+            "age" => self.bio.age.to_string(),
+            "height" => self.bio.height.to_string(),
+            "weight" => self.bio.weight.to_string(),
+            "eyes" => self.bio.eyes.to_string(),
+            "hair" => self.bio.hair.to_string(),
+            "skin" => self.bio.skin.to_string(),
+            _ if index.ends_with("_save") => {
+                match AbilityName::iter()
+                    .into_iter()
+                    .find(|ability| ability.save_key() == index)
+                {
+                    Some(ability) => {
+                        let mut value = format!("{:+}", self.get_saving_throw_modifier(ability));
+                        if self.saving_throw_proficiencies.contains(ability) {
+                            value.push_str(" (proficient)");
+                        }
+                        value
+                    }
+                    None => "".to_string(),
+                }
+            }
+            _ => match Skill::iter().into_iter().find(|skill| skill.key() == index) {
+                Some(skill) => {
+                    let mut value = format!("{:+}", self.get_skill_modifier(skill));
+                    if self.skill_expertise.contains(skill) {
+                        value.push_str(" (expertise)");
+                    } else if self.skill_proficiencies.contains(skill) {
+                        value.push_str(" (proficient)");
+                    }
+                    value
+                }
+                None => "".to_string(),
+            },
         };
         return Box::leak(x.into_boxed_str());
     }
 }
+/// The character's personality and appearance.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Bio {
+    pub personality_traits: Vec<String>,
+    pub ideal: String,
+    pub bond: String,
+    pub flaw: String,
+    pub backstory: String,
+    pub age: String,
+    pub height: String,
+    pub weight: String,
+    pub eyes: String,
+    pub hair: String,
+    pub skin: String,
+}
+
+/// Join a list of displayable values into a comma separated string.
+pub fn join<T: fmt::Display, I: IntoIterator<Item = T>>(items: I) -> String {
+    return items
+        .into_iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<String>>()
+        .join(", ");
+}
+
 type Name = String;
 
-#[derive(Copy, Clone, Debug, Sequence, EnumIter, EnumString)]
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Sequence, EnumIter, EnumString, Serialize, Deserialize,
+)]
 pub enum Alignment {
     LawfulGood,
     NeutralGood,
@@ -80,19 +515,107 @@ pub enum Alignment {
     ChaoticEvil,
 }
 
+impl Alignment {
+    pub fn is_lawful(&self) -> bool {
+        return matches!(
+            *self,
+            Alignment::LawfulGood | Alignment::LawfulNeutral | Alignment::LawfulEvil
+        );
+    }
+
+    pub fn is_chaotic(&self) -> bool {
+        return matches!(
+            *self,
+            Alignment::ChaoticGood | Alignment::ChaoticNeutral | Alignment::ChaoticEvil
+        );
+    }
+
+    pub fn is_good(&self) -> bool {
+        return matches!(
+            *self,
+            Alignment::LawfulGood | Alignment::NeutralGood | Alignment::ChaoticGood
+        );
+    }
+
+    pub fn is_evil(&self) -> bool {
+        return matches!(
+            *self,
+            Alignment::LawfulEvil | Alignment::NeutralEvil | Alignment::ChaoticEvil
+        );
+    }
+}
+
 type Level = i8;
 
+const SPELL_LEVEL_NAMES: [&str; 9] = [
+    "1st", "2nd", "3rd", "4th", "5th", "6th", "7th", "8th", "9th",
+];
+
+/// The number of levels a character has in one class.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClassLevel {
+    pub class: AvailableClasses,
+    pub level: Level,
+    #[serde(default)]
+    pub subclass: Option<AvailableSubclasses>,
+}
+
+impl ClassLevel {
+    /// The first level in a class, before a subclass has been chosen.
+    pub fn new(class: AvailableClasses) -> ClassLevel {
+        return ClassLevel {
+            class,
+            level: 1,
+            subclass: None,
+        };
+    }
+
+    /// How these levels count towards spell slots: by the class's own progression, or by the
+    /// subclass's for a fighter or rogue who learns magic from it.
+    pub fn get_spellcasting_progression(&self) -> Option<SpellcastingProgression> {
+        return self.class.get_spellcasting_progression().or(self
+            .subclass
+            .and_then(|subclass| subclass.get_spellcasting_progression()));
+    }
+}
+
+impl fmt::Display for ClassLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.class, self.level)?;
+        if let Some(subclass) = self.subclass {
+            write!(f, " ({})", subclass)?;
+        }
+        return Ok(());
+    }
+}
+
 type ExperiencePoints = u32;
 
+pub const MAX_LEVEL: Level = 20;
+
+/// The experience points needed to reach each level, starting at 1st level.
+pub const EXPERIENCE_THRESHOLDS: [ExperiencePoints; MAX_LEVEL as usize] = [
+    0, 300, 900, 2700, 6500, 14000, 23000, 34000, 48000, 64000, 85000, 100000, 120000, 140000,
+    165000, 195000, 225000, 265000, 305000, 355000,
+];
+
+/// The highest level a character with this many experience points can be.
+pub fn get_level_for_experience(experience_points: ExperiencePoints) -> Level {
+    return EXPERIENCE_THRESHOLDS
+        .iter()
+        .take_while(|threshold| **threshold <= experience_points)
+        .count() as Level;
+}
+
 type AbilityScore = i8;
 
-trait AbilityModifier {
+pub trait AbilityModifier {
     fn get_modifier(&self) -> i8;
 }
 
 impl AbilityModifier for AbilityScore {
     fn get_modifier(&self) -> i8 {
-        return (self - 10) / 2;
+        return Integer::div_floor(&(self - 10), &2);
     }
 }
 
@@ -111,13 +634,17 @@ impl Default for AbilityScores {
 
 impl AbilityScores {
     pub fn get(&self, ability: AbilityName) -> AbilityScore {
-        return self.0.get(&ability).unwrap().clone();
+        return *self.0.get(&ability).unwrap();
     }
 
     pub fn set(&mut self, ability: AbilityName, score: AbilityScore) {
         self.0.insert(ability, score);
     }
 
+    pub fn get_modifier(&self, ability: AbilityName) -> i8 {
+        return self.get(ability).get_modifier();
+    }
+
     // Sort the ability scores specically: STR, DEX, CON, INT, WIS, CHA
     pub fn get_sorted(&self) -> Vec<(AbilityName, AbilityScore)> {
         return vec![
@@ -137,17 +664,53 @@ impl AbilityScores {
     }
 }
 
-impl ToString for AbilityScores {
-    fn to_string(&self) -> String {
-        let mut ability_scores = String::new();
+// Serialize in the conventional STR, DEX, CON, INT, WIS, CHA order so saved files diff cleanly.
+impl Serialize for AbilityScores {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let sorted = self.get_sorted();
+        let mut map = serializer.serialize_map(Some(sorted.len()))?;
+        for (ability, score) in sorted {
+            map.serialize_entry(&ability, &score)?;
+        }
+        return map.end();
+    }
+}
+
+impl<'de> Deserialize<'de> for AbilityScores {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let scores = HashMap::<AbilityName, AbilityScore>::deserialize(deserializer)?;
+        let mut ability_scores = AbilityScores::default();
+        for (ability, score) in scores {
+            ability_scores.set(ability, score);
+        }
+        return Ok(ability_scores);
+    }
+}
+
+impl fmt::Display for AbilityScores {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         for (ability, score) in self.get_sorted() {
-            ability_scores.push_str(&format!("{}: {:<2}   ", ability, score));
+            write!(f, "{}: {:<2}   ", ability, score)?;
         }
-        return ability_scores;
+        return Ok(());
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Sequence, EnumIter, EnumString)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Sequence,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
 pub enum AbilityName {
     Strength,
     Dexterity,
@@ -158,8 +721,25 @@ pub enum AbilityName {
     ANY,
 }
 
-#[derive(Debug, Sequence, EnumIter, EnumString)]
-enum Skill {
+impl AbilityName {
+    /// The key used for the ability's saving throw when rendering the character sheet.
+    fn save_key(&self) -> &'static str {
+        match *self {
+            AbilityName::Strength => "strength_save",
+            AbilityName::Dexterity => "dexterity_save",
+            AbilityName::Constitution => "constitution_save",
+            AbilityName::Intelligence => "intelligence_save",
+            AbilityName::Wisdom => "wisdom_save",
+            AbilityName::Charisma => "charisma_save",
+            AbilityName::ANY => "any_save",
+        }
+    }
+}
+
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Sequence, EnumIter, EnumString, Serialize, Deserialize,
+)]
+pub enum Skill {
     Acrobatics,
     AnimalHandling,
     Arcana,
@@ -180,8 +760,263 @@ enum Skill {
     Survival,
 }
 
-#[derive(Copy, Clone)]
-enum Dice {
+impl Skill {
+    pub fn get_ability(&self) -> AbilityName {
+        match *self {
+            Skill::Athletics => AbilityName::Strength,
+            Skill::Acrobatics | Skill::SleightOfHand | Skill::Stealth => AbilityName::Dexterity,
+            Skill::Arcana
+            | Skill::History
+            | Skill::Investigation
+            | Skill::Nature
+            | Skill::Religion => AbilityName::Intelligence,
+            Skill::AnimalHandling
+            | Skill::Insight
+            | Skill::Medicine
+            | Skill::Perception
+            | Skill::Survival => AbilityName::Wisdom,
+            Skill::Deception | Skill::Intimidation | Skill::Performance | Skill::Persuasion => {
+                AbilityName::Charisma
+            }
+        }
+    }
+
+    /// The key used for the skill when rendering the character sheet.
+    fn key(&self) -> &'static str {
+        match *self {
+            Skill::Acrobatics => "acrobatics",
+            Skill::AnimalHandling => "animal_handling",
+            Skill::Arcana => "arcana",
+            Skill::Athletics => "athletics",
+            Skill::Deception => "deception",
+            Skill::History => "history",
+            Skill::Insight => "insight",
+            Skill::Intimidation => "intimidation",
+            Skill::Investigation => "investigation",
+            Skill::Medicine => "medicine",
+            Skill::Nature => "nature",
+            Skill::Perception => "perception",
+            Skill::Performance => "performance",
+            Skill::Persuasion => "persuasion",
+            Skill::Religion => "religion",
+            Skill::SleightOfHand => "sleight_of_hand",
+            Skill::Stealth => "stealth",
+            Skill::Survival => "survival",
+        }
+    }
+}
+
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Sequence, EnumIter, EnumString, Serialize, Deserialize,
+)]
+pub enum Tool {
+    AlchemistsSupplies,
+    BrewersSupplies,
+    CalligraphersSupplies,
+    CarpentersTools,
+    CartographersTools,
+    CobblersTools,
+    CooksUtensils,
+    GlassblowersTools,
+    JewelersTools,
+    LeatherworkersTools,
+    MasonsTools,
+    PaintersSupplies,
+    PottersTools,
+    SmithsTools,
+    TinkersTools,
+    WeaversTools,
+    WoodcarversTools,
+    DisguiseKit,
+    ForgeryKit,
+    HerbalismKit,
+    NavigatorsTools,
+    PoisonersKit,
+    ThievesTools,
+    DiceSet,
+    DragonchessSet,
+    PlayingCardSet,
+    ThreeDragonAnteSet,
+    Bagpipes,
+    Drum,
+    Dulcimer,
+    Flute,
+    Lute,
+    Lyre,
+    Horn,
+    PanFlute,
+    Shawm,
+    Viol,
+    VehiclesLand,
+    VehiclesWater,
+}
+
+impl Tool {
+    pub fn artisans_tools() -> Vec<Tool> {
+        return vec![
+            Tool::AlchemistsSupplies,
+            Tool::BrewersSupplies,
+            Tool::CalligraphersSupplies,
+            Tool::CarpentersTools,
+            Tool::CartographersTools,
+            Tool::CobblersTools,
+            Tool::CooksUtensils,
+            Tool::GlassblowersTools,
+            Tool::JewelersTools,
+            Tool::LeatherworkersTools,
+            Tool::MasonsTools,
+            Tool::PaintersSupplies,
+            Tool::PottersTools,
+            Tool::SmithsTools,
+            Tool::TinkersTools,
+            Tool::WeaversTools,
+            Tool::WoodcarversTools,
+        ];
+    }
+
+    pub fn gaming_sets() -> Vec<Tool> {
+        return vec![
+            Tool::DiceSet,
+            Tool::DragonchessSet,
+            Tool::PlayingCardSet,
+            Tool::ThreeDragonAnteSet,
+        ];
+    }
+
+    pub fn musical_instruments() -> Vec<Tool> {
+        return vec![
+            Tool::Bagpipes,
+            Tool::Drum,
+            Tool::Dulcimer,
+            Tool::Flute,
+            Tool::Lute,
+            Tool::Lyre,
+            Tool::Horn,
+            Tool::PanFlute,
+            Tool::Shawm,
+            Tool::Viol,
+        ];
+    }
+}
+
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Sequence, EnumIter, EnumString, Serialize, Deserialize,
+)]
+pub enum Language {
+    Common,
+    Dwarvish,
+    Elvish,
+    Giant,
+    Gnomish,
+    Goblin,
+    Halfling,
+    Orc,
+    Abyssal,
+    Celestial,
+    Draconic,
+    DeepSpeech,
+    Infernal,
+    Primordial,
+    Sylvan,
+    Undercommon,
+}
+
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Sequence, EnumIter, EnumString, Serialize, Deserialize,
+)]
+pub enum ArmorCategory {
+    Light,
+    Medium,
+    Heavy,
+    Shields,
+}
+
+/// Where a proficiency, language or ability score increase came from.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Source {
+    Race,
+    /// The choices made for the 1st level class.
+    Class,
+    /// Gained on levelling up: multiclass proficiencies, later expertise and ability score
+    /// improvements.
+    LevelUp,
+    Background,
+    Feat(AvailableFeats),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Proficiency<T> {
+    pub value: T,
+    pub source: Source,
+}
+
+/// A list of proficiencies (or languages), each recorded once along with its source.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Proficiencies<T>(Vec<Proficiency<T>>);
+
+impl<T> Default for Proficiencies<T> {
+    fn default() -> Self {
+        return Proficiencies(Vec::new());
+    }
+}
+
+impl<T: Copy + PartialEq> Proficiencies<T> {
+    /// Add a proficiency, unless the character already has it from another source.
+    pub fn add(&mut self, value: T, source: Source) {
+        if !self.contains(value) {
+            self.0.push(Proficiency { value, source });
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        return self.0.iter().any(|proficiency| proficiency.value == value);
+    }
+
+    pub fn remove_source(&mut self, source: Source) {
+        self.0.retain(|proficiency| proficiency.source != source);
+    }
+
+    pub fn values(&self) -> Vec<T> {
+        return self.0.iter().map(|proficiency| proficiency.value).collect();
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Proficiency<T>> {
+        return self.0.iter();
+    }
+}
+
+impl<T: Copy + PartialEq + fmt::Display> fmt::Display for Proficiencies<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", join(self.values()))
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AbilityScoreIncrease {
+    pub ability: AbilityName,
+    pub increase: i8,
+    pub source: Source,
+}
+
+/// A named feature granted by a background, race or class.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Feature {
+    pub name: String,
+    pub description: String,
+}
+
+impl Feature {
+    pub fn new<T: Into<String>, U: Into<String>>(name: T, description: U) -> Feature {
+        return Feature {
+            name: name.into(),
+            description: description.into(),
+        };
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Dice {
     D4 = 4,
     D6 = 6,
     D8 = 8,
@@ -191,48 +1026,132 @@ enum Dice {
     D100 = 100,
 }
 
-trait Roll {
-    fn roll(&mut self, num: i8) -> i8;
-}
+impl Dice {
+    pub fn get_sides(&self) -> i8 {
+        return *self as i8;
+    }
 
-impl Roll for Dice {
-    fn roll(&mut self, num: i8) -> i8 {
-        let mut rng = rand::thread_rng();
-        let mut total = 0;
+    /// The rounded-up average roll, as used for fixed hit point gains (e.g. 6 for a d10).
+    pub fn get_average(&self) -> i8 {
+        return self.get_sides() / 2 + 1;
+    }
 
-        for _ in 0..num {
-            total += rng.gen_range(1..=*self as i8);
-        }
+    /// An expression for rolling a number of these dice, e.g. `2d6`.
+    pub fn get_expression(&self, count: u32) -> DiceExpression {
+        return DiceExpression::new(count, self.get_sides() as u32);
+    }
+}
 
-        total
+impl fmt::Display for Dice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "d{}", self.get_sides())
     }
 }
 
-/// Roll for an ability score. This is done by rolling 4d6 and dropping the lowest roll.
-fn roll_for_ability_score() -> i8 {
-    let mut dice = Dice::D6;
-    let mut rolls = [0; 4];
+pub trait Roll {
+    fn roll<R: Rng + ?Sized>(&mut self, num: i8, rng: &mut R) -> i32;
+}
 
-    for i in 0..4 {
-        rolls[i] = Dice::D6.roll(1);
+impl Roll for Dice {
+    fn roll<R: Rng + ?Sized>(&mut self, num: i8, rng: &mut R) -> i32 {
+        return self.get_expression(num as u32).roll(rng).total;
     }
+}
 
-    rolls.sort();
+/// The fixed scores a player can assign instead of rolling.
+pub const STANDARD_ARRAY: [i8; 6] = [15, 14, 13, 12, 10, 8];
 
-    rolls[1] + rolls[2] + rolls[3]
+/// The ways a player can generate their ability scores.
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Sequence, EnumIter, EnumString, Serialize, Deserialize,
+)]
+pub enum AbilityScoreMethod {
+    DropLowest,
+    StandardArray,
+    PointBuy,
+    Straight,
+    InOrder,
+    RerollOnes,
+    Heroic,
 }
 
-pub fn get_ability_score_rolls() -> Vec<i8> {
-    let mut ability_score_rolls = Vec::new();
+impl AbilityScoreMethod {
+    pub fn get_description(&self) -> &'static str {
+        match *self {
+            AbilityScoreMethod::DropLowest => "Roll 4d6 and drop the lowest die",
+            AbilityScoreMethod::StandardArray => "Use the standard array (15, 14, 13, 12, 10, 8)",
+            AbilityScoreMethod::PointBuy => "Point buy",
+            AbilityScoreMethod::Straight => "Roll 3d6",
+            AbilityScoreMethod::InOrder => "Roll 3d6 in order",
+            AbilityScoreMethod::RerollOnes => "Roll 4d6, rerolling ones, and drop the lowest die",
+            AbilityScoreMethod::Heroic => "Roll 2d6+6 (heroic)",
+        }
+    }
 
-    for _ in 0..6 {
-        ability_score_rolls.push(roll_for_ability_score());
+    /// The dice rolled for each score, or `None` if the method doesn't roll.
+    pub fn get_expression(&self) -> Option<DiceExpression> {
+        match *self {
+            AbilityScoreMethod::DropLowest => {
+                Some(DiceExpression::new(4, 6).keep(Keep::Highest(3)))
+            }
+            AbilityScoreMethod::Straight | AbilityScoreMethod::InOrder => {
+                Some(DiceExpression::new(3, 6))
+            }
+            AbilityScoreMethod::RerollOnes => {
+                Some(DiceExpression::new(4, 6).reroll(1).keep(Keep::Highest(3)))
+            }
+            AbilityScoreMethod::Heroic => Some(DiceExpression::new(2, 6).modifier(6)),
+            AbilityScoreMethod::StandardArray | AbilityScoreMethod::PointBuy => None,
+        }
     }
 
-    // Sort the rolls from lowest to highest (ascending order)
-    ability_score_rolls.sort();
+    /// Whether the scores go to the abilities in the order they're rolled, rather than being
+    /// assigned by the player.
+    pub fn is_in_order(&self) -> bool {
+        return *self == AbilityScoreMethod::InOrder;
+    }
 
-    ability_score_rolls
+    /// Roll the six scores for methods that use dice, in the order they were rolled. Methods
+    /// that don't roll return no rolls.
+    pub fn roll<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<DiceRoll> {
+        return match self.get_expression() {
+            Some(expression) => (0..6).map(|_| expression.roll(rng)).collect(),
+            None => Vec::new(),
+        };
+    }
+
+    /// The six scores to assign from the method's rolls, lowest first, or in ability order for
+    /// methods that are rolled in order. Point buy has nothing to assign and returns `None`.
+    /// Rolling with the same seed gives the same scores, so a disputed roll can be replayed:
+    ///
+    /// ```
+    /// use dnd_character_builder::character::AbilityScoreMethod;
+    /// use dnd_character_builder::dice::seeded_rng;
+    ///
+    /// let method = AbilityScoreMethod::DropLowest;
+    /// let rolls = method.roll(&mut seeded_rng(20));
+    /// let scores = method.get_scores(&rolls).unwrap();
+    /// assert_eq!(scores.len(), 6);
+    /// assert!(scores.windows(2).all(|pair| pair[0] <= pair[1]));
+    /// assert!(scores.iter().all(|score| (3..=18).contains(score)));
+    /// assert_eq!(rolls, method.roll(&mut seeded_rng(20)));
+    ///
+    /// let heroic = AbilityScoreMethod::Heroic;
+    /// let scores = heroic.get_scores(&heroic.roll(&mut seeded_rng(20))).unwrap();
+    /// assert!(scores.iter().all(|score| (8..=18).contains(score)));
+    /// ```
+    pub fn get_scores(&self, rolls: &[DiceRoll]) -> Option<Vec<i8>> {
+        let mut scores = match *self {
+            AbilityScoreMethod::StandardArray => STANDARD_ARRAY.to_vec(),
+            AbilityScoreMethod::PointBuy => return None,
+            _ => rolls.iter().map(|roll| roll.total as i8).collect(),
+        };
+
+        if !self.is_in_order() {
+            scores.sort();
+        }
+        return Some(scores);
+    }
 }
 
 // 2. Implement a function create_character_sheet() that prompts the user for input for each field in the character sheet and returns a new character sheet instance with the specified values.
@@ -247,3 +1166,94 @@ fn create_character_sheet() -> CharacterSheet {
 
     character_sheet
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modifiers_round_down() {
+        let modifiers = [
+            -5, -4, -4, -3, -3, -2, -2, -1, -1, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8,
+            8, 9, 9, 10,
+        ];
+        for (score, modifier) in (1..=30).zip(modifiers) {
+            assert_eq!(score.get_modifier(), modifier, "score {}", score);
+        }
+    }
+
+    fn with_classes(
+        classes: &[(AvailableClasses, Level, Option<AvailableSubclasses>)],
+    ) -> CharacterSheet {
+        let mut character_sheet = CharacterSheet::new();
+        character_sheet.classes = classes
+            .iter()
+            .map(|&(class, level, subclass)| ClassLevel {
+                class,
+                level,
+                subclass,
+            })
+            .collect();
+        return character_sheet;
+    }
+
+    #[test]
+    fn multiclass_spell_slots() {
+        use AvailableClasses::*;
+
+        // full casters add their levels together
+        let character_sheet = with_classes(&[(Wizard, 3, None), (Cleric, 2, None)]);
+        assert_eq!(character_sheet.get_spell_slots(), get_spell_slots(5));
+
+        // half casters add half their levels, rounded down
+        let character_sheet = with_classes(&[(Paladin, 4, None), (Sorcerer, 1, None)]);
+        assert_eq!(character_sheet.get_spell_slots(), get_spell_slots(3));
+
+        // pact magic slots are kept apart from the wizard's
+        let character_sheet = with_classes(&[(Warlock, 3, None), (Wizard, 2, None)]);
+        assert_eq!(character_sheet.get_spell_slots(), get_spell_slots(2));
+        assert_eq!(
+            character_sheet.get_pact_slots(),
+            Some((2, SpellLevel::Level2))
+        );
+    }
+
+    #[test]
+    fn third_caster_spell_slots() {
+        use AvailableClasses::*;
+        use AvailableSubclasses::*;
+
+        // on their own, eldritch knights and arcane tricksters round up
+        let character_sheet = with_classes(&[(Fighter, 3, Some(EldritchKnight))]);
+        assert_eq!(character_sheet.get_spell_slots(), get_spell_slots(1));
+        let character_sheet = with_classes(&[(Rogue, 7, Some(ArcaneTrickster))]);
+        assert_eq!(
+            character_sheet.get_spell_slots(),
+            [4, 2, 0, 0, 0, 0, 0, 0, 0]
+        );
+        let character_sheet = with_classes(&[(Fighter, 20, Some(EldritchKnight))]);
+        assert_eq!(
+            character_sheet.get_spell_slots(),
+            [4, 3, 3, 1, 0, 0, 0, 0, 0]
+        );
+
+        // but only count a third of their levels, rounded down, when multiclassed
+        let character_sheet =
+            with_classes(&[(Fighter, 5, Some(EldritchKnight)), (Wizard, 2, None)]);
+        assert_eq!(character_sheet.get_spell_slots(), get_spell_slots(3));
+
+        // other fighters don't count at all
+        let character_sheet = with_classes(&[(Fighter, 5, Some(Champion)), (Wizard, 2, None)]);
+        assert_eq!(character_sheet.get_spell_slots(), get_spell_slots(2));
+        let character_sheet = with_classes(&[(Fighter, 2, None)]);
+        assert_eq!(character_sheet.get_spell_slots(), get_spell_slots(0));
+    }
+
+    #[test]
+    fn odd_scores_below_ten_round_down() {
+        assert_eq!(9.get_modifier(), -1);
+        assert_eq!(7.get_modifier(), -2);
+        assert_eq!(3.get_modifier(), -4);
+        assert_eq!(1.get_modifier(), -5);
+    }
+}
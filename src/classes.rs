@@ -1,10 +1,20 @@
 use super::*;
+use armor::AvailableArmor;
+use character::{AbilityName, AbilityScores, ArmorCategory, Dice, Feature, Skill, Tool};
 use enum_iterator::{all, Sequence};
+use equipment::{EquipmentChoice, ItemGroup, Pack, StartingItem, Weapon};
 use rand::Rng;
-use std::{collections::HashMap, error::Error, io};
+use serde::{Deserialize, Serialize};
+use spells::Level as SpellLevel;
+use std::{cmp, collections::HashMap, error::Error, io};
 use util::{EnumIter, EnumString};
 
-#[derive(Copy, Clone, Debug, Sequence, EnumIter, EnumString)]
+/// The score each multiclassing prerequisite ability must reach.
+pub const MULTICLASS_MINIMUM_SCORE: i8 = 13;
+
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Sequence, EnumIter, EnumString, Serialize, Deserialize,
+)]
 pub enum AvailableClasses {
     Barbarian,
     Bard,
@@ -19,3 +29,1344 @@ pub enum AvailableClasses {
     Warlock,
     Wizard,
 }
+
+impl AvailableClasses {
+    pub fn get_hit_die(&self) -> Dice {
+        match *self {
+            AvailableClasses::Sorcerer | AvailableClasses::Wizard => Dice::D6,
+            AvailableClasses::Bard
+            | AvailableClasses::Cleric
+            | AvailableClasses::Druid
+            | AvailableClasses::Monk
+            | AvailableClasses::Rogue
+            | AvailableClasses::Warlock => Dice::D8,
+            AvailableClasses::Fighter | AvailableClasses::Paladin | AvailableClasses::Ranger => {
+                Dice::D10
+            }
+            AvailableClasses::Barbarian => Dice::D12,
+        }
+    }
+
+    /// The class's starting equipment as a list of choices. A choice with a single option is
+    /// given to every member of the class.
+    pub fn get_starting_equipment(&self) -> Vec<EquipmentChoice> {
+        let light_crossbow = vec![
+            StartingItem::weapon(Weapon::LightCrossbow, 1),
+            StartingItem::gear("Crossbow bolts", 20),
+        ];
+        let simple_weapon = vec![StartingItem::any(ItemGroup::SimpleWeapon, 1)];
+        let spellcasting_focus = vec![
+            vec![StartingItem::gear("Component pouch", 1)],
+            vec![StartingItem::gear("Arcane focus", 1)],
+        ];
+        match *self {
+            AvailableClasses::Barbarian => vec![
+                vec![
+                    vec![StartingItem::weapon(Weapon::Greataxe, 1)],
+                    vec![StartingItem::any(ItemGroup::MartialMeleeWeapon, 1)],
+                ],
+                vec![
+                    vec![StartingItem::weapon(Weapon::Handaxe, 2)],
+                    simple_weapon,
+                ],
+                vec![vec![
+                    StartingItem::pack(Pack::ExplorersPack),
+                    StartingItem::weapon(Weapon::Javelin, 4),
+                ]],
+            ],
+            AvailableClasses::Bard => vec![
+                vec![
+                    vec![StartingItem::weapon(Weapon::Rapier, 1)],
+                    vec![StartingItem::weapon(Weapon::Longsword, 1)],
+                    simple_weapon,
+                ],
+                vec![
+                    vec![StartingItem::pack(Pack::DiplomatsPack)],
+                    vec![StartingItem::pack(Pack::EntertainersPack)],
+                ],
+                vec![
+                    vec![StartingItem::tool(Tool::Lute)],
+                    vec![StartingItem::any(ItemGroup::MusicalInstrument, 1)],
+                ],
+                vec![vec![
+                    StartingItem::armor(AvailableArmor::Leather),
+                    StartingItem::weapon(Weapon::Dagger, 1),
+                ]],
+            ],
+            AvailableClasses::Cleric => vec![
+                vec![
+                    vec![StartingItem::weapon(Weapon::Mace, 1)],
+                    vec![StartingItem::weapon(Weapon::Warhammer, 1)],
+                ],
+                vec![
+                    vec![StartingItem::armor(AvailableArmor::ScaleMail)],
+                    vec![StartingItem::armor(AvailableArmor::Leather)],
+                    vec![StartingItem::armor(AvailableArmor::ChainMail)],
+                ],
+                vec![light_crossbow, simple_weapon],
+                vec![
+                    vec![StartingItem::pack(Pack::PriestsPack)],
+                    vec![StartingItem::pack(Pack::ExplorersPack)],
+                ],
+                vec![vec![
+                    StartingItem::shield(),
+                    StartingItem::gear("Holy symbol", 1),
+                ]],
+            ],
+            AvailableClasses::Druid => vec![
+                vec![vec![StartingItem::shield()], simple_weapon],
+                vec![
+                    vec![StartingItem::weapon(Weapon::Scimitar, 1)],
+                    vec![StartingItem::any(ItemGroup::SimpleMeleeWeapon, 1)],
+                ],
+                vec![vec![
+                    StartingItem::armor(AvailableArmor::Leather),
+                    StartingItem::pack(Pack::ExplorersPack),
+                    StartingItem::gear("Druidic focus", 1),
+                ]],
+            ],
+            AvailableClasses::Fighter => vec![
+                vec![
+                    vec![StartingItem::armor(AvailableArmor::ChainMail)],
+                    vec![
+                        StartingItem::armor(AvailableArmor::Leather),
+                        StartingItem::weapon(Weapon::Longbow, 1),
+                        StartingItem::gear("Arrows", 20),
+                    ],
+                ],
+                vec![
+                    vec![
+                        StartingItem::any(ItemGroup::MartialWeapon, 1),
+                        StartingItem::shield(),
+                    ],
+                    vec![StartingItem::any(ItemGroup::MartialWeapon, 2)],
+                ],
+                vec![
+                    light_crossbow,
+                    vec![StartingItem::weapon(Weapon::Handaxe, 2)],
+                ],
+                vec![
+                    vec![StartingItem::pack(Pack::DungeoneersPack)],
+                    vec![StartingItem::pack(Pack::ExplorersPack)],
+                ],
+            ],
+            AvailableClasses::Monk => vec![
+                vec![
+                    vec![StartingItem::weapon(Weapon::Shortsword, 1)],
+                    simple_weapon,
+                ],
+                vec![
+                    vec![StartingItem::pack(Pack::DungeoneersPack)],
+                    vec![StartingItem::pack(Pack::ExplorersPack)],
+                ],
+                vec![vec![StartingItem::weapon(Weapon::Dart, 10)]],
+            ],
+            AvailableClasses::Paladin => vec![
+                vec![
+                    vec![
+                        StartingItem::any(ItemGroup::MartialWeapon, 1),
+                        StartingItem::shield(),
+                    ],
+                    vec![StartingItem::any(ItemGroup::MartialWeapon, 2)],
+                ],
+                vec![
+                    vec![StartingItem::weapon(Weapon::Javelin, 5)],
+                    vec![StartingItem::any(ItemGroup::SimpleMeleeWeapon, 1)],
+                ],
+                vec![
+                    vec![StartingItem::pack(Pack::PriestsPack)],
+                    vec![StartingItem::pack(Pack::ExplorersPack)],
+                ],
+                vec![vec![
+                    StartingItem::armor(AvailableArmor::ChainMail),
+                    StartingItem::gear("Holy symbol", 1),
+                ]],
+            ],
+            AvailableClasses::Ranger => vec![
+                vec![
+                    vec![StartingItem::armor(AvailableArmor::ScaleMail)],
+                    vec![StartingItem::armor(AvailableArmor::Leather)],
+                ],
+                vec![
+                    vec![StartingItem::weapon(Weapon::Shortsword, 2)],
+                    vec![StartingItem::any(ItemGroup::SimpleMeleeWeapon, 2)],
+                ],
+                vec![
+                    vec![StartingItem::pack(Pack::DungeoneersPack)],
+                    vec![StartingItem::pack(Pack::ExplorersPack)],
+                ],
+                vec![vec![
+                    StartingItem::weapon(Weapon::Longbow, 1),
+                    StartingItem::gear("Quiver", 1),
+                    StartingItem::gear("Arrows", 20),
+                ]],
+            ],
+            AvailableClasses::Rogue => vec![
+                vec![
+                    vec![StartingItem::weapon(Weapon::Rapier, 1)],
+                    vec![StartingItem::weapon(Weapon::Shortsword, 1)],
+                ],
+                vec![
+                    vec![
+                        StartingItem::weapon(Weapon::Shortbow, 1),
+                        StartingItem::gear("Quiver", 1),
+                        StartingItem::gear("Arrows", 20),
+                    ],
+                    vec![StartingItem::weapon(Weapon::Shortsword, 1)],
+                ],
+                vec![
+                    vec![StartingItem::pack(Pack::BurglarsPack)],
+                    vec![StartingItem::pack(Pack::DungeoneersPack)],
+                    vec![StartingItem::pack(Pack::ExplorersPack)],
+                ],
+                vec![vec![
+                    StartingItem::armor(AvailableArmor::Leather),
+                    StartingItem::weapon(Weapon::Dagger, 2),
+                    StartingItem::tool(Tool::ThievesTools),
+                ]],
+            ],
+            AvailableClasses::Sorcerer => vec![
+                vec![light_crossbow, simple_weapon],
+                spellcasting_focus,
+                vec![
+                    vec![StartingItem::pack(Pack::DungeoneersPack)],
+                    vec![StartingItem::pack(Pack::ExplorersPack)],
+                ],
+                vec![vec![StartingItem::weapon(Weapon::Dagger, 2)]],
+            ],
+            AvailableClasses::Warlock => vec![
+                vec![light_crossbow, simple_weapon],
+                spellcasting_focus,
+                vec![
+                    vec![StartingItem::pack(Pack::ScholarsPack)],
+                    vec![StartingItem::pack(Pack::DungeoneersPack)],
+                ],
+                vec![vec![
+                    StartingItem::armor(AvailableArmor::Leather),
+                    StartingItem::any(ItemGroup::SimpleWeapon, 1),
+                    StartingItem::weapon(Weapon::Dagger, 2),
+                ]],
+            ],
+            AvailableClasses::Wizard => vec![
+                vec![
+                    vec![StartingItem::weapon(Weapon::Quarterstaff, 1)],
+                    vec![StartingItem::weapon(Weapon::Dagger, 1)],
+                ],
+                spellcasting_focus,
+                vec![
+                    vec![StartingItem::pack(Pack::ScholarsPack)],
+                    vec![StartingItem::pack(Pack::ExplorersPack)],
+                ],
+                vec![vec![StartingItem::gear("Spellbook", 1)]],
+            ],
+        }
+    }
+
+    /// The dice rolled for starting gold when buying equipment instead of taking the class's
+    /// starting equipment: a number of d4s and what to multiply their total by.
+    pub fn get_starting_wealth(&self) -> (i8, Dice, u32) {
+        match *self {
+            AvailableClasses::Barbarian | AvailableClasses::Druid => (2, Dice::D4, 10),
+            AvailableClasses::Sorcerer => (3, Dice::D4, 10),
+            AvailableClasses::Rogue | AvailableClasses::Warlock | AvailableClasses::Wizard => {
+                (4, Dice::D4, 10)
+            }
+            AvailableClasses::Monk => (5, Dice::D4, 1),
+            AvailableClasses::Bard
+            | AvailableClasses::Cleric
+            | AvailableClasses::Fighter
+            | AvailableClasses::Paladin
+            | AvailableClasses::Ranger => (5, Dice::D4, 10),
+        }
+    }
+
+    /// The class features gained on reaching the given level. Ability score improvements are
+    /// handled separately, see `has_ability_score_improvement`.
+    pub fn get_features(&self, level: i8) -> Vec<Feature> {
+        let features: Vec<(&str, &str)> = match (*self, level) {
+            (AvailableClasses::Barbarian, 1) => vec![
+                ("Rage", "On your turn you can enter a rage as a bonus action, gaining advantage on Strength checks and saves, bonus melee damage and resistance to bludgeoning, piercing and slashing damage."),
+                ("Unarmored Defense", "While you aren't wearing armor, your AC equals 10 + your Dexterity modifier + your Constitution modifier."),
+            ],
+            (AvailableClasses::Barbarian, 2) => vec![
+                ("Reckless Attack", "You can attack with advantage using Strength on your first attack of a turn, but attacks against you have advantage until your next turn."),
+                ("Danger Sense", "You have advantage on Dexterity saving throws against effects you can see."),
+            ],
+            (AvailableClasses::Barbarian, 3) => vec![("Primal Path", "You choose a path that shapes the nature of your rage.")],
+            (AvailableClasses::Barbarian, 5) => vec![
+                ("Extra Attack", "You can attack twice whenever you take the Attack action on your turn."),
+                ("Fast Movement", "Your speed increases by 10 feet while you aren't wearing heavy armor."),
+            ],
+            (AvailableClasses::Barbarian, 7) => vec![("Feral Instinct", "You have advantage on initiative rolls, and can act normally on a surprised turn if you rage first.")],
+            (AvailableClasses::Barbarian, 9) => vec![("Brutal Critical", "You roll one additional weapon damage die when determining the extra damage for a critical hit with a melee attack.")],
+            (AvailableClasses::Barbarian, 11) => vec![("Relentless Rage", "If you drop to 0 hit points while raging, you can make a Constitution saving throw to drop to 1 hit point instead.")],
+            (AvailableClasses::Barbarian, 15) => vec![("Persistent Rage", "Your rage ends early only if you fall unconscious or choose to end it.")],
+            (AvailableClasses::Barbarian, 18) => vec![("Indomitable Might", "If your total for a Strength check is less than your Strength score, you can use that score in place of the total.")],
+            (AvailableClasses::Barbarian, 20) => vec![("Primal Champion", "Your Strength and Constitution scores increase by 4, to a maximum of 24.")],
+
+            (AvailableClasses::Bard, 1) => vec![
+                ("Bardic Inspiration", "As a bonus action, you can give a creature an inspiration die to add to one ability check, attack roll or saving throw."),
+                ("Spellcasting", "You can cast bard spells using Charisma as your spellcasting ability."),
+            ],
+            (AvailableClasses::Bard, 2) => vec![
+                ("Jack of All Trades", "You add half your proficiency bonus to any ability check that doesn't already include it."),
+                ("Song of Rest", "Allies who hear your performance during a short rest regain extra hit points."),
+            ],
+            (AvailableClasses::Bard, 3) => vec![
+                ("Bard College", "You join a bard college."),
+                ("Expertise", "Your proficiency bonus is doubled for two skills of your choice."),
+            ],
+            (AvailableClasses::Bard, 5) => vec![("Font of Inspiration", "You regain all expended uses of Bardic Inspiration on a short or long rest.")],
+            (AvailableClasses::Bard, 6) => vec![("Countercharm", "You can use a performance to give nearby allies advantage on saves against being frightened or charmed.")],
+            (AvailableClasses::Bard, 10) => vec![
+                ("Expertise", "Your proficiency bonus is doubled for two more skills of your choice."),
+                ("Magical Secrets", "You learn two spells of your choice from any class."),
+            ],
+            (AvailableClasses::Bard, 14) | (AvailableClasses::Bard, 18) => vec![("Magical Secrets", "You learn two more spells of your choice from any class.")],
+            (AvailableClasses::Bard, 20) => vec![("Superior Inspiration", "When you roll initiative with no uses of Bardic Inspiration left, you regain one.")],
+
+            (AvailableClasses::Cleric, 1) => vec![
+                ("Spellcasting", "You can cast cleric spells using Wisdom as your spellcasting ability."),
+                ("Divine Domain", "You choose a domain related to your deity."),
+            ],
+            (AvailableClasses::Cleric, 2) => vec![("Channel Divinity", "You can channel divine energy to Turn Undead or fuel an effect from your domain.")],
+            (AvailableClasses::Cleric, 5) => vec![("Destroy Undead", "Low challenge undead that fail their save against your Turn Undead are destroyed.")],
+            (AvailableClasses::Cleric, 10) => vec![("Divine Intervention", "You can call on your deity to intervene on your behalf.")],
+            (AvailableClasses::Cleric, 20) => vec![("Divine Intervention Improvement", "Your call for divine intervention succeeds automatically.")],
+
+            (AvailableClasses::Druid, 1) => vec![
+                ("Druidic", "You know Druidic, the secret language of druids."),
+                ("Spellcasting", "You can cast druid spells using Wisdom as your spellcasting ability."),
+            ],
+            (AvailableClasses::Druid, 2) => vec![
+                ("Wild Shape", "You can use your action to magically assume the shape of a beast you have seen before."),
+                ("Druid Circle", "You choose to identify with a circle of druids."),
+            ],
+            (AvailableClasses::Druid, 18) => vec![
+                ("Timeless Body", "You age more slowly, only one year for every ten that pass."),
+                ("Beast Spells", "You can cast many of your druid spells in any shape you assume using Wild Shape."),
+            ],
+            (AvailableClasses::Druid, 20) => vec![("Archdruid", "You can use Wild Shape an unlimited number of times.")],
+
+            (AvailableClasses::Fighter, 1) => vec![
+                ("Fighting Style", "You adopt a particular style of fighting as your specialty."),
+                ("Second Wind", "As a bonus action, you can regain 1d10 + your fighter level hit points once per rest."),
+            ],
+            (AvailableClasses::Fighter, 2) => vec![("Action Surge", "Once per rest, you can take one additional action on your turn.")],
+            (AvailableClasses::Fighter, 3) => vec![("Martial Archetype", "You choose an archetype that you strive to emulate in your combat styles.")],
+            (AvailableClasses::Fighter, 5) => vec![("Extra Attack", "You can attack twice whenever you take the Attack action on your turn.")],
+            (AvailableClasses::Fighter, 9) => vec![("Indomitable", "Once per long rest, you can reroll a saving throw that you fail.")],
+            (AvailableClasses::Fighter, 11) => vec![("Extra Attack (2)", "You can attack three times whenever you take the Attack action on your turn.")],
+            (AvailableClasses::Fighter, 20) => vec![("Extra Attack (3)", "You can attack four times whenever you take the Attack action on your turn.")],
+
+            (AvailableClasses::Monk, 1) => vec![
+                ("Unarmored Defense", "While you wear no armor and wield no shield, your AC equals 10 + your Dexterity modifier + your Wisdom modifier."),
+                ("Martial Arts", "You can use Dexterity for unarmed strikes and monk weapons, and make an unarmed strike as a bonus action."),
+            ],
+            (AvailableClasses::Monk, 2) => vec![
+                ("Ki", "You can spend ki points to fuel Flurry of Blows, Patient Defense and Step of the Wind."),
+                ("Unarmored Movement", "Your speed increases while you aren't wearing armor or wielding a shield."),
+            ],
+            (AvailableClasses::Monk, 3) => vec![
+                ("Monastic Tradition", "You commit yourself to a monastic tradition."),
+                ("Deflect Missiles", "You can use your reaction to reduce the damage of a ranged weapon attack that hits you."),
+            ],
+            (AvailableClasses::Monk, 4) => vec![("Slow Fall", "You can use your reaction to reduce falling damage by five times your monk level.")],
+            (AvailableClasses::Monk, 5) => vec![
+                ("Extra Attack", "You can attack twice whenever you take the Attack action on your turn."),
+                ("Stunning Strike", "You can spend a ki point to try to stun a creature you hit with a melee weapon attack."),
+            ],
+            (AvailableClasses::Monk, 6) => vec![("Ki-Empowered Strikes", "Your unarmed strikes count as magical.")],
+            (AvailableClasses::Monk, 7) => vec![
+                ("Evasion", "When an effect allows a Dexterity save for half damage, you take none on a success and half on a failure."),
+                ("Stillness of Mind", "You can use your action to end one effect on yourself that is causing you to be charmed or frightened."),
+            ],
+            (AvailableClasses::Monk, 10) => vec![("Purity of Body", "You are immune to disease and poison.")],
+            (AvailableClasses::Monk, 13) => vec![("Tongue of the Sun and Moon", "You understand all spoken languages, and any creature that understands a language can understand you.")],
+            (AvailableClasses::Monk, 14) => vec![("Diamond Soul", "You are proficient in all saving throws, and can spend a ki point to reroll a failed save.")],
+            (AvailableClasses::Monk, 15) => vec![("Timeless Body", "You suffer none of the frailty of old age and no longer need food or water.")],
+            (AvailableClasses::Monk, 18) => vec![("Empty Body", "You can spend ki points to become invisible or to cast astral projection.")],
+            (AvailableClasses::Monk, 20) => vec![("Perfect Self", "When you roll initiative with no ki points remaining, you regain 4 ki points.")],
+
+            (AvailableClasses::Paladin, 1) => vec![
+                ("Divine Sense", "You can detect the presence of strong evil or good nearby."),
+                ("Lay on Hands", "You have a pool of healing power equal to five times your paladin level."),
+            ],
+            (AvailableClasses::Paladin, 2) => vec![
+                ("Fighting Style", "You adopt a particular style of fighting as your specialty."),
+                ("Spellcasting", "You can cast paladin spells using Charisma as your spellcasting ability."),
+                ("Divine Smite", "When you hit with a melee weapon attack, you can expend a spell slot to deal extra radiant damage."),
+            ],
+            (AvailableClasses::Paladin, 3) => vec![
+                ("Divine Health", "You are immune to disease."),
+                ("Sacred Oath", "You swear the oath that binds you as a paladin forever."),
+            ],
+            (AvailableClasses::Paladin, 5) => vec![("Extra Attack", "You can attack twice whenever you take the Attack action on your turn.")],
+            (AvailableClasses::Paladin, 6) => vec![("Aura of Protection", "You and friendly creatures near you add your Charisma modifier to saving throws.")],
+            (AvailableClasses::Paladin, 10) => vec![("Aura of Courage", "You and friendly creatures near you can't be frightened while you are conscious.")],
+            (AvailableClasses::Paladin, 11) => vec![("Improved Divine Smite", "Your melee weapon hits deal an extra 1d8 radiant damage.")],
+            (AvailableClasses::Paladin, 14) => vec![("Cleansing Touch", "You can use your action to end one spell on yourself or a willing creature you touch.")],
+
+            (AvailableClasses::Ranger, 1) => vec![
+                ("Favored Enemy", "You have advantage on tracking and recalling information about a type of enemy you choose."),
+                ("Natural Explorer", "You are particularly familiar with one type of natural environment."),
+            ],
+            (AvailableClasses::Ranger, 2) => vec![
+                ("Fighting Style", "You adopt a particular style of fighting as your specialty."),
+                ("Spellcasting", "You can cast ranger spells using Wisdom as your spellcasting ability."),
+            ],
+            (AvailableClasses::Ranger, 3) => vec![
+                ("Ranger Archetype", "You choose an archetype that you strive to emulate."),
+                ("Primeval Awareness", "You can expend a spell slot to sense certain types of creatures nearby."),
+            ],
+            (AvailableClasses::Ranger, 5) => vec![("Extra Attack", "You can attack twice whenever you take the Attack action on your turn.")],
+            (AvailableClasses::Ranger, 8) => vec![("Land's Stride", "Moving through nonmagical difficult terrain costs you no extra movement.")],
+            (AvailableClasses::Ranger, 10) => vec![("Hide in Plain Sight", "You can camouflage yourself to gain a bonus to Stealth checks while you remain still.")],
+            (AvailableClasses::Ranger, 14) => vec![("Vanish", "You can Hide as a bonus action, and can't be tracked by nonmagical means.")],
+            (AvailableClasses::Ranger, 18) => vec![("Feral Senses", "You can fight creatures you can't see without disadvantage.")],
+            (AvailableClasses::Ranger, 20) => vec![("Foe Slayer", "Once per turn, you can add your Wisdom modifier to an attack or damage roll against a favored enemy.")],
+
+            (AvailableClasses::Rogue, 1) => vec![
+                ("Expertise", "Your proficiency bonus is doubled for two skills of your choice."),
+                ("Sneak Attack", "Once per turn, you deal extra damage to a creature you hit with advantage or next to an ally."),
+                ("Thieves' Cant", "You know thieves' cant, a secret mix of dialect, jargon and code."),
+            ],
+            (AvailableClasses::Rogue, 2) => vec![("Cunning Action", "You can Dash, Disengage or Hide as a bonus action.")],
+            (AvailableClasses::Rogue, 3) => vec![("Roguish Archetype", "You choose an archetype that you emulate in the exercise of your rogue abilities.")],
+            (AvailableClasses::Rogue, 5) => vec![("Uncanny Dodge", "You can use your reaction to halve the damage of an attack from an attacker you can see.")],
+            (AvailableClasses::Rogue, 6) => vec![("Expertise", "Your proficiency bonus is doubled for two more skills of your choice.")],
+            (AvailableClasses::Rogue, 7) => vec![("Evasion", "When an effect allows a Dexterity save for half damage, you take none on a success and half on a failure.")],
+            (AvailableClasses::Rogue, 11) => vec![("Reliable Talent", "When you make an ability check that uses a proficient skill, you treat a d20 roll of 9 or lower as a 10.")],
+            (AvailableClasses::Rogue, 14) => vec![("Blindsense", "You are aware of the location of any hidden or invisible creature within 10 feet of you.")],
+            (AvailableClasses::Rogue, 15) => vec![("Slippery Mind", "You gain proficiency in Wisdom saving throws.")],
+            (AvailableClasses::Rogue, 18) => vec![("Elusive", "No attack roll has advantage against you while you aren't incapacitated.")],
+            (AvailableClasses::Rogue, 20) => vec![("Stroke of Luck", "Once per rest, you can turn a miss into a hit or a failed ability check into a 20.")],
+
+            (AvailableClasses::Sorcerer, 1) => vec![
+                ("Spellcasting", "You can cast sorcerer spells using Charisma as your spellcasting ability."),
+                ("Sorcerous Origin", "You choose the source of your innate magical power."),
+            ],
+            (AvailableClasses::Sorcerer, 2) => vec![("Font of Magic", "You have sorcery points that you can turn into spell slots and back.")],
+            (AvailableClasses::Sorcerer, 3) => vec![("Metamagic", "You gain two Metamagic options for twisting your spells to suit your needs.")],
+            (AvailableClasses::Sorcerer, 10) | (AvailableClasses::Sorcerer, 17) => vec![("Metamagic", "You gain another Metamagic option.")],
+            (AvailableClasses::Sorcerer, 20) => vec![("Sorcerous Restoration", "You regain 4 expended sorcery points whenever you finish a short rest.")],
+
+            (AvailableClasses::Warlock, 1) => vec![
+                ("Otherworldly Patron", "You have struck a bargain with an otherworldly being."),
+                ("Pact Magic", "You can cast warlock spells using Charisma as your spellcasting ability, regaining slots on a short rest."),
+            ],
+            (AvailableClasses::Warlock, 2) => vec![("Eldritch Invocations", "You learn two eldritch invocations of your choice.")],
+            (AvailableClasses::Warlock, 3) => vec![("Pact Boon", "Your patron bestows a gift upon you: a pact of the chain, blade or tome.")],
+            (AvailableClasses::Warlock, 11) => vec![("Mystic Arcanum", "Your patron bestows a 6th level spell you can cast once per long rest.")],
+            (AvailableClasses::Warlock, 13) | (AvailableClasses::Warlock, 15) | (AvailableClasses::Warlock, 17) => vec![("Mystic Arcanum", "Your patron bestows another spell you can cast once per long rest.")],
+            (AvailableClasses::Warlock, 20) => vec![("Eldritch Master", "Once per long rest, you can spend a minute entreating your patron to regain all your expended spell slots.")],
+
+            (AvailableClasses::Wizard, 1) => vec![
+                ("Spellcasting", "You can cast wizard spells from your spellbook using Intelligence as your spellcasting ability."),
+                ("Arcane Recovery", "Once per day during a short rest, you can recover some expended spell slots."),
+            ],
+            (AvailableClasses::Wizard, 2) => vec![("Arcane Tradition", "You choose an arcane tradition, shaping your practice of magic.")],
+            (AvailableClasses::Wizard, 18) => vec![("Spell Mastery", "You can cast a chosen 1st level and 2nd level spell at their lowest level without expending a slot.")],
+            (AvailableClasses::Wizard, 20) => vec![("Signature Spells", "You can cast two chosen 3rd level spells once each without expending a slot.")],
+            _ => Vec::new(),
+        };
+        return features
+            .into_iter()
+            .map(|(name, description)| Feature::new(name, description))
+            .collect();
+    }
+
+    /// The subclasses the class chooses between, see `get_subclass_level`.
+    pub fn get_subclasses(&self) -> Vec<AvailableSubclasses> {
+        match *self {
+            AvailableClasses::Barbarian => vec![
+                AvailableSubclasses::Berserker,
+                AvailableSubclasses::TotemWarrior,
+            ],
+            AvailableClasses::Bard => vec![AvailableSubclasses::Lore, AvailableSubclasses::Valor],
+            AvailableClasses::Cleric => vec![
+                AvailableSubclasses::Knowledge,
+                AvailableSubclasses::Life,
+                AvailableSubclasses::Light,
+                AvailableSubclasses::Nature,
+                AvailableSubclasses::Tempest,
+                AvailableSubclasses::Trickery,
+                AvailableSubclasses::War,
+            ],
+            AvailableClasses::Druid => vec![AvailableSubclasses::Land, AvailableSubclasses::Moon],
+            AvailableClasses::Fighter => vec![
+                AvailableSubclasses::Champion,
+                AvailableSubclasses::BattleMaster,
+                AvailableSubclasses::EldritchKnight,
+            ],
+            AvailableClasses::Monk => vec![
+                AvailableSubclasses::OpenHand,
+                AvailableSubclasses::Shadow,
+                AvailableSubclasses::FourElements,
+            ],
+            AvailableClasses::Paladin => vec![
+                AvailableSubclasses::Devotion,
+                AvailableSubclasses::Ancients,
+                AvailableSubclasses::Vengeance,
+            ],
+            AvailableClasses::Ranger => vec![
+                AvailableSubclasses::Hunter,
+                AvailableSubclasses::BeastMaster,
+            ],
+            AvailableClasses::Rogue => vec![
+                AvailableSubclasses::Thief,
+                AvailableSubclasses::Assassin,
+                AvailableSubclasses::ArcaneTrickster,
+            ],
+            AvailableClasses::Sorcerer => vec![
+                AvailableSubclasses::DraconicBloodline,
+                AvailableSubclasses::WildMagic,
+            ],
+            AvailableClasses::Warlock => vec![
+                AvailableSubclasses::Archfey,
+                AvailableSubclasses::Fiend,
+                AvailableSubclasses::GreatOldOne,
+            ],
+            AvailableClasses::Wizard => vec![
+                AvailableSubclasses::Abjuration,
+                AvailableSubclasses::Conjuration,
+                AvailableSubclasses::Divination,
+                AvailableSubclasses::Enchantment,
+                AvailableSubclasses::Evocation,
+                AvailableSubclasses::Illusion,
+                AvailableSubclasses::Necromancy,
+                AvailableSubclasses::Transmutation,
+            ],
+        }
+    }
+
+    /// The class level at which the subclass is chosen.
+    pub fn get_subclass_level(&self) -> i8 {
+        match *self {
+            AvailableClasses::Cleric | AvailableClasses::Sorcerer | AvailableClasses::Warlock => 1,
+            AvailableClasses::Druid | AvailableClasses::Wizard => 2,
+            _ => 3,
+        }
+    }
+
+    /// Whether the class gains an ability score improvement (or a feat instead) at this level.
+    pub fn has_ability_score_improvement(&self, level: i8) -> bool {
+        match *self {
+            AvailableClasses::Fighter => [4, 6, 8, 12, 14, 16, 19].contains(&level),
+            AvailableClasses::Rogue => [4, 8, 10, 12, 16, 19].contains(&level),
+            _ => [4, 8, 12, 16, 19].contains(&level),
+        }
+    }
+
+    pub fn get_spellcasting_progression(&self) -> Option<SpellcastingProgression> {
+        match *self {
+            AvailableClasses::Bard
+            | AvailableClasses::Cleric
+            | AvailableClasses::Druid
+            | AvailableClasses::Sorcerer
+            | AvailableClasses::Wizard => Some(SpellcastingProgression::Full),
+            AvailableClasses::Paladin | AvailableClasses::Ranger => {
+                Some(SpellcastingProgression::Half)
+            }
+            AvailableClasses::Warlock => Some(SpellcastingProgression::Pact),
+            AvailableClasses::Barbarian
+            | AvailableClasses::Fighter
+            | AvailableClasses::Monk
+            | AvailableClasses::Rogue => None,
+        }
+    }
+
+    /// The ability scores needed to multiclass into or out of the class. Every group must be
+    /// met, and a group is met when any one of its abilities is at least 13.
+    pub fn get_multiclass_prerequisites(&self) -> Vec<Vec<AbilityName>> {
+        match *self {
+            AvailableClasses::Barbarian => vec![vec![AbilityName::Strength]],
+            AvailableClasses::Bard | AvailableClasses::Sorcerer | AvailableClasses::Warlock => {
+                vec![vec![AbilityName::Charisma]]
+            }
+            AvailableClasses::Cleric | AvailableClasses::Druid => vec![vec![AbilityName::Wisdom]],
+            AvailableClasses::Fighter => {
+                vec![vec![AbilityName::Strength, AbilityName::Dexterity]]
+            }
+            AvailableClasses::Monk | AvailableClasses::Ranger => {
+                vec![vec![AbilityName::Dexterity], vec![AbilityName::Wisdom]]
+            }
+            AvailableClasses::Paladin => {
+                vec![vec![AbilityName::Strength], vec![AbilityName::Charisma]]
+            }
+            AvailableClasses::Rogue => vec![vec![AbilityName::Dexterity]],
+            AvailableClasses::Wizard => vec![vec![AbilityName::Intelligence]],
+        }
+    }
+
+    pub fn meets_multiclass_prerequisites(&self, ability_scores: &AbilityScores) -> bool {
+        return self.get_multiclass_prerequisites().iter().all(|group| {
+            group
+                .iter()
+                .any(|ability| ability_scores.get(*ability) >= MULTICLASS_MINIMUM_SCORE)
+        });
+    }
+
+    /// The armor proficiencies gained when multiclassing into the class, a subset of those a
+    /// character starting in the class gets.
+    pub fn get_multiclass_armor_proficiencies(&self) -> Vec<ArmorCategory> {
+        match *self {
+            AvailableClasses::Barbarian => vec![ArmorCategory::Shields],
+            AvailableClasses::Bard | AvailableClasses::Rogue | AvailableClasses::Warlock => {
+                vec![ArmorCategory::Light]
+            }
+            AvailableClasses::Cleric
+            | AvailableClasses::Druid
+            | AvailableClasses::Fighter
+            | AvailableClasses::Paladin
+            | AvailableClasses::Ranger => vec![
+                ArmorCategory::Light,
+                ArmorCategory::Medium,
+                ArmorCategory::Shields,
+            ],
+            AvailableClasses::Monk | AvailableClasses::Sorcerer | AvailableClasses::Wizard => {
+                Vec::new()
+            }
+        }
+    }
+
+    /// The number of skills from the class's skill list gained when multiclassing into it.
+    pub fn get_multiclass_skill_count(&self) -> usize {
+        match *self {
+            AvailableClasses::Bard | AvailableClasses::Ranger | AvailableClasses::Rogue => 1,
+            _ => 0,
+        }
+    }
+
+    /// The tool proficiencies gained when multiclassing into the class, each as a list of
+    /// options to choose from.
+    pub fn get_multiclass_tool_proficiencies(&self) -> Vec<Vec<Tool>> {
+        match *self {
+            AvailableClasses::Bard => vec![Tool::musical_instruments()],
+            AvailableClasses::Rogue => vec![vec![Tool::ThievesTools]],
+            _ => Vec::new(),
+        }
+    }
+
+    /// The abilities the class relies on most, most important first, following the quick build
+    /// suggestions in the Player's Handbook.
+    pub fn get_primary_abilities(&self) -> [AbilityName; 2] {
+        match *self {
+            AvailableClasses::Barbarian | AvailableClasses::Fighter => {
+                [AbilityName::Strength, AbilityName::Constitution]
+            }
+            AvailableClasses::Bard => [AbilityName::Charisma, AbilityName::Dexterity],
+            AvailableClasses::Cleric => [AbilityName::Wisdom, AbilityName::Strength],
+            AvailableClasses::Druid => [AbilityName::Wisdom, AbilityName::Constitution],
+            AvailableClasses::Monk | AvailableClasses::Ranger => {
+                [AbilityName::Dexterity, AbilityName::Wisdom]
+            }
+            AvailableClasses::Paladin => [AbilityName::Strength, AbilityName::Charisma],
+            AvailableClasses::Rogue => [AbilityName::Dexterity, AbilityName::Intelligence],
+            AvailableClasses::Sorcerer | AvailableClasses::Warlock => {
+                [AbilityName::Charisma, AbilityName::Constitution]
+            }
+            AvailableClasses::Wizard => [AbilityName::Intelligence, AbilityName::Constitution],
+        }
+    }
+
+    pub fn get_saving_throws(&self) -> [AbilityName; 2] {
+        match *self {
+            AvailableClasses::Barbarian | AvailableClasses::Fighter => {
+                [AbilityName::Strength, AbilityName::Constitution]
+            }
+            AvailableClasses::Bard => [AbilityName::Dexterity, AbilityName::Charisma],
+            AvailableClasses::Cleric | AvailableClasses::Paladin | AvailableClasses::Warlock => {
+                [AbilityName::Wisdom, AbilityName::Charisma]
+            }
+            AvailableClasses::Druid | AvailableClasses::Wizard => {
+                [AbilityName::Intelligence, AbilityName::Wisdom]
+            }
+            AvailableClasses::Monk | AvailableClasses::Ranger => {
+                [AbilityName::Strength, AbilityName::Dexterity]
+            }
+            AvailableClasses::Rogue => [AbilityName::Dexterity, AbilityName::Intelligence],
+            AvailableClasses::Sorcerer => [AbilityName::Constitution, AbilityName::Charisma],
+        }
+    }
+
+    /// The number of skill proficiencies chosen at 1st level, and the skills to choose from.
+    pub fn get_skill_choices(&self) -> (usize, Vec<Skill>) {
+        match *self {
+            AvailableClasses::Barbarian => (
+                2,
+                vec![
+                    Skill::AnimalHandling,
+                    Skill::Athletics,
+                    Skill::Intimidation,
+                    Skill::Nature,
+                    Skill::Perception,
+                    Skill::Survival,
+                ],
+            ),
+            AvailableClasses::Bard => (3, Skill::iter()),
+            AvailableClasses::Cleric => (
+                2,
+                vec![
+                    Skill::History,
+                    Skill::Insight,
+                    Skill::Medicine,
+                    Skill::Persuasion,
+                    Skill::Religion,
+                ],
+            ),
+            AvailableClasses::Druid => (
+                2,
+                vec![
+                    Skill::Arcana,
+                    Skill::AnimalHandling,
+                    Skill::Insight,
+                    Skill::Medicine,
+                    Skill::Nature,
+                    Skill::Perception,
+                    Skill::Religion,
+                    Skill::Survival,
+                ],
+            ),
+            AvailableClasses::Fighter => (
+                2,
+                vec![
+                    Skill::Acrobatics,
+                    Skill::AnimalHandling,
+                    Skill::Athletics,
+                    Skill::History,
+                    Skill::Insight,
+                    Skill::Intimidation,
+                    Skill::Perception,
+                    Skill::Survival,
+                ],
+            ),
+            AvailableClasses::Monk => (
+                2,
+                vec![
+                    Skill::Acrobatics,
+                    Skill::Athletics,
+                    Skill::History,
+                    Skill::Insight,
+                    Skill::Religion,
+                    Skill::Stealth,
+                ],
+            ),
+            AvailableClasses::Paladin => (
+                2,
+                vec![
+                    Skill::Athletics,
+                    Skill::Insight,
+                    Skill::Intimidation,
+                    Skill::Medicine,
+                    Skill::Persuasion,
+                    Skill::Religion,
+                ],
+            ),
+            AvailableClasses::Ranger => (
+                3,
+                vec![
+                    Skill::AnimalHandling,
+                    Skill::Athletics,
+                    Skill::Insight,
+                    Skill::Investigation,
+                    Skill::Nature,
+                    Skill::Perception,
+                    Skill::Stealth,
+                    Skill::Survival,
+                ],
+            ),
+            AvailableClasses::Rogue => (
+                4,
+                vec![
+                    Skill::Acrobatics,
+                    Skill::Athletics,
+                    Skill::Deception,
+                    Skill::Insight,
+                    Skill::Intimidation,
+                    Skill::Investigation,
+                    Skill::Perception,
+                    Skill::Performance,
+                    Skill::Persuasion,
+                    Skill::SleightOfHand,
+                    Skill::Stealth,
+                ],
+            ),
+            AvailableClasses::Sorcerer => (
+                2,
+                vec![
+                    Skill::Arcana,
+                    Skill::Deception,
+                    Skill::Insight,
+                    Skill::Intimidation,
+                    Skill::Persuasion,
+                    Skill::Religion,
+                ],
+            ),
+            AvailableClasses::Warlock => (
+                2,
+                vec![
+                    Skill::Arcana,
+                    Skill::Deception,
+                    Skill::History,
+                    Skill::Intimidation,
+                    Skill::Investigation,
+                    Skill::Nature,
+                    Skill::Religion,
+                ],
+            ),
+            AvailableClasses::Wizard => (
+                2,
+                vec![
+                    Skill::Arcana,
+                    Skill::History,
+                    Skill::Insight,
+                    Skill::Investigation,
+                    Skill::Medicine,
+                    Skill::Religion,
+                ],
+            ),
+        }
+    }
+
+    /// The number of skill proficiencies that gain expertise at the given class level.
+    pub fn get_expertise_count(&self, level: i8) -> usize {
+        match *self {
+            AvailableClasses::Rogue if level >= 6 => 4,
+            AvailableClasses::Rogue if level >= 1 => 2,
+            AvailableClasses::Bard if level >= 10 => 4,
+            AvailableClasses::Bard if level >= 3 => 2,
+            _ => 0,
+        }
+    }
+
+    pub fn get_armor_proficiencies(&self) -> Vec<ArmorCategory> {
+        match *self {
+            AvailableClasses::Barbarian
+            | AvailableClasses::Cleric
+            | AvailableClasses::Druid
+            | AvailableClasses::Ranger => vec![
+                ArmorCategory::Light,
+                ArmorCategory::Medium,
+                ArmorCategory::Shields,
+            ],
+            AvailableClasses::Fighter | AvailableClasses::Paladin => vec![
+                ArmorCategory::Light,
+                ArmorCategory::Medium,
+                ArmorCategory::Heavy,
+                ArmorCategory::Shields,
+            ],
+            AvailableClasses::Bard | AvailableClasses::Rogue | AvailableClasses::Warlock => {
+                vec![ArmorCategory::Light]
+            }
+            AvailableClasses::Monk | AvailableClasses::Sorcerer | AvailableClasses::Wizard => {
+                vec![]
+            }
+        }
+    }
+
+    /// The ability used for spell attack rolls and save DCs, or `None` if the class doesn't
+    /// cast spells.
+    pub fn get_spellcasting_ability(&self) -> Option<AbilityName> {
+        match *self {
+            AvailableClasses::Bard
+            | AvailableClasses::Paladin
+            | AvailableClasses::Sorcerer
+            | AvailableClasses::Warlock => Some(AbilityName::Charisma),
+            AvailableClasses::Cleric | AvailableClasses::Druid | AvailableClasses::Ranger => {
+                Some(AbilityName::Wisdom)
+            }
+            AvailableClasses::Wizard => Some(AbilityName::Intelligence),
+            _ => None,
+        }
+    }
+
+    /// The highest level of spell the class can cast at the given class level, or `None` if
+    /// it can't cast leveled spells yet.
+    pub fn get_max_spell_level(&self, level: i8) -> Option<SpellLevel> {
+        let max = match *self {
+            AvailableClasses::Bard
+            | AvailableClasses::Cleric
+            | AvailableClasses::Druid
+            | AvailableClasses::Sorcerer
+            | AvailableClasses::Wizard => cmp::min((level + 1) / 2, 9),
+            AvailableClasses::Warlock => cmp::min((level + 1) / 2, 5),
+            AvailableClasses::Paladin | AvailableClasses::Ranger if level >= 2 => {
+                cmp::min((level + 3) / 4, 5)
+            }
+            _ => 0,
+        };
+        if max <= 0 {
+            return None;
+        }
+        return Some(SpellLevel::from_number(max as u8));
+    }
+
+    pub fn get_cantrips_known(&self, level: i8) -> usize {
+        let tier = match level {
+            1..=3 => 0,
+            4..=9 => 1,
+            _ => 2,
+        };
+        let known = match *self {
+            AvailableClasses::Bard | AvailableClasses::Druid | AvailableClasses::Warlock => {
+                [2, 3, 4]
+            }
+            AvailableClasses::Cleric | AvailableClasses::Wizard => [3, 4, 5],
+            AvailableClasses::Sorcerer => [4, 5, 6],
+            _ => [0, 0, 0],
+        };
+        return known[tier];
+    }
+
+    /// The number of leveled spells the class knows (Bard, Ranger, Sorcerer, Warlock), has
+    /// prepared (Cleric, Druid, Paladin) or has in its spellbook (Wizard) at the given level.
+    pub fn get_spells_known(&self, level: i8, spellcasting_modifier: i8) -> usize {
+        let level = level.clamp(1, 20);
+        let index = (level - 1) as usize;
+        let known: i8 = match *self {
+            AvailableClasses::Bard => [
+                4, 5, 6, 7, 8, 9, 10, 11, 12, 14, 15, 15, 16, 18, 19, 19, 20, 22, 22, 22,
+            ][index],
+            AvailableClasses::Ranger => [
+                0, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11,
+            ][index],
+            AvailableClasses::Sorcerer => [
+                2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 12, 13, 13, 14, 14, 15, 15, 15, 15,
+            ][index],
+            AvailableClasses::Warlock => [
+                2, 3, 4, 5, 6, 7, 8, 9, 10, 10, 11, 11, 12, 12, 13, 13, 14, 14, 15, 15,
+            ][index],
+            AvailableClasses::Cleric | AvailableClasses::Druid => {
+                cmp::max(1, spellcasting_modifier + level)
+            }
+            AvailableClasses::Paladin if level >= 2 => {
+                cmp::max(1, spellcasting_modifier + level / 2)
+            }
+            AvailableClasses::Wizard => 6 + (level - 1) * 2,
+            _ => 0,
+        };
+        return known as usize;
+    }
+}
+
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Sequence, EnumIter, EnumString, Serialize, Deserialize,
+)]
+pub enum AvailableSubclasses {
+    Berserker,
+    TotemWarrior,
+    Lore,
+    Valor,
+    Knowledge,
+    Life,
+    Light,
+    Nature,
+    Tempest,
+    Trickery,
+    War,
+    Land,
+    Moon,
+    Champion,
+    BattleMaster,
+    EldritchKnight,
+    OpenHand,
+    Shadow,
+    FourElements,
+    Devotion,
+    Ancients,
+    Vengeance,
+    Hunter,
+    BeastMaster,
+    Thief,
+    Assassin,
+    ArcaneTrickster,
+    DraconicBloodline,
+    WildMagic,
+    Archfey,
+    Fiend,
+    GreatOldOne,
+    Abjuration,
+    Conjuration,
+    Divination,
+    Enchantment,
+    Evocation,
+    Illusion,
+    Necromancy,
+    Transmutation,
+}
+
+impl AvailableSubclasses {
+    pub fn get_class(&self) -> AvailableClasses {
+        return AvailableClasses::iter()
+            .into_iter()
+            .find(|class| class.get_subclasses().contains(self))
+            .unwrap();
+    }
+
+    /// Eldritch knights and arcane tricksters learn wizard spells from their subclass, gaining
+    /// spell slots at a third of the rate of a full spellcaster.
+    pub fn get_spellcasting_progression(&self) -> Option<SpellcastingProgression> {
+        match *self {
+            AvailableSubclasses::EldritchKnight | AvailableSubclasses::ArcaneTrickster => {
+                Some(SpellcastingProgression::Third)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn get_spellcasting_ability(&self) -> Option<AbilityName> {
+        return self
+            .get_spellcasting_progression()
+            .map(|_| AbilityName::Intelligence);
+    }
+
+    /// The highest level of spell the subclass can cast at the given class level, or `None` if
+    /// it can't cast leveled spells.
+    pub fn get_max_spell_level(&self, level: i8) -> Option<SpellLevel> {
+        let max = match self.get_spellcasting_progression() {
+            Some(SpellcastingProgression::Third) if level >= 3 => cmp::min((level + 5) / 6, 4),
+            _ => 0,
+        };
+        if max <= 0 {
+            return None;
+        }
+        return Some(SpellLevel::from_number(max as u8));
+    }
+
+    pub fn get_cantrips_known(&self, level: i8) -> usize {
+        let known = match *self {
+            AvailableSubclasses::EldritchKnight => [2, 3],
+            // including the mage hand every arcane trickster knows
+            AvailableSubclasses::ArcaneTrickster => [3, 4],
+            _ => return 0,
+        };
+        return match level {
+            i8::MIN..=2 => 0,
+            3..=9 => known[0],
+            _ => known[1],
+        };
+    }
+
+    /// The number of leveled spells the subclass knows at the given class level.
+    pub fn get_spells_known(&self, level: i8) -> usize {
+        if self.get_spellcasting_progression().is_none() || level < 3 {
+            return 0;
+        }
+        let index = (level.clamp(3, 20) - 3) as usize;
+        return [3, 4, 4, 4, 5, 6, 6, 7, 8, 8, 9, 10, 10, 11, 11, 11, 12, 13][index];
+    }
+
+    /// The subclass features gained on reaching the given level in the subclass's class.
+    pub fn get_features(&self, level: i8) -> Vec<Feature> {
+        let features: Vec<(&str, &str)> = match (*self, level) {
+            (AvailableSubclasses::Berserker, 3) => vec![("Frenzy", "While raging, you can make a single melee weapon attack as a bonus action on each of your turns, at the cost of exhaustion afterwards.")],
+            (AvailableSubclasses::Berserker, 6) => vec![("Mindless Rage", "You can't be charmed or frightened while raging.")],
+            (AvailableSubclasses::Berserker, 10) => vec![("Intimidating Presence", "You can use your action to frighten a creature with your menacing presence.")],
+            (AvailableSubclasses::Berserker, 14) => vec![("Retaliation", "When a creature within 5 feet damages you, you can use your reaction to make a melee weapon attack against it.")],
+            (AvailableSubclasses::TotemWarrior, 3) => vec![
+                ("Spirit Seeker", "You can cast beast sense and speak with animals as rituals."),
+                ("Totem Spirit", "You choose a bear, eagle or wolf totem spirit that empowers your rage."),
+            ],
+            (AvailableSubclasses::TotemWarrior, 6) => vec![("Aspect of the Beast", "You gain a magical benefit based on a totem animal of your choice.")],
+            (AvailableSubclasses::TotemWarrior, 10) => vec![("Spirit Walker", "You can cast commune with nature as a ritual.")],
+            (AvailableSubclasses::TotemWarrior, 14) => vec![("Totemic Attunement", "You gain a magical benefit based on a totem animal of your choice while raging.")],
+
+            (AvailableSubclasses::Lore, 3) => vec![
+                ("Bonus Proficiencies", "You gain proficiency with three skills of your choice."),
+                ("Cutting Words", "You can use your reaction and a Bardic Inspiration die to reduce a creature's attack roll, ability check or damage roll."),
+            ],
+            (AvailableSubclasses::Lore, 6) => vec![("Additional Magical Secrets", "You learn two spells of your choice from any class.")],
+            (AvailableSubclasses::Lore, 14) => vec![("Peerless Skill", "You can add a Bardic Inspiration die to your own ability checks.")],
+            (AvailableSubclasses::Valor, 3) => vec![
+                ("Bonus Proficiencies", "You gain proficiency with medium armor, shields and martial weapons."),
+                ("Combat Inspiration", "A creature with your Bardic Inspiration die can add it to a weapon damage roll or to its AC against an attack."),
+            ],
+            (AvailableSubclasses::Valor, 6) => vec![("Extra Attack", "You can attack twice whenever you take the Attack action on your turn.")],
+            (AvailableSubclasses::Valor, 14) => vec![("Battle Magic", "When you use your action to cast a bard spell, you can make one weapon attack as a bonus action.")],
+
+            (AvailableSubclasses::Knowledge, 1) => vec![("Blessings of Knowledge", "You learn two languages and gain expertise in two of Arcana, History, Nature or Religion.")],
+            (AvailableSubclasses::Knowledge, 2) => vec![("Channel Divinity: Knowledge of the Ages", "You can gain proficiency with a skill or tool for 10 minutes.")],
+            (AvailableSubclasses::Knowledge, 6) => vec![("Channel Divinity: Read Thoughts", "You can read a creature's surface thoughts and cast suggestion on it.")],
+            (AvailableSubclasses::Knowledge, 8) => vec![("Potent Spellcasting", "You add your Wisdom modifier to the damage you deal with cleric cantrips.")],
+            (AvailableSubclasses::Knowledge, 17) => vec![("Visions of the Past", "You can call up visions of the past relating to an object or your surroundings.")],
+            (AvailableSubclasses::Life, 1) => vec![
+                ("Bonus Proficiency", "You gain proficiency with heavy armor."),
+                ("Disciple of Life", "Your healing spells restore additional hit points equal to 2 + the spell's level."),
+            ],
+            (AvailableSubclasses::Life, 2) => vec![("Channel Divinity: Preserve Life", "You can restore hit points equal to five times your cleric level, divided among nearby creatures.")],
+            (AvailableSubclasses::Life, 6) => vec![("Blessed Healer", "When you cast a healing spell on another creature, you also regain hit points.")],
+            (AvailableSubclasses::Life, 8) => vec![("Divine Strike", "Once per turn, your weapon attacks deal an extra 1d8 radiant damage.")],
+            (AvailableSubclasses::Life, 17) => vec![("Supreme Healing", "You use the highest number possible for each die when restoring hit points with a spell.")],
+            (AvailableSubclasses::Light, 1) => vec![
+                ("Bonus Cantrip", "You know the light cantrip."),
+                ("Warding Flare", "You can use your reaction to impose disadvantage on an attack against you."),
+            ],
+            (AvailableSubclasses::Light, 2) => vec![("Channel Divinity: Radiance of the Dawn", "You dispel magical darkness and deal radiant damage to hostile creatures near you.")],
+            (AvailableSubclasses::Light, 6) => vec![("Improved Flare", "You can use Warding Flare to protect other creatures.")],
+            (AvailableSubclasses::Light, 8) => vec![("Potent Spellcasting", "You add your Wisdom modifier to the damage you deal with cleric cantrips.")],
+            (AvailableSubclasses::Light, 17) => vec![("Corona of Light", "You can emit an aura of sunlight that imposes disadvantage on saves against fire and radiant spells.")],
+            (AvailableSubclasses::Nature, 1) => vec![
+                ("Acolyte of Nature", "You learn one druid cantrip and gain proficiency in Animal Handling, Nature or Survival."),
+                ("Bonus Proficiency", "You gain proficiency with heavy armor."),
+            ],
+            (AvailableSubclasses::Nature, 2) => vec![("Channel Divinity: Charm Animals and Plants", "You can charm nearby beasts and plant creatures.")],
+            (AvailableSubclasses::Nature, 6) => vec![("Dampen Elements", "You can use your reaction to grant resistance against acid, cold, fire, lightning or thunder damage.")],
+            (AvailableSubclasses::Nature, 8) => vec![("Divine Strike", "Once per turn, your weapon attacks deal an extra 1d8 cold, fire or lightning damage.")],
+            (AvailableSubclasses::Nature, 17) => vec![("Master of Nature", "You can command creatures charmed by your Channel Divinity.")],
+            (AvailableSubclasses::Tempest, 1) => vec![
+                ("Bonus Proficiencies", "You gain proficiency with martial weapons and heavy armor."),
+                ("Wrath of the Storm", "You can use your reaction to deal lightning or thunder damage to a creature that hits you."),
+            ],
+            (AvailableSubclasses::Tempest, 2) => vec![("Channel Divinity: Destructive Wrath", "You can deal maximum damage with a lightning or thunder damage roll.")],
+            (AvailableSubclasses::Tempest, 6) => vec![("Thunderbolt Strike", "Your lightning damage can push Large or smaller creatures away from you.")],
+            (AvailableSubclasses::Tempest, 8) => vec![("Divine Strike", "Once per turn, your weapon attacks deal an extra 1d8 thunder damage.")],
+            (AvailableSubclasses::Tempest, 17) => vec![("Stormborn", "You have a flying speed equal to your walking speed when outdoors.")],
+            (AvailableSubclasses::Trickery, 1) => vec![("Blessing of the Trickster", "You can give a willing creature advantage on Stealth checks for an hour.")],
+            (AvailableSubclasses::Trickery, 2) => vec![("Channel Divinity: Invoke Duplicity", "You create an illusory duplicate of yourself.")],
+            (AvailableSubclasses::Trickery, 6) => vec![("Channel Divinity: Cloak of Shadows", "You can become invisible until the end of your next turn.")],
+            (AvailableSubclasses::Trickery, 8) => vec![("Divine Strike", "Once per turn, your weapon attacks deal an extra 1d8 poison damage.")],
+            (AvailableSubclasses::Trickery, 17) => vec![("Improved Duplicity", "You can create up to four duplicates of yourself.")],
+            (AvailableSubclasses::War, 1) => vec![
+                ("Bonus Proficiencies", "You gain proficiency with martial weapons and heavy armor."),
+                ("War Priest", "When you take the Attack action, you can make a weapon attack as a bonus action."),
+            ],
+            (AvailableSubclasses::War, 2) => vec![("Channel Divinity: Guided Strike", "You can gain a +10 bonus to an attack roll.")],
+            (AvailableSubclasses::War, 6) => vec![("Channel Divinity: War God's Blessing", "You can grant a nearby creature a +10 bonus to an attack roll.")],
+            (AvailableSubclasses::War, 8) => vec![("Divine Strike", "Once per turn, your weapon attacks deal an extra 1d8 damage of the weapon's type.")],
+            (AvailableSubclasses::War, 17) => vec![("Avatar of Battle", "You have resistance to bludgeoning, piercing and slashing damage from nonmagical weapons.")],
+
+            (AvailableSubclasses::Land, 2) => vec![
+                ("Bonus Cantrip", "You learn one additional druid cantrip."),
+                ("Natural Recovery", "During a short rest, you can recover some expended spell slots."),
+            ],
+            (AvailableSubclasses::Land, 3) => vec![("Circle Spells", "You always have certain spells prepared, based on the land where you became a druid.")],
+            (AvailableSubclasses::Land, 6) => vec![("Land's Stride", "Moving through nonmagical difficult terrain costs you no extra movement.")],
+            (AvailableSubclasses::Land, 10) => vec![("Nature's Ward", "You can't be charmed or frightened by elementals or fey, and you are immune to poison and disease.")],
+            (AvailableSubclasses::Land, 14) => vec![("Nature's Sanctuary", "Beasts and plant creatures must make a Wisdom save to attack you.")],
+            (AvailableSubclasses::Moon, 2) => vec![
+                ("Combat Wild Shape", "You can use Wild Shape as a bonus action, and spend spell slots to heal while transformed."),
+                ("Circle Forms", "You can transform into beasts with a challenge rating as high as 1."),
+            ],
+            (AvailableSubclasses::Moon, 6) => vec![("Primal Strike", "Your attacks in beast form count as magical.")],
+            (AvailableSubclasses::Moon, 10) => vec![("Elemental Wild Shape", "You can expend two uses of Wild Shape to transform into an elemental.")],
+            (AvailableSubclasses::Moon, 14) => vec![("Thousand Forms", "You can cast alter self at will.")],
+
+            (AvailableSubclasses::Champion, 3) => vec![("Improved Critical", "Your weapon attacks score a critical hit on a roll of 19 or 20.")],
+            (AvailableSubclasses::Champion, 7) => vec![("Remarkable Athlete", "You add half your proficiency bonus to Strength, Dexterity and Constitution checks that don't already use it.")],
+            (AvailableSubclasses::Champion, 10) => vec![("Additional Fighting Style", "You choose a second Fighting Style.")],
+            (AvailableSubclasses::Champion, 15) => vec![("Superior Critical", "Your weapon attacks score a critical hit on a roll of 18 to 20.")],
+            (AvailableSubclasses::Champion, 18) => vec![("Survivor", "At the start of each of your turns, you regain hit points if you have no more than half of your hit points left.")],
+            (AvailableSubclasses::BattleMaster, 3) => vec![
+                ("Combat Superiority", "You learn maneuvers fueled by superiority dice."),
+                ("Student of War", "You gain proficiency with one type of artisan's tools."),
+            ],
+            (AvailableSubclasses::BattleMaster, 7) => vec![("Know Your Enemy", "You can learn how a creature compares to you after observing it for a minute.")],
+            (AvailableSubclasses::BattleMaster, 10) => vec![("Improved Combat Superiority", "Your superiority dice turn into d10s.")],
+            (AvailableSubclasses::BattleMaster, 15) => vec![("Relentless", "When you roll initiative with no superiority dice, you regain one.")],
+            (AvailableSubclasses::BattleMaster, 18) => vec![("Improved Combat Superiority", "Your superiority dice turn into d12s.")],
+            (AvailableSubclasses::EldritchKnight, 3) => vec![
+                ("Spellcasting", "You can cast wizard spells, mostly from the abjuration and evocation schools, using Intelligence."),
+                ("Weapon Bond", "You can bond with up to two weapons, which can't be disarmed and can be summoned to your hand."),
+            ],
+            (AvailableSubclasses::EldritchKnight, 7) => vec![("War Magic", "When you use your action to cast a cantrip, you can make one weapon attack as a bonus action.")],
+            (AvailableSubclasses::EldritchKnight, 10) => vec![("Eldritch Strike", "A creature you hit with a weapon attack has disadvantage on its next save against your spells.")],
+            (AvailableSubclasses::EldritchKnight, 15) => vec![("Arcane Charge", "When you use Action Surge, you can teleport up to 30 feet.")],
+            (AvailableSubclasses::EldritchKnight, 18) => vec![("Improved War Magic", "When you use your action to cast a spell, you can make one weapon attack as a bonus action.")],
+
+            (AvailableSubclasses::OpenHand, 3) => vec![("Open Hand Technique", "Your Flurry of Blows can knock creatures prone, push them or stop their reactions.")],
+            (AvailableSubclasses::OpenHand, 6) => vec![("Wholeness of Body", "You can use your action to regain hit points equal to three times your monk level.")],
+            (AvailableSubclasses::OpenHand, 11) => vec![("Tranquility", "At the end of a long rest, you gain the effect of a sanctuary spell.")],
+            (AvailableSubclasses::OpenHand, 17) => vec![("Quivering Palm", "You can set up lethal vibrations in a creature you hit with an unarmed strike.")],
+            (AvailableSubclasses::Shadow, 3) => vec![("Shadow Arts", "You can spend ki points to cast darkness, darkvision, pass without trace or silence.")],
+            (AvailableSubclasses::Shadow, 6) => vec![("Shadow Step", "You can teleport from one shadow to another as a bonus action.")],
+            (AvailableSubclasses::Shadow, 11) => vec![("Cloak of Shadows", "You can become invisible while in dim light or darkness.")],
+            (AvailableSubclasses::Shadow, 17) => vec![("Opportunist", "You can use your reaction to attack a creature that is hit by another creature's attack.")],
+            (AvailableSubclasses::FourElements, 3) => vec![("Disciple of the Elements", "You learn elemental disciplines fueled by ki points.")],
+            (AvailableSubclasses::FourElements, 6) | (AvailableSubclasses::FourElements, 11) | (AvailableSubclasses::FourElements, 17) => vec![("Elemental Discipline", "You learn an additional elemental discipline.")],
+
+            (AvailableSubclasses::Devotion, 3) => vec![("Channel Divinity: Sacred Weapon and Turn the Unholy", "You can imbue a weapon with positive energy or turn fiends and undead.")],
+            (AvailableSubclasses::Devotion, 7) => vec![("Aura of Devotion", "You and friendly creatures near you can't be charmed while you are conscious.")],
+            (AvailableSubclasses::Devotion, 15) => vec![("Purity of Spirit", "You are always under the effects of a protection from evil and good spell.")],
+            (AvailableSubclasses::Devotion, 20) => vec![("Holy Nimbus", "You can emanate an aura of sunlight that damages enemies.")],
+            (AvailableSubclasses::Ancients, 3) => vec![("Channel Divinity: Nature's Wrath and Turn the Faithless", "You can restrain a creature with spectral vines or turn fey and fiends.")],
+            (AvailableSubclasses::Ancients, 7) => vec![("Aura of Warding", "You and friendly creatures near you have resistance to damage from spells.")],
+            (AvailableSubclasses::Ancients, 15) => vec![("Undying Sentinel", "When reduced to 0 hit points, you can drop to 1 hit point instead once per long rest.")],
+            (AvailableSubclasses::Ancients, 20) => vec![("Elder Champion", "You can transform into a force of nature, regaining hit points and casting spells faster.")],
+            (AvailableSubclasses::Vengeance, 3) => vec![("Channel Divinity: Abjure Enemy and Vow of Enmity", "You can frighten a creature or gain advantage on attacks against it.")],
+            (AvailableSubclasses::Vengeance, 7) => vec![("Relentless Avenger", "When you hit a creature with an opportunity attack, you can move after it.")],
+            (AvailableSubclasses::Vengeance, 15) => vec![("Soul of Vengeance", "You can attack the target of your Vow of Enmity as a reaction when it attacks.")],
+            (AvailableSubclasses::Vengeance, 20) => vec![("Avenging Angel", "You can sprout wings and emanate an aura of menace.")],
+
+            (AvailableSubclasses::Hunter, 3) => vec![("Hunter's Prey", "You gain Colossus Slayer, Giant Killer or Horde Breaker.")],
+            (AvailableSubclasses::Hunter, 7) => vec![("Defensive Tactics", "You gain Escape the Horde, Multiattack Defense or Steel Will.")],
+            (AvailableSubclasses::Hunter, 11) => vec![("Multiattack", "You gain Volley or Whirlwind Attack.")],
+            (AvailableSubclasses::Hunter, 15) => vec![("Superior Hunter's Defense", "You gain Evasion, Stand Against the Tide or Uncanny Dodge.")],
+            (AvailableSubclasses::BeastMaster, 3) => vec![("Ranger's Companion", "You gain a beast companion that fights alongside you.")],
+            (AvailableSubclasses::BeastMaster, 7) => vec![("Exceptional Training", "Your companion can Dash, Disengage, Dodge or Help as a bonus action.")],
+            (AvailableSubclasses::BeastMaster, 11) => vec![("Bestial Fury", "Your companion can make two attacks when you command it to Attack.")],
+            (AvailableSubclasses::BeastMaster, 15) => vec![("Share Spells", "Spells you cast targeting yourself can also affect your companion.")],
+
+            (AvailableSubclasses::Thief, 3) => vec![
+                ("Fast Hands", "You can use Cunning Action to make Sleight of Hand checks, use thieves' tools or use an object."),
+                ("Second-Story Work", "You climb faster and jump further."),
+            ],
+            (AvailableSubclasses::Thief, 9) => vec![("Supreme Sneak", "You have advantage on Stealth checks if you move no more than half your speed.")],
+            (AvailableSubclasses::Thief, 13) => vec![("Use Magic Device", "You ignore class, race and level requirements on the use of magic items.")],
+            (AvailableSubclasses::Thief, 17) => vec![("Thief's Reflexes", "You can take two turns during the first round of any combat.")],
+            (AvailableSubclasses::Assassin, 3) => vec![
+                ("Bonus Proficiencies", "You gain proficiency with the disguise kit and the poisoner's kit."),
+                ("Assassinate", "You have advantage against creatures that haven't acted yet, and hits against surprised creatures are critical."),
+            ],
+            (AvailableSubclasses::Assassin, 9) => vec![("Infiltration Expertise", "You can create false identities for yourself.")],
+            (AvailableSubclasses::Assassin, 13) => vec![("Impostor", "You can unerringly mimic another person's speech, writing and behavior.")],
+            (AvailableSubclasses::Assassin, 17) => vec![("Death Strike", "A surprised creature you hit must make a Constitution save or take double damage.")],
+            (AvailableSubclasses::ArcaneTrickster, 3) => vec![
+                ("Spellcasting", "You can cast wizard spells, mostly from the enchantment and illusion schools, using Intelligence."),
+                ("Mage Hand Legerdemain", "Your mage hand is invisible and can pick locks and pockets."),
+            ],
+            (AvailableSubclasses::ArcaneTrickster, 9) => vec![("Magical Ambush", "Creatures have disadvantage on saves against your spells if you are hidden from them.")],
+            (AvailableSubclasses::ArcaneTrickster, 13) => vec![("Versatile Trickster", "You can use your mage hand to distract a creature, gaining advantage on attacks against it.")],
+            (AvailableSubclasses::ArcaneTrickster, 17) => vec![("Spell Thief", "You can use your reaction to steal a spell cast at you.")],
+
+            (AvailableSubclasses::DraconicBloodline, 1) => vec![
+                ("Dragon Ancestor", "You choose a dragon ancestor, and can speak, read and write Draconic."),
+                ("Draconic Resilience", "Your hit point maximum increases by 1 per sorcerer level, and your AC is 13 + Dexterity modifier when unarmored."),
+            ],
+            (AvailableSubclasses::DraconicBloodline, 6) => vec![("Elemental Affinity", "You add your Charisma modifier to damage from spells of your ancestor's damage type.")],
+            (AvailableSubclasses::DraconicBloodline, 14) => vec![("Dragon Wings", "You can sprout dragon wings, gaining a flying speed.")],
+            (AvailableSubclasses::DraconicBloodline, 18) => vec![("Draconic Presence", "You can exude an aura of awe or fear.")],
+            (AvailableSubclasses::WildMagic, 1) => vec![
+                ("Wild Magic Surge", "Casting a sorcerer spell can unleash a surge of wild magic."),
+                ("Tides of Chaos", "You can gain advantage on one attack roll, ability check or saving throw."),
+            ],
+            (AvailableSubclasses::WildMagic, 6) => vec![("Bend Luck", "You can spend sorcery points to add or subtract 1d4 from another creature's roll.")],
+            (AvailableSubclasses::WildMagic, 14) => vec![("Controlled Chaos", "You can roll twice on the Wild Magic Surge table and use either number.")],
+            (AvailableSubclasses::WildMagic, 18) => vec![("Spell Bombardment", "You can reroll a damage die that rolls its highest number and add it to the damage.")],
+
+            (AvailableSubclasses::Archfey, 1) => vec![("Fey Presence", "You can charm or frighten creatures in a 10-foot cube.")],
+            (AvailableSubclasses::Archfey, 6) => vec![("Misty Escape", "When you take damage, you can turn invisible and teleport up to 60 feet.")],
+            (AvailableSubclasses::Archfey, 10) => vec![("Beguiling Defenses", "You are immune to being charmed, and can turn charm attempts back on their source.")],
+            (AvailableSubclasses::Archfey, 14) => vec![("Dark Delirium", "You can plunge a creature into an illusory realm.")],
+            (AvailableSubclasses::Fiend, 1) => vec![("Dark One's Blessing", "When you reduce a hostile creature to 0 hit points, you gain temporary hit points.")],
+            (AvailableSubclasses::Fiend, 6) => vec![("Dark One's Own Luck", "You can add a d10 to an ability check or saving throw.")],
+            (AvailableSubclasses::Fiend, 10) => vec![("Fiendish Resilience", "You gain resistance to a damage type of your choice after each rest.")],
+            (AvailableSubclasses::Fiend, 14) => vec![("Hurl Through Hell", "When you hit a creature, you can send it through the lower planes.")],
+            (AvailableSubclasses::GreatOldOne, 1) => vec![("Awakened Mind", "You can speak telepathically to any creature within 30 feet.")],
+            (AvailableSubclasses::GreatOldOne, 6) => vec![("Entropic Ward", "You can impose disadvantage on an attack against you, and gain advantage on your next attack if it misses.")],
+            (AvailableSubclasses::GreatOldOne, 10) => vec![("Thought Shield", "Your thoughts can't be read, and you have resistance to psychic damage.")],
+            (AvailableSubclasses::GreatOldOne, 14) => vec![("Create Thrall", "You can charm an incapacitated humanoid indefinitely.")],
+
+            (AvailableSubclasses::Abjuration, 2) => vec![
+                ("Abjuration Savant", "Copying abjuration spells into your spellbook takes half the gold and time."),
+                ("Arcane Ward", "Casting abjuration spells creates a magical ward that absorbs damage."),
+            ],
+            (AvailableSubclasses::Abjuration, 6) => vec![("Projected Ward", "Your Arcane Ward can absorb damage dealt to nearby creatures.")],
+            (AvailableSubclasses::Abjuration, 10) => vec![("Improved Abjuration", "You add your proficiency bonus to ability checks made as part of abjuration spells.")],
+            (AvailableSubclasses::Abjuration, 14) => vec![("Spell Resistance", "You have advantage on saves against spells and resistance to their damage.")],
+            (AvailableSubclasses::Conjuration, 2) => vec![
+                ("Conjuration Savant", "Copying conjuration spells into your spellbook takes half the gold and time."),
+                ("Minor Conjuration", "You can conjure an inanimate object in your hand."),
+            ],
+            (AvailableSubclasses::Conjuration, 6) => vec![("Benign Transposition", "You can teleport up to 30 feet, or swap places with a willing creature.")],
+            (AvailableSubclasses::Conjuration, 10) => vec![("Focused Conjuration", "Damage can't break your concentration on a conjuration spell.")],
+            (AvailableSubclasses::Conjuration, 14) => vec![("Durable Summons", "Creatures you summon or create gain 30 temporary hit points.")],
+            (AvailableSubclasses::Divination, 2) => vec![
+                ("Divination Savant", "Copying divination spells into your spellbook takes half the gold and time."),
+                ("Portent", "After a long rest, you roll two d20s that you can use in place of rolls made by creatures you can see."),
+            ],
+            (AvailableSubclasses::Divination, 6) => vec![("Expert Divination", "Casting divination spells of 2nd level or higher recovers a lower level spell slot.")],
+            (AvailableSubclasses::Divination, 10) => vec![("The Third Eye", "You can gain darkvision, ethereal sight, comprehension of languages or see invisibility.")],
+            (AvailableSubclasses::Divination, 14) => vec![("Greater Portent", "You roll three d20s for Portent rather than two.")],
+            (AvailableSubclasses::Enchantment, 2) => vec![
+                ("Enchantment Savant", "Copying enchantment spells into your spellbook takes half the gold and time."),
+                ("Hypnotic Gaze", "You can charm and incapacitate a creature next to you with your gaze."),
+            ],
+            (AvailableSubclasses::Enchantment, 6) => vec![("Instinctive Charm", "You can divert an attack against you to another creature.")],
+            (AvailableSubclasses::Enchantment, 10) => vec![("Split Enchantment", "Your enchantment spells that target one creature can target a second.")],
+            (AvailableSubclasses::Enchantment, 14) => vec![("Alter Memories", "A creature you charm can be made unaware that it was charmed.")],
+            (AvailableSubclasses::Evocation, 2) => vec![
+                ("Evocation Savant", "Copying evocation spells into your spellbook takes half the gold and time."),
+                ("Sculpt Spells", "Chosen creatures automatically succeed on saves against your evocation spells and take no damage."),
+            ],
+            (AvailableSubclasses::Evocation, 6) => vec![("Potent Cantrip", "Creatures that succeed on a save against your cantrips still take half damage.")],
+            (AvailableSubclasses::Evocation, 10) => vec![("Empowered Evocation", "You add your Intelligence modifier to the damage of your wizard evocation spells.")],
+            (AvailableSubclasses::Evocation, 14) => vec![("Overchannel", "You can deal maximum damage with a wizard spell of 5th level or lower.")],
+            (AvailableSubclasses::Illusion, 2) => vec![
+                ("Illusion Savant", "Copying illusion spells into your spellbook takes half the gold and time."),
+                ("Improved Minor Illusion", "You learn minor illusion, and can create both a sound and an image with it."),
+            ],
+            (AvailableSubclasses::Illusion, 6) => vec![("Malleable Illusions", "You can change the nature of your lasting illusions.")],
+            (AvailableSubclasses::Illusion, 10) => vec![("Illusory Self", "You can use your reaction to make an attack against you miss automatically.")],
+            (AvailableSubclasses::Illusion, 14) => vec![("Illusory Reality", "You can make one inanimate object in your illusion real for a minute.")],
+            (AvailableSubclasses::Necromancy, 2) => vec![
+                ("Necromancy Savant", "Copying necromancy spells into your spellbook takes half the gold and time."),
+                ("Grim Harvest", "When you kill a creature with a spell, you regain hit points."),
+            ],
+            (AvailableSubclasses::Necromancy, 6) => vec![("Undead Thralls", "Undead you create are tougher, and you learn animate dead.")],
+            (AvailableSubclasses::Necromancy, 10) => vec![("Inured to Undeath", "You have resistance to necrotic damage, and your hit point maximum can't be reduced.")],
+            (AvailableSubclasses::Necromancy, 14) => vec![("Command Undead", "You can bring undead under your control.")],
+            (AvailableSubclasses::Transmutation, 2) => vec![
+                ("Transmutation Savant", "Copying transmutation spells into your spellbook takes half the gold and time."),
+                ("Minor Alchemy", "You can temporarily change what an object is made of."),
+            ],
+            (AvailableSubclasses::Transmutation, 6) => vec![("Transmuter's Stone", "You can create a stone that grants a benefit to whoever carries it.")],
+            (AvailableSubclasses::Transmutation, 10) => vec![("Shapechanger", "You learn polymorph, and can cast it on yourself without a spell slot.")],
+            (AvailableSubclasses::Transmutation, 14) => vec![("Master Transmuter", "You can destroy your transmuter's stone to perform a major transformation.")],
+            _ => Vec::new(),
+        };
+        return features
+            .into_iter()
+            .map(|(name, description)| Feature::new(name, description))
+            .collect();
+    }
+}
+
+/// How quickly a class gains spell slots, which decides how its levels count towards the shared
+/// spell slots of a multiclassed spellcaster.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SpellcastingProgression {
+    Full,
+    Half,
+    Third,
+    Pact,
+}
+
+/// The spell slots of each level from 1st to 9th for a spellcaster of the given caster level.
+pub fn get_spell_slots(caster_level: i8) -> [usize; 9] {
+    return match caster_level {
+        i8::MIN..=0 => [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        1 => [2, 0, 0, 0, 0, 0, 0, 0, 0],
+        2 => [3, 0, 0, 0, 0, 0, 0, 0, 0],
+        3 => [4, 2, 0, 0, 0, 0, 0, 0, 0],
+        4 => [4, 3, 0, 0, 0, 0, 0, 0, 0],
+        5 => [4, 3, 2, 0, 0, 0, 0, 0, 0],
+        6 => [4, 3, 3, 0, 0, 0, 0, 0, 0],
+        7 => [4, 3, 3, 1, 0, 0, 0, 0, 0],
+        8 => [4, 3, 3, 2, 0, 0, 0, 0, 0],
+        9 => [4, 3, 3, 3, 1, 0, 0, 0, 0],
+        10 => [4, 3, 3, 3, 2, 0, 0, 0, 0],
+        11 | 12 => [4, 3, 3, 3, 2, 1, 0, 0, 0],
+        13 | 14 => [4, 3, 3, 3, 2, 1, 1, 0, 0],
+        15 | 16 => [4, 3, 3, 3, 2, 1, 1, 1, 0],
+        17 => [4, 3, 3, 3, 2, 1, 1, 1, 1],
+        18 => [4, 3, 3, 3, 3, 1, 1, 1, 1],
+        19 => [4, 3, 3, 3, 3, 2, 1, 1, 1],
+        _ => [4, 3, 3, 3, 3, 2, 2, 1, 1],
+    };
+}
+
+/// The number of warlock pact magic slots and their level for the given warlock level.
+pub fn get_pact_slots(warlock_level: i8) -> Option<(usize, SpellLevel)> {
+    return match warlock_level {
+        i8::MIN..=0 => None,
+        1 => Some((1, SpellLevel::Level1)),
+        2 => Some((2, SpellLevel::Level1)),
+        3 | 4 => Some((2, SpellLevel::Level2)),
+        5 | 6 => Some((2, SpellLevel::Level3)),
+        7 | 8 => Some((2, SpellLevel::Level4)),
+        9 | 10 => Some((2, SpellLevel::Level5)),
+        11..=16 => Some((3, SpellLevel::Level5)),
+        _ => Some((4, SpellLevel::Level5)),
+    };
+}
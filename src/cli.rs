@@ -0,0 +1,713 @@
+use super::*;
+use answers::{parse_choice, Answers, InvalidChoice};
+use character::{AbilityName, AbilityScoreMethod, AbilityScores, CharacterSheet, MAX_LEVEL};
+use controller::{default_file_name, render_character_sheet, App};
+use dice::{seeded_rng, DiceExpression, DiceExpressionError};
+use pdf::to_pdf;
+use rand::Rng;
+use random::{exclude_alignments, random_answers, Constraints};
+use std::{error::Error, fmt, fs, path::Path};
+use storage::{load_character, save_character, to_json, StorageError};
+use util::EnumIter;
+
+pub const USAGE: &str = "\
+Usage: dnd-character-builder [command] [options]
+
+Commands:
+  new [--seed N] [--methods LIST] [--answers FILE]
+                                        Create a character with the interactive wizard (default),
+                                        asking only what the answers file leaves out
+  edit <file> [--seed N] [--methods LIST] [--answers FILE]
+                                        Open a saved character in the wizard
+  create --name NAME --race RACE --class CLASS [options]
+                                        Create a character without any prompts
+      --subrace SUBRACE --subclass SUBCLASS --alignment ALIGNMENT --background BACKGROUND
+      --abilities STR,DEX,CON,INT,WIS,CHA --method METHOD
+      --answers FILE --seed N --output FILE
+  random [options]                      Generate a random character, printing it or saving it
+      --race LIST --class LIST --background LIST --alignment LIST --methods LIST
+                                        Pick only from these
+      --exclude-alignment LIST          Never pick these, where good, evil, lawful and chaotic
+                                        rule out every alignment of that kind
+      --name NAME --subrace SUBRACE --subclass SUBCLASS --answers FILE --seed N --output FILE
+  show <file>                           Print a saved character
+  roll <expression> [--seed N]          Roll dice, e.g. 2d6+3, 4d6kh3, 1d20adv
+  export <file> --format json|text|pdf [--output FILE]
+                                        Write a character out in another format; PDFs are
+                                        written next to the character file by default
+  level-up <file> [options]             Level up a saved character without any prompts
+      --experience POINTS --class CLASS --subclass SUBCLASS --roll-hit-points
+      --increase ABILITY[,ABILITY] --answers FILE --seed N --output FILE
+  verify <file>                         Check a character's ability scores against their roll log
+  help                                  Show this message";
+
+#[derive(Debug)]
+pub enum CliError {
+    /// The command line couldn't be understood.
+    Usage(String),
+    /// An option was given a value it doesn't accept.
+    InvalidValue {
+        option: String,
+        message: String,
+    },
+    Storage {
+        path: String,
+        error: StorageError,
+    },
+    /// The character can't do what was asked, e.g. level up without the experience.
+    NotAllowed(String),
+    /// `verify` found problems with the roll log, which it has already reported.
+    VerificationFailed(usize),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CliError::Usage(message) => write!(f, "{}\n\n{}", message, USAGE),
+            CliError::InvalidValue { option, message } => write!(f, "{}: {}", option, message),
+            CliError::Storage { path, error } => write!(f, "{}: {}", path, error),
+            CliError::NotAllowed(message) => write!(f, "{}", message),
+            CliError::VerificationFailed(count) => {
+                write!(f, "the roll log has {} problem(s)", count)
+            }
+        }
+    }
+}
+
+impl Error for CliError {}
+
+fn invalid(option: &str, error: impl fmt::Display) -> CliError {
+    return CliError::InvalidValue {
+        option: option.to_string(),
+        message: error.to_string(),
+    };
+}
+
+/// A command's arguments once split into positional arguments and `--option value` pairs.
+struct Arguments {
+    positional: Vec<String>,
+    options: Vec<(String, Option<String>)>,
+}
+
+impl Arguments {
+    /// Split the arguments, rejecting any option the command doesn't take. `flags` are options
+    /// that don't take a value.
+    fn parse(args: &[String], options: &[&str], flags: &[&str]) -> Result<Arguments, CliError> {
+        let mut arguments = Arguments {
+            positional: Vec::new(),
+            options: Vec::new(),
+        };
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            if !arg.starts_with("--") {
+                arguments.positional.push(arg.clone());
+            } else if flags.contains(&arg.as_str()) {
+                arguments.options.push((arg.clone(), None));
+            } else if options.contains(&arg.as_str()) {
+                let value = args
+                    .next()
+                    .ok_or_else(|| CliError::Usage(format!("{} needs a value", arg)))?;
+                arguments.options.push((arg.clone(), Some(value.clone())));
+            } else {
+                return Err(CliError::Usage(format!("unknown option {}", arg)));
+            }
+        }
+        return Ok(arguments);
+    }
+
+    fn get(&self, option: &str) -> Option<&str> {
+        return self
+            .options
+            .iter()
+            .rev()
+            .find(|(name, _)| name == option)
+            .and_then(|(_, value)| value.as_deref());
+    }
+
+    fn has(&self, flag: &str) -> bool {
+        return self.options.iter().any(|(name, _)| name == flag);
+    }
+
+    fn require(&self, option: &str) -> Result<&str, CliError> {
+        return self
+            .get(option)
+            .ok_or_else(|| CliError::Usage(format!("{} is required", option)));
+    }
+
+    /// The single positional argument a command takes, such as a file name.
+    fn single(&self, name: &str) -> Result<&str, CliError> {
+        return match &self.positional[..] {
+            [value] => Ok(value),
+            [] => Err(CliError::Usage(format!("missing {}", name))),
+            _ => Err(CliError::Usage(format!(
+                "expected one {}, got {}",
+                name,
+                self.positional.join(" ")
+            ))),
+        };
+    }
+
+    fn choice<T: EnumIter + fmt::Debug + fmt::Display>(
+        &self,
+        option: &str,
+        kind: &'static str,
+    ) -> Result<Option<T>, CliError> {
+        return match self.get(option) {
+            Some(value) => parse_choice(kind, value)
+                .map(Some)
+                .map_err(|error| invalid(option, error)),
+            None => Ok(None),
+        };
+    }
+
+    /// A comma-separated list of choices, or an empty list if the option isn't given.
+    fn list<T: EnumIter + fmt::Debug + fmt::Display>(
+        &self,
+        option: &str,
+        kind: &'static str,
+    ) -> Result<Vec<T>, CliError> {
+        return match self.get(option) {
+            Some(value) => parse_list(option, kind, value),
+            None => Ok(Vec::new()),
+        };
+    }
+
+    fn seed(&self) -> Result<u64, CliError> {
+        return match self.get("--seed") {
+            Some(seed) => seed
+                .parse()
+                .map_err(|_| invalid("--seed", "expected a whole number, e.g. --seed 42")),
+            None => Ok(rand::thread_rng().gen()),
+        };
+    }
+}
+
+/// Parse six comma-separated scores in ability order, e.g. "15,14,13,12,10,8".
+fn parse_ability_scores(value: &str) -> Result<AbilityScores, CliError> {
+    let scores = value
+        .split(',')
+        .map(|score| score.trim().parse::<i8>())
+        .collect::<Result<Vec<i8>, _>>()
+        .map_err(|_| {
+            invalid(
+                "--abilities",
+                format!("{:?} isn't a list of numbers", value),
+            )
+        })?;
+    let abilities = AbilityName::iter();
+    if scores.len() != abilities.len() {
+        return Err(invalid(
+            "--abilities",
+            format!(
+                "expected {} scores in the order STR,DEX,CON,INT,WIS,CHA, got {}",
+                abilities.len(),
+                scores.len()
+            ),
+        ));
+    }
+
+    let mut ability_scores = AbilityScores::default();
+    for (ability, score) in abilities.into_iter().zip(scores) {
+        if !(3..=20).contains(&score) {
+            return Err(invalid(
+                "--abilities",
+                format!("{} of {} is outside 3 to 20", ability, score),
+            ));
+        }
+        ability_scores.set(ability, score);
+    }
+    return Ok(ability_scores);
+}
+
+fn parse_list<T: EnumIter + fmt::Debug + fmt::Display>(
+    option: &str,
+    kind: &'static str,
+    value: &str,
+) -> Result<Vec<T>, CliError> {
+    return value
+        .split(',')
+        .map(|name| parse_choice(kind, name.trim()))
+        .collect::<Result<Vec<T>, InvalidChoice>>()
+        .map_err(|error| invalid(option, error));
+}
+
+fn load(path: &str) -> Result<CharacterSheet, CliError> {
+    return load_character(path).map_err(|error| CliError::Storage {
+        path: path.to_string(),
+        error,
+    });
+}
+
+fn save(character_sheet: &CharacterSheet, path: &str) -> Result<(), CliError> {
+    return save_character(character_sheet, path).map_err(|error| CliError::Storage {
+        path: path.to_string(),
+        error,
+    });
+}
+
+/// Run the command line, given the arguments after the program name.
+pub fn run(args: &[String]) -> Result<(), CliError> {
+    let (command, rest) = match args.split_first() {
+        Some((command, rest)) => (command.as_str(), rest),
+        None => ("new", args),
+    };
+
+    return match command {
+        "new" => new(rest),
+        "edit" => edit(rest),
+        "create" => create(rest),
+        "show" => show(rest),
+        "roll" => roll(rest),
+        "export" => export(rest),
+        "level-up" => level_up(rest),
+        "random" => random(rest),
+        "verify" => verify(rest),
+        "help" | "--help" | "-h" => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        _ => open(args),
+    };
+}
+
+fn new(args: &[String]) -> Result<(), CliError> {
+    let arguments = Arguments::parse(args, &["--seed", "--methods", "--answers"], &[])?;
+    if !arguments.positional.is_empty() {
+        return Err(CliError::Usage(
+            "new doesn't take a file; use edit to open one".to_string(),
+        ));
+    }
+    return wizard(None, &arguments);
+}
+
+fn edit(args: &[String]) -> Result<(), CliError> {
+    let arguments = Arguments::parse(args, &["--seed", "--methods", "--answers"], &[])?;
+    return wizard(Some(arguments.single("character file")?), &arguments);
+}
+
+/// Without a command, start the wizard on the given file, or a new character if there isn't one.
+fn open(args: &[String]) -> Result<(), CliError> {
+    let arguments = Arguments::parse(args, &["--seed", "--methods", "--answers"], &[])?;
+    return match arguments.positional.len() {
+        0 => wizard(None, &arguments),
+        _ => wizard(Some(arguments.single("character file")?), &arguments),
+    };
+}
+
+fn wizard(path: Option<&str>, arguments: &Arguments) -> Result<(), CliError> {
+    let mut app = match path {
+        Some(path) => App::load(path).map_err(|error| CliError::Storage {
+            path: path.to_string(),
+            error,
+        })?,
+        None => App::new(),
+    };
+    let answers = load_answers(arguments)?;
+    check_answers(&answers)?;
+    app.set_answers(answers);
+    // a seed on the command line wins over one in the answers file
+    if arguments.get("--seed").is_some() {
+        app.set_seed(arguments.seed()?);
+    }
+    if let Some(methods) = arguments.get("--methods") {
+        let methods = parse_list("--methods", "ability score method", methods)?;
+        app.set_allowed_ability_score_methods(methods);
+    }
+    app.run();
+    return Ok(());
+}
+
+fn create(args: &[String]) -> Result<(), CliError> {
+    let arguments = Arguments::parse(
+        args,
+        &[
+            "--name",
+            "--race",
+            "--subrace",
+            "--class",
+            "--subclass",
+            "--alignment",
+            "--background",
+            "--abilities",
+            "--method",
+            "--answers",
+            "--seed",
+            "--output",
+        ],
+        &[],
+    )?;
+    if !arguments.positional.is_empty() {
+        return Err(CliError::Usage(format!(
+            "unexpected argument {}",
+            arguments.positional.join(" ")
+        )));
+    }
+
+    // options given on the command line take precedence over the answers file
+    let answers = Answers {
+        seed: match arguments.get("--seed") {
+            Some(_) => Some(arguments.seed()?),
+            None => None,
+        },
+        name: arguments.get("--name").map(|name| name.to_string()),
+        race: arguments.choice("--race", "race")?,
+        subrace: arguments.choice("--subrace", "subrace")?,
+        alignment: arguments.choice("--alignment", "alignment")?,
+        class: arguments.choice("--class", "class")?,
+        subclass: arguments.choice("--subclass", "subclass")?,
+        background: arguments.choice("--background", "background")?,
+        ability_score_method: arguments.choice("--method", "ability score method")?,
+        ability_scores: match arguments.get("--abilities") {
+            Some(scores) => Some(parse_ability_scores(scores)?),
+            None => None,
+        },
+        ..Answers::default()
+    };
+    let answers = answers.or(load_answers(&arguments)?);
+
+    match &answers.name {
+        Some(name) if name.trim().is_empty() => {
+            return Err(invalid("--name", "the name can't be empty"))
+        }
+        Some(_) => {}
+        None => return Err(CliError::Usage("--name is required".to_string())),
+    }
+    if answers.race.is_none() {
+        return Err(CliError::Usage("--race is required".to_string()));
+    }
+    if answers.class.is_none() {
+        return Err(CliError::Usage("--class is required".to_string()));
+    }
+    check_answers(&answers)?;
+
+    let mut app = App::with_seed(arguments.seed()?);
+    app.set_answers(answers);
+    app.set_interactive(false);
+    app.run();
+
+    let character_sheet = app.character_sheet();
+    let path = match arguments.get("--output") {
+        Some(path) => path.to_string(),
+        None => default_file_name(&character_sheet.name),
+    };
+    save(character_sheet, &path)?;
+    println!("Created {} in {}", character_sheet.name, path);
+    return Ok(());
+}
+
+fn check_answers(answers: &Answers) -> Result<(), CliError> {
+    return answers
+        .check()
+        .map_err(|message| invalid("answers", message));
+}
+
+/// Read the answers file given with `--answers`, if there is one.
+fn load_answers(arguments: &Arguments) -> Result<Answers, CliError> {
+    return match arguments.get("--answers") {
+        Some(path) => Answers::load(path).map_err(|error| invalid("--answers", error)),
+        None => Ok(Answers::default()),
+    };
+}
+
+fn random(args: &[String]) -> Result<(), CliError> {
+    let arguments = Arguments::parse(
+        args,
+        &[
+            "--name",
+            "--race",
+            "--subrace",
+            "--class",
+            "--subclass",
+            "--background",
+            "--alignment",
+            "--exclude-alignment",
+            "--methods",
+            "--answers",
+            "--seed",
+            "--output",
+        ],
+        &[],
+    )?;
+    if !arguments.positional.is_empty() {
+        return Err(CliError::Usage(format!(
+            "unexpected argument {}",
+            arguments.positional.join(" ")
+        )));
+    }
+
+    let mut constraints = Constraints {
+        races: arguments.list("--race", "race")?,
+        classes: arguments.list("--class", "class")?,
+        backgrounds: arguments.list("--background", "background")?,
+        alignments: arguments.list("--alignment", "alignment")?,
+        ability_score_methods: arguments.list("--methods", "ability score method")?,
+    };
+    if let Some(excluded) = arguments.get("--exclude-alignment") {
+        let excluded = excluded
+            .split(',')
+            .map(|name| name.trim())
+            .collect::<Vec<&str>>();
+        let allowed =
+            exclude_alignments(&excluded).map_err(|error| invalid("--exclude-alignment", error))?;
+        constraints.alignments = match constraints.alignments.is_empty() {
+            true => allowed,
+            false => constraints
+                .alignments
+                .into_iter()
+                .filter(|alignment| allowed.contains(alignment))
+                .collect(),
+        };
+        if constraints.alignments.is_empty() {
+            return Err(invalid(
+                "--exclude-alignment",
+                "every alignment has been ruled out",
+            ));
+        }
+    }
+
+    let pinned = Answers {
+        name: arguments.get("--name").map(|name| name.to_string()),
+        subrace: arguments.choice("--subrace", "subrace")?,
+        subclass: arguments.choice("--subclass", "subclass")?,
+        ..Answers::default()
+    };
+    let pinned = pinned.or(load_answers(&arguments)?);
+    let seed = match (arguments.get("--seed"), pinned.seed) {
+        (None, Some(seed)) => seed,
+        _ => arguments.seed()?,
+    };
+    let answers = random_answers(pinned, &constraints, &mut seeded_rng(seed))
+        .map_err(|error| CliError::NotAllowed(error.to_string()))?;
+    check_answers(&answers)?;
+
+    let mut app = App::with_seed(seed);
+    app.set_answers(answers);
+    app.set_seed(seed);
+    app.set_interactive(false);
+    app.run();
+
+    let character_sheet = app.character_sheet();
+    match arguments.get("--output") {
+        Some(path) => {
+            save(character_sheet, path)?;
+            println!("Created {} in {}", character_sheet.name, path);
+        }
+        None => print!(
+            "{}",
+            render_character_sheet(character_sheet).replace("\r\n", "\n")
+        ),
+    }
+    println!(
+        "Seed {} (run with --seed {} to generate this character again)",
+        seed, seed
+    );
+    return Ok(());
+}
+
+fn show(args: &[String]) -> Result<(), CliError> {
+    let arguments = Arguments::parse(args, &[], &[])?;
+    let character_sheet = load(arguments.single("character file")?)?;
+    print!(
+        "{}",
+        render_character_sheet(&character_sheet).replace("\r\n", "\n")
+    );
+    return Ok(());
+}
+
+fn roll(args: &[String]) -> Result<(), CliError> {
+    let arguments = Arguments::parse(args, &["--seed"], &[])?;
+    // allow expressions with spaces, e.g. "roll 2d6 + 3"
+    if arguments.positional.is_empty() {
+        return Err(CliError::Usage("missing dice expression".to_string()));
+    }
+    let expression = arguments
+        .positional
+        .join(" ")
+        .parse::<DiceExpression>()
+        .map_err(|error| invalid("roll", error))?;
+    println!("{}", expression.roll(&mut seeded_rng(arguments.seed()?)));
+    return Ok(());
+}
+
+fn export(args: &[String]) -> Result<(), CliError> {
+    let arguments = Arguments::parse(args, &["--format", "--output"], &[])?;
+    let path = arguments.single("character file")?;
+    let character_sheet = load(path)?;
+    let contents = match arguments.require("--format")? {
+        "json" => to_json(&character_sheet)
+            .map_err(|error| invalid("--format", error))?
+            .into_bytes(),
+        "text" => render_character_sheet(&character_sheet)
+            .replace("\r\n", "\n")
+            .into_bytes(),
+        "pdf" => to_pdf(&character_sheet),
+        format => {
+            return Err(invalid(
+                "--format",
+                format!("unknown format {:?}; expected json, text or pdf", format),
+            ))
+        }
+    };
+
+    // a PDF isn't any use on the terminal, so it goes next to the character file
+    let output = match arguments.get("--output") {
+        Some(output) => Some(output.to_string()),
+        None if arguments.get("--format") == Some("pdf") => Some(
+            Path::new(path)
+                .with_extension("pdf")
+                .to_string_lossy()
+                .to_string(),
+        ),
+        None => None,
+    };
+    return match output {
+        Some(output) => {
+            fs::write(&output, contents).map_err(|error| CliError::Storage {
+                path: output.clone(),
+                error: StorageError::Io(error),
+            })?;
+            if arguments.get("--format") == Some("pdf") {
+                println!("Exported to {}", output);
+            }
+            Ok(())
+        }
+        None => {
+            print!("{}", String::from_utf8_lossy(&contents));
+            Ok(())
+        }
+    };
+}
+
+fn level_up(args: &[String]) -> Result<(), CliError> {
+    let arguments = Arguments::parse(
+        args,
+        &[
+            "--experience",
+            "--class",
+            "--subclass",
+            "--increase",
+            "--answers",
+            "--seed",
+            "--output",
+        ],
+        &["--roll-hit-points"],
+    )?;
+    let path = arguments.single("character file")?;
+    let mut app = App::load(path).map_err(|error| CliError::Storage {
+        path: path.to_string(),
+        error,
+    })?;
+    if let Some(experience_points) = arguments.get("--experience") {
+        let experience_points = experience_points
+            .parse()
+            .map_err(|_| invalid("--experience", "expected a whole number of points"))?;
+        app.award_experience(experience_points);
+    }
+
+    let character_sheet = app.character_sheet();
+    let level = character_sheet.get_level();
+    if level >= MAX_LEVEL {
+        return Err(CliError::NotAllowed(format!(
+            "{} is already level {}",
+            character_sheet.name, MAX_LEVEL
+        )));
+    }
+    if !character_sheet.can_level_up() {
+        return Err(CliError::NotAllowed(format!(
+            "{} needs more experience to reach level {}; award some with --experience",
+            character_sheet.name,
+            level + 1
+        )));
+    }
+
+    let level_up_class = arguments.choice("--class", "class")?;
+    if let Some(class) = level_up_class {
+        if character_sheet.get_class_level(class) == 0
+            && !character_sheet.can_multiclass_into(class)
+        {
+            return Err(invalid(
+                "--class",
+                format!(
+                    "{} doesn't meet the ability score prerequisites to multiclass into {}",
+                    character_sheet.name, class
+                ),
+            ));
+        }
+    }
+    let ability_score_improvements = match arguments.get("--increase") {
+        Some(abilities) => parse_list("--increase", "ability", abilities)?,
+        None => Vec::new(),
+    };
+    match ability_score_improvements[..] {
+        [first, second] if first == second => {
+            return Err(invalid(
+                "--increase",
+                "give one ability for +2 or two different abilities for +1 each",
+            ))
+        }
+        [_, _, _, ..] => {
+            return Err(invalid(
+                "--increase",
+                "give one ability for +2 or two abilities for +1 each",
+            ))
+        }
+        _ => {}
+    }
+
+    let answers = Answers {
+        level_up_class,
+        subclass: arguments.choice("--subclass", "subclass")?,
+        roll_hit_points: arguments.has("--roll-hit-points").then_some(true),
+        ability_score_improvements,
+        ..Answers::default()
+    };
+    let mut answers = answers.or(load_answers(&arguments)?);
+    // take the average hit points unless asked to roll
+    answers.roll_hit_points = answers.roll_hit_points.or(Some(false));
+    app.set_answers(answers);
+    app.set_interactive(false);
+    if arguments.get("--seed").is_some() {
+        app.set_seed(arguments.seed()?);
+    }
+    app.level_up();
+
+    let character_sheet = app.character_sheet();
+    let output = arguments.get("--output").unwrap_or(path);
+    save(character_sheet, output)?;
+    println!(
+        "{} is now level {} ({}) with {} hit points. Saved to {}",
+        character_sheet.name,
+        character_sheet.get_level(),
+        &character_sheet["class"],
+        character_sheet.get_max_hit_points(),
+        output
+    );
+    return Ok(());
+}
+
+/// Print a character's roll log and check it against their ability scores.
+fn verify(args: &[String]) -> Result<(), CliError> {
+    let arguments = Arguments::parse(args, &[], &[])?;
+    let character_sheet = load(arguments.single("character file")?)?;
+
+    let roll_log = &character_sheet.roll_log;
+    for (index, entry) in roll_log.get_entries().iter().enumerate() {
+        println!("{:>3}. {}", index + 1, entry);
+    }
+    if let Some(hash) = roll_log.get_head_hash() {
+        println!("Log hash: {}", hash);
+    }
+
+    let issues = roll_log.verify(&character_sheet.base_ability_scores);
+    if issues.is_empty() {
+        println!(
+            "Verified: the ability scores of {} match their roll log.",
+            character_sheet.name
+        );
+        return Ok(());
+    }
+    for issue in issues.iter() {
+        eprintln!("Problem: {}", issue);
+    }
+    return Err(CliError::VerificationFailed(issues.len()));
+}
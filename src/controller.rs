@@ -1,13 +1,26 @@
 use super::{
-    character::get_ability_score_rolls, character::AbilityName, character::Alignment,
-    character::CharacterSheet, classes::AvailableClasses, races::AvailableRaces,
+    answers::normalize, answers::Answers, armor::get_armor_class, armor::AvailableArmor,
+    backgrounds::AvailableBackgrounds, character::join, character::AbilityName,
+    character::AbilityScoreIncrease, character::AbilityScoreMethod, character::AbilityScores,
+    character::Alignment, character::ArmorCategory, character::CharacterSheet,
+    character::ClassLevel, character::Language, character::Skill, character::Source,
+    character::Tool, character::MAX_LEVEL, character::STANDARD_ARRAY, classes::AvailableClasses,
+    dice::seeded_rng, dice::DiceExpression, dice::DiceRng, dice::DiceRoll, equipment::Item,
+    equipment::StartingItem, feats::get_available_feats, feats::AvailableFeats, feats::FeatEffect,
+    pdf::export_pdf, point_buy::get_point_cost, point_buy::PointBuy, point_buy::DEFAULT_BUDGET,
+    races::AvailableRaces, races::AvailableSubraces, roll_log::RollEvent,
+    roll_log::ABILITY_SCORE_PURPOSE, spells::get_class_spells, spells::get_highest_spell_level,
+    spells::get_spell_by_name, spells::Level, spells::Spell, storage::load_character,
+    storage::save_character, storage::StorageError,
 };
 use enum_iterator::{all, Sequence};
 use num::Integer;
+use rand::Rng;
 use std::{
     cmp, fmt,
     io::{self, stdin, stdout, Stdout, Write},
     ops::Index,
+    path::{Path, PathBuf},
 };
 use termion::{clear, event::*};
 use termion::{
@@ -17,7 +30,7 @@ use termion::{
     raw::{IntoRawMode, RawTerminal},
 };
 
-use util::EnumIter;
+use util::{EnumIter, EnumString};
 
 /// Represents a menu item with a name and a value.
 #[derive(Clone)]
@@ -26,20 +39,34 @@ struct MenuItem<T: Copy> {
     value: T,
 }
 
-fn prompt_and_read_selection<T: Copy>(
-    prompt: &str,
-    menu: &Vec<MenuItem<T>>,
-) -> Result<T, io::Error> {
+/// Build a menu from a list of values, using each value's display name.
+fn to_menu_items<T: Copy + fmt::Display>(values: Vec<T>) -> Vec<MenuItem<T>> {
+    return values
+        .into_iter()
+        .map(|value| MenuItem {
+            name: value.to_string(),
+            value,
+        })
+        .collect();
+}
+
+/// Use up the first of the answers that's one of the options.
+fn take_answer<T: Copy + PartialEq>(answers: &mut Vec<T>, options: &[T]) -> Option<T> {
+    let index = answers.iter().position(|answer| options.contains(answer))?;
+    return Some(answers.remove(index));
+}
+
+fn prompt_and_read_selection<T: Copy>(prompt: &str, menu: &[MenuItem<T>]) -> Result<T, io::Error> {
     let stdin = stdin();
     let mut stdout = MouseTerminal::from(io::stdout().into_raw_mode().unwrap());
     let prompt = prompt.to_string() + "\r\n";
     let prompt = prompt.as_str();
 
-    let next_cursor: u16 = prompt.to_string().split("\n").count() as u16 + 1;
+    let next_cursor: u16 = prompt.split('\n').count() as u16 + 1;
 
     let mut cursor_pos = 0;
 
-    render_menu(prompt, &menu, &cursor_pos);
+    render_menu(prompt, menu, &cursor_pos);
 
     for c in stdin.events() {
         let evt = c.unwrap();
@@ -48,17 +75,15 @@ fn prompt_and_read_selection<T: Copy>(
                 Key::Esc => exit(&mut stdout),
                 Key::Ctrl('c') => exit(&mut stdout),
                 Key::Up => {
-                    if cursor_pos > 0 {
-                        cursor_pos -= 1;
-                    }
-                    render_menu(prompt, &menu, &cursor_pos);
+                    cursor_pos = cursor_pos.saturating_sub(1);
+                    render_menu(prompt, menu, &cursor_pos);
                     stdout.flush().unwrap();
                 }
                 Key::Down => {
                     if cursor_pos < menu.len() - 1 {
                         cursor_pos += 1;
                     }
-                    render_menu(prompt, &menu, &cursor_pos);
+                    render_menu(prompt, menu, &cursor_pos);
                     stdout.flush().unwrap();
                 }
                 Key::Char('\r') => break,
@@ -72,7 +97,7 @@ fn prompt_and_read_selection<T: Copy>(
                     let is_in_menu_bounds = y >= next_cursor && y < next_cursor + menu.len() as u16;
                     if is_in_menu_bounds {
                         cursor_pos = (y - next_cursor) as usize;
-                        render_menu(prompt, &menu, &cursor_pos);
+                        render_menu(prompt, menu, &cursor_pos);
                         stdout.flush().unwrap();
                     }
                 }
@@ -88,8 +113,8 @@ fn prompt_and_read_selection<T: Copy>(
     return Ok(selected_item.value);
 }
 
-fn render_menu<T: Copy>(prompt: &str, menu: &Vec<MenuItem<T>>, cursor_pos: &usize) {
-    let cursor_pos = cursor_pos.clone();
+fn render_menu<T: Copy>(prompt: &str, menu: &[MenuItem<T>], cursor_pos: &usize) {
+    let cursor_pos = *cursor_pos;
     let mut to_render = String::new();
     to_render.push_str(prompt);
     to_render.push_str("\r\n");
@@ -107,7 +132,7 @@ fn render_menu<T: Copy>(prompt: &str, menu: &Vec<MenuItem<T>>, cursor_pos: &usiz
                     termion::style::NoInvert
                 )
             } else {
-                format!("{}", item.name)
+                item.name.to_string()
             }
         })
         .collect::<Vec<String>>()
@@ -148,7 +173,7 @@ fn prompt_and_read_input<T: fmt::Display>(
     let prompt = prompt.to_string() + "\r\n";
     let prompt = prompt.as_str();
 
-    let next_cursor_row: u16 = prompt.to_string().split("\n").count() as u16 + 1;
+    let next_cursor_row: u16 = prompt.split('\n').count() as u16 + 1;
 
     let mut input = existing_value.to_string();
 
@@ -208,10 +233,39 @@ pub struct App {
     page_stack: Vec<Page>,
     current_page: usize,
     ability_score_rolls: Vec<i8>,
+    /// The methods the DM allows for generating ability scores.
+    allowed_ability_score_methods: Vec<AbilityScoreMethod>,
+    ability_score_method: AbilityScoreMethod,
+    /// Set once the player reaches the review page, so that editing a page returns there.
+    reviewing: bool,
+    /// Pages that must be revisited after an edit before returning to the review page.
+    follow_up_pages: Vec<Page>,
+    save_path: Option<PathBuf>,
+    /// Shown at the top of the review page, e.g. the result of saving.
+    review_message: Option<String>,
+    /// The seed every roll in this run is drawn from, so the rolls can be replayed.
+    seed: u64,
+    rng: DiceRng,
+    /// Choices made ahead of time, which are used instead of prompting.
+    answers: Answers,
+    /// Whether there's a player at the terminal. Without one, every unanswered prompt takes
+    /// its first option or existing value.
+    interactive: bool,
+}
+
+impl Default for App {
+    fn default() -> Self {
+        return App::new();
+    }
 }
 
 impl App {
     pub fn new() -> Self {
+        return App::with_seed(rand::thread_rng().gen());
+    }
+
+    /// Start a new character whose rolls all come from the given seed.
+    pub fn with_seed(seed: u64) -> Self {
         let character_sheet = CharacterSheet::new();
         let page_stack = Page::iter();
         Self {
@@ -219,37 +273,187 @@ impl App {
             page_stack,
             current_page: 0,
             ability_score_rolls: Vec::new(),
+            allowed_ability_score_methods: AbilityScoreMethod::iter(),
+            ability_score_method: AbilityScoreMethod::DropLowest,
+            reviewing: false,
+            follow_up_pages: Vec::new(),
+            save_path: None,
+            review_message: None,
+            seed,
+            rng: seeded_rng(seed),
+            answers: Answers::default(),
+            interactive: true,
+        }
+    }
+
+    /// Load a saved character and start at the review page, so it can be edited and saved again.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, StorageError> {
+        let mut app = App::new();
+        app.character_sheet = load_character(&path)?;
+        app.save_path = Some(path.as_ref().to_path_buf());
+        app.reviewing = true;
+        app.go_to_page(Page::Review);
+        return Ok(app);
+    }
+
+    /// Draw every following roll from the given seed.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.rng = seeded_rng(seed);
+    }
+
+    pub fn get_seed(&self) -> u64 {
+        return self.seed;
+    }
+
+    /// Only offer these methods for generating ability scores. An empty list allows them all.
+    pub fn set_allowed_ability_score_methods(&mut self, methods: Vec<AbilityScoreMethod>) {
+        self.allowed_ability_score_methods = match methods.is_empty() {
+            true => AbilityScoreMethod::iter(),
+            false => methods,
+        };
+    }
+
+    /// Use these answers instead of asking, taking their seed if they have one.
+    pub fn set_answers(&mut self, answers: Answers) {
+        if let Some(seed) = answers.seed {
+            self.set_seed(seed);
         }
+        self.answers = answers;
+    }
+
+    /// Run without a terminal, taking the answers given and defaults for everything else. The
+    /// highest scores and ability score improvements go to the class's primary abilities, levels
+    /// are taken in the character's 1st class, and the character wears the armor that gives them
+    /// the best armor class; other choices take the first option.
+    pub fn set_interactive(&mut self, interactive: bool) {
+        self.interactive = interactive;
+    }
+
+    pub fn save<P: AsRef<Path>>(&mut self, path: P) -> Result<(), StorageError> {
+        save_character(&self.character_sheet, &path)?;
+        self.save_path = Some(path.as_ref().to_path_buf());
+        return Ok(());
+    }
+
+    pub fn character_sheet(&self) -> &CharacterSheet {
+        return &self.character_sheet;
+    }
+
+    /// Award experience points to the character, returning the level they can now reach if it's
+    /// higher than their current one.
+    pub fn award_experience(&mut self, experience_points: u32) -> Option<i8> {
+        return self.character_sheet.award_experience(experience_points);
     }
 
     pub fn run(&mut self) {
         while self.current_page < self.page_stack.len() {
-            match self.page_stack[self.current_page] {
+            let page = self.page_stack[self.current_page];
+            match page {
                 Page::Name => self.name_page(),
                 Page::Race => self.race_page(),
                 Page::Class => self.class_page(),
                 Page::Abilities => self.abilities_page(),
-                _ => break,
+                Page::Background => self.background_page(),
+                Page::Skills => self.skills_page(),
+                Page::Spells => self.spells_page(),
+                Page::Feats => self.feats_page(),
+                Page::Equipment => self.equipment_page(),
+                Page::Armor => self.armor_page(),
+                Page::Bio => self.bio_page(),
+                Page::Review => self.review_page(),
+            }
+
+            if self.reviewing && page != Page::Review {
+                self.character_sheet.recompute();
+                if self.follow_up_pages.is_empty() {
+                    self.go_to_page(Page::Review);
+                } else {
+                    let next_page = self.follow_up_pages.remove(0);
+                    self.go_to_page(next_page);
+                }
             }
         }
 
-        print_character_sheet(&self.character_sheet);
+        if self.interactive {
+            print_character_sheet(&self.character_sheet);
+        }
+    }
+
+    fn select<T: Copy>(&self, prompt: &str, menu_items: &[MenuItem<T>]) -> T {
+        if !self.interactive {
+            return menu_items[0].value;
+        }
+        return prompt_and_read_selection(prompt, menu_items).unwrap();
+    }
+
+    fn input<T: fmt::Display>(&self, prompt: T, existing_value: &String) -> String {
+        if !self.interactive {
+            return existing_value.clone();
+        }
+        return prompt_and_read_input(prompt, existing_value).unwrap();
     }
 
+    /// Apply the ability score increases of the race and subrace, prompting the player to place
+    /// any increases that can go to an ability of their choice.
     fn adjust_ability_score_for_race(&mut self) {
         let race = self.character_sheet.race;
-        let ability_score_increases = race.get_ability_score_increases();
-        for (name, increase) in ability_score_increases {
-            let score = self.character_sheet.ability_scores.get(name);
+        let mut ability_score_increases = race.get_ability_score_increases();
+        if let Some(subrace) = self.character_sheet.subrace {
+            ability_score_increases.extend(subrace.get_ability_score_increases());
+        }
+        self.character_sheet.remove_grants(Source::Race);
+
+        // floating increases can't go to an ability the race already boosts, or to the same
+        // ability twice
+        let (floating, fixed): (Vec<_>, Vec<_>) = ability_score_increases
+            .into_iter()
+            .partition(|(name, _)| *name == AbilityName::ANY);
+        let mut boosted = fixed.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+        let mut increases = fixed;
+        for (i, (_, increase)) in floating.iter().enumerate() {
+            let options = AbilityName::iter()
+                .into_iter()
+                .filter(|ability| !boosted.contains(ability))
+                .collect::<Vec<AbilityName>>();
+            let answer = take_answer(&mut self.answers.racial_ability_increases, &options);
+            let ability = match answer {
+                Some(ability) => ability,
+                None => {
+                    let menu_items = to_menu_items(options);
+                    self.select(
+                        &format!(
+                            "Your {} race increases an ability score of your choice by {} ({} of {}):",
+                            race,
+                            increase,
+                            i + 1,
+                            floating.len()
+                        ),
+                        &menu_items,
+                    )
+                }
+            };
+            boosted.push(ability);
+            increases.push((ability, *increase));
+        }
+
+        for (name, increase) in increases {
             self.character_sheet
-                .ability_scores
-                .set(name, score + increase);
+                .ability_score_increases
+                .push(AbilityScoreIncrease {
+                    ability: name,
+                    increase,
+                    source: Source::Race,
+                });
         }
+        self.character_sheet.recompute();
     }
 
     fn name_page(&mut self) {
-        let mut name = self.character_sheet.name.clone();
-        name = prompt_and_read_input("What is your character's name?", &name).unwrap();
+        let name = match self.answers.name.take() {
+            Some(name) => name,
+            None => self.input("What is your character's name?", &self.character_sheet.name),
+        };
 
         self.character_sheet.name = name.trim().to_string();
         self.current_page += 1;
@@ -265,8 +469,69 @@ impl App {
             menu_items.push(menu_item);
         }
 
-        let result = prompt_and_read_selection("What is your character's race?", &menu_items);
-        self.character_sheet.race = result.unwrap();
+        let race = match self.answers.race.take() {
+            Some(race) => race,
+            None => self.select("What is your character's race?", &menu_items),
+        };
+        self.character_sheet.race = race;
+
+        let subraces = race.get_subraces();
+        let subrace = match self.answers.subrace.take() {
+            _ if subraces.is_empty() => None,
+            Some(subrace) if subraces.contains(&subrace) => Some(subrace),
+            _ => {
+                let menu_items = to_menu_items(subraces);
+                Some(self.select("Choose a subrace:", &menu_items))
+            }
+        };
+        if let Some(previous) = self.character_sheet.subrace {
+            let previous_traits = previous.get_traits();
+            self.character_sheet
+                .features
+                .retain(|feature| !previous_traits.contains(feature));
+        }
+        self.character_sheet.subrace = subrace;
+        self.adjust_ability_score_for_race();
+
+        if let Some(subrace) = subrace {
+            self.character_sheet.features.extend(subrace.get_traits());
+            for category in subrace.get_armor_proficiencies() {
+                self.character_sheet
+                    .armor_proficiencies
+                    .add(category, Source::Race);
+            }
+            for tool in subrace.get_tool_proficiencies() {
+                self.character_sheet
+                    .tool_proficiencies
+                    .add(tool, Source::Race);
+            }
+            for _ in 0..subrace.get_language_count() {
+                self.learn_language(
+                    &format!("Choose a language from your {} subrace:", subrace),
+                    Source::Race,
+                );
+            }
+        }
+
+        for skill in race.get_skill_proficiencies() {
+            self.character_sheet
+                .skill_proficiencies
+                .add(skill, Source::Race);
+        }
+        for _ in 0..race.get_skill_choice_count() {
+            self.learn_skill(
+                &format!("Choose a skill proficiency from your {} race:", race),
+                Skill::iter(),
+                Source::Race,
+            );
+        }
+        for _ in 0..subrace.map_or(0, |subrace| subrace.get_skill_choice_count()) {
+            self.learn_skill(
+                "Choose a skill proficiency from your subrace:",
+                Skill::iter(),
+                Source::Race,
+            );
+        }
 
         // show alignment options
         let mut alignment_menu_items = Vec::new();
@@ -278,9 +543,10 @@ impl App {
             alignment_menu_items.push(menu_item);
         }
 
-        let result =
-            prompt_and_read_selection("What is your character's alignment?", &alignment_menu_items);
-        self.character_sheet.alignment = result.unwrap();
+        self.character_sheet.alignment = match self.answers.alignment.take() {
+            Some(alignment) => alignment,
+            None => self.select("What is your character's alignment?", &alignment_menu_items),
+        };
 
         self.current_page += 1;
     }
@@ -295,99 +561,819 @@ impl App {
             menu_items.push(menu_item);
         }
 
-        let result = prompt_and_read_selection("What is your character's class?", &menu_items);
-        self.character_sheet.class = result.unwrap();
+        let class = match self.answers.class.take() {
+            Some(class) => class,
+            None => self.select("What is your character's class?", &menu_items),
+        };
+
+        // rechoosing the class of a character being edited keeps the levels they've gained
+        if self.reviewing && class == self.character_sheet.get_class() {
+            self.current_page += 1;
+            return;
+        }
+
+        // changing class starts the character over at 1st level in the new class
+        let previous_features = self
+            .character_sheet
+            .classes
+            .iter()
+            .flat_map(|class_level| {
+                (1..=class_level.level).flat_map(|level| {
+                    let mut features = class_level.class.get_features(level);
+                    if let Some(subclass) = class_level.subclass {
+                        features.extend(subclass.get_features(level));
+                    }
+                    features
+                })
+            })
+            .map(|feature| feature.name)
+            .collect::<Vec<String>>();
+        self.character_sheet
+            .features
+            .retain(|feature| !previous_features.contains(&feature.name));
+        self.character_sheet.classes = vec![ClassLevel::new(class)];
+        self.character_sheet.features.extend(class.get_features(1));
+        self.gain_subclass_features(class);
+
+        self.character_sheet.remove_grants(Source::Class);
+        self.character_sheet.remove_grants(Source::LevelUp);
+        // feats taken in place of an ability score improvement go with the levels they came from
+        let starting_feat = self.character_sheet.starting_feat;
+        for feat in std::mem::take(&mut self.character_sheet.feats) {
+            if Some(feat) == starting_feat {
+                self.character_sheet.feats.push(feat);
+            } else {
+                self.character_sheet.remove_grants(Source::Feat(feat));
+            }
+        }
+        self.character_sheet.recompute();
+        for category in class.get_armor_proficiencies() {
+            self.character_sheet
+                .armor_proficiencies
+                .add(category, Source::Class);
+        }
+        for ability in class.get_saving_throws() {
+            self.character_sheet
+                .saving_throw_proficiencies
+                .add(ability, Source::Class);
+        }
+
+        // 1st level hit points are the maximum roll of the class's hit die
+        let hit_die = class.get_hit_die();
+        self.character_sheet.hit_dice.clear();
+        self.character_sheet.hit_point_gains.clear();
+        self.character_sheet
+            .add_hit_die(hit_die, hit_die.get_sides());
+
+        // spells chosen for a previous class may not be on this class's list
+        let is_class_spell = |name: &String| match get_spell_by_name(name) {
+            Some(spell) => spell.is_available_to(class),
+            None => false,
+        };
+        self.character_sheet.cantrips.retain(is_class_spell);
+        self.character_sheet.spells.retain(is_class_spell);
+
+        // the class's skill choices were removed along with its other grants
+        if self.reviewing {
+            self.follow_up_pages = vec![Page::Skills, Page::Spells, Page::Equipment, Page::Armor];
+        }
+
         self.current_page += 1;
     }
 
     fn abilities_page(&mut self) {
-        let mut point_buy_menu_items = Vec::new();
-        point_buy_menu_items.push(MenuItem {
-            name: "Roll".to_string(),
-            value: false,
-        });
+        // scores given up front aren't generated, so only their assignment is logged, unless
+        // they're a legal purchase with point buy
+        if let Some(scores) = self.answers.ability_scores.take() {
+            let method = self.answers.ability_score_method.take();
+            let point_buy = method == Some(AbilityScoreMethod::PointBuy)
+                && PointBuy::from_scores(&scores, DEFAULT_BUDGET).is_some();
+            if point_buy {
+                self.ability_score_method = AbilityScoreMethod::PointBuy;
+                let roll_log = &mut self.character_sheet.roll_log;
+                roll_log.record(RollEvent::Method(AbilityScoreMethod::PointBuy));
+            }
+            self.character_sheet.point_buy = point_buy;
+            self.character_sheet.base_ability_scores = scores;
+            self.record_ability_scores();
+            self.character_sheet.recompute();
+            self.current_page += 1;
+            return;
+        }
 
-        point_buy_menu_items.push(MenuItem {
-            name: "Point Buy".to_string(),
-            value: true,
-        });
+        let menu_items = self
+            .allowed_ability_score_methods
+            .iter()
+            .map(|method| MenuItem {
+                name: method.get_description().to_string(),
+                value: *method,
+            })
+            .collect::<Vec<MenuItem<AbilityScoreMethod>>>();
+
+        let allowed = &self.allowed_ability_score_methods;
+        let method = match (self.answers.ability_score_method.take(), menu_items.len()) {
+            (Some(method), _) if allowed.contains(&method) => method,
+            (_, 1) => menu_items[0].value,
+            _ => self.select(
+                "How would you like to generate your ability scores?",
+                &menu_items,
+            ),
+        };
+
+        self.ability_score_method = method;
+        let roll_log = &mut self.character_sheet.roll_log;
+        roll_log.record(RollEvent::Method(method));
+        let rolls = method.roll(&mut self.rng);
+        for roll in rolls.iter() {
+            roll_log.record(RollEvent::roll(ABILITY_SCORE_PURPOSE, roll));
+        }
 
-        let point_buy = prompt_and_read_selection(
-            "Would you like to roll for your ability scores or use point buy?",
-            &point_buy_menu_items,
-        )
-        .unwrap();
+        match method.get_scores(&rolls) {
+            Some(scores) => {
+                self.character_sheet.point_buy = false;
+                self.ability_score_rolls = scores;
+                return self.roll_page();
+            }
+            None => return self.point_buy_page(),
+        }
+    }
 
-        if point_buy {
-            return self.point_buy_page();
+    fn point_buy_page(&mut self) {
+        // pick up where the player left off if they bought their scores before
+        let mut point_buy = match self.character_sheet.point_buy {
+            true => {
+                PointBuy::from_scores(&self.character_sheet.base_ability_scores, DEFAULT_BUDGET)
+                    .unwrap_or_default()
+            }
+            false => PointBuy::new(),
+        };
+
+        // without a terminal, buy the standard array, which costs the full budget
+        if !self.interactive {
+            point_buy = PointBuy::new();
+            for (ability, score) in self.default_ability_order().into_iter().zip(STANDARD_ARRAY) {
+                point_buy.set(ability, score).unwrap();
+            }
+        } else {
+            loop {
+                let scores = point_buy.get_scores();
+                let mut menu_items = vec![MenuItem {
+                    name: "Done".to_string(),
+                    value: None,
+                }];
+                for ability in AbilityName::iter() {
+                    let score = scores.get(ability);
+                    menu_items.push(MenuItem {
+                        name: format!(
+                            "{}: {} (cost {})",
+                            ability,
+                            score,
+                            get_point_cost(score).unwrap_or(0)
+                        ),
+                        value: Some(ability),
+                    });
+                }
+                let prompt = format!(
+                    "Points remaining: {} of {}\r\nChoose an ability score to adjust:",
+                    point_buy.get_remaining(),
+                    point_buy.get_budget()
+                );
+
+                match self.select(&prompt, &menu_items) {
+                    Some(ability) => prompt_and_read_score_inc_dec(
+                        &format!("Adjust points for {} (up/down, enter when done):", ability),
+                        &mut point_buy,
+                        ability,
+                    )
+                    .unwrap(),
+                    None if point_buy.get_remaining() > 0 => {
+                        let menu_items = vec![
+                            MenuItem {
+                                name: "Yes".to_string(),
+                                value: true,
+                            },
+                            MenuItem {
+                                name: "No".to_string(),
+                                value: false,
+                            },
+                        ];
+                        let confirmed = self.select(
+                            &format!(
+                                "You have {} points remaining. Are you sure you want to proceed?",
+                                point_buy.get_remaining()
+                            ),
+                            &menu_items,
+                        );
+                        if confirmed {
+                            break;
+                        }
+                    }
+                    None => break,
+                }
+            }
         }
 
-        self.ability_score_rolls = get_ability_score_rolls();
-        return self.roll_page();
+        self.character_sheet.point_buy = true;
+        self.character_sheet.base_ability_scores = point_buy.get_scores().clone();
+        self.record_ability_scores();
+        self.character_sheet.recompute();
+        self.current_page += 1;
     }
 
-    fn point_buy_page(&mut self) {
-        let mut menu_items = Vec::new();
-        let ability_names = AbilityName::iter();
+    fn background_page(&mut self) {
+        let menu_items = to_menu_items(AvailableBackgrounds::iter());
+        let background = match self.answers.background.take() {
+            Some(background) => background,
+            None => self.select("What is your character's background?", &menu_items),
+        };
+
+        // undo anything granted by a previously chosen background
+        let previous = self.character_sheet.background;
+        self.character_sheet.remove_grants(Source::Background);
+        let previous_feature = previous.get_feature();
+        self.character_sheet
+            .features
+            .retain(|feature| feature.name != previous_feature.name);
+
+        self.character_sheet.background = background;
+
+        for skill in background.get_skill_proficiencies() {
+            if self.character_sheet.skill_proficiencies.contains(skill) {
+                self.learn_skill(
+                    &format!(
+                        "You are already proficient in {}. Choose another skill instead:",
+                        skill
+                    ),
+                    Skill::iter(),
+                    Source::Background,
+                );
+            } else {
+                self.character_sheet
+                    .skill_proficiencies
+                    .add(skill, Source::Background);
+            }
+        }
 
-        for ability in AbilityName::iter() {
-            let menu_item = MenuItem {
-                name: ability.to_string(),
-                value: ability,
+        for options in background.get_tool_proficiencies() {
+            let answer = take_answer(&mut self.answers.tools, &options);
+            let tool = match answer {
+                _ if options.len() == 1 => options[0],
+                Some(tool) => tool,
+                None => {
+                    let menu_items = to_menu_items(options);
+                    self.select(
+                        &format!(
+                            "Choose a tool proficiency from the {} background:",
+                            background
+                        ),
+                        &menu_items,
+                    )
+                }
             };
-            menu_items.push(menu_item);
+            self.character_sheet
+                .tool_proficiencies
+                .add(tool, Source::Background);
         }
 
-        let mut pool = 27;
-        let max = 15;
-        let min = 8;
-        let mut i = 0;
+        for _ in 0..background.get_language_count() {
+            self.learn_language(
+                &format!("Choose a language from the {} background:", background),
+                Source::Background,
+            );
+        }
 
-        let mut ability_scores = self.character_sheet.ability_scores.clone();
+        self.character_sheet.features.push(background.get_feature());
 
-        while i < menu_items.len() {
-            let ability = menu_items[i].value;
-            let mut score = ability_scores.get(ability);
-            // get whichever is less, the remaining pool or the max score
-            score = prompt_and_read_score_inc_dec(
-                &format!("Adjust points for {}:", ability),
-                &score,
-                &mut pool,
-            )
-            .unwrap();
-            ability_scores.set(ability, score);
-            i += 1;
-            if i == menu_items.len() && pool > 0 {
-                let menu_items = vec![
-                    MenuItem {
-                        name: "Yes".to_string(),
-                        value: true,
-                    },
-                    MenuItem {
-                        name: "No".to_string(),
-                        value: false,
-                    },
-                ];
-                let confirmed = prompt_and_read_selection(
-                    &format!(
-                        "You have {} points remaining. Are you sure you want to proceed?",
-                        pool
+        // the background's equipment is handed out on the equipment page
+        if self.reviewing {
+            self.follow_up_pages = vec![Page::Equipment, Page::Armor];
+        }
+
+        self.current_page += 1;
+    }
+
+    fn spells_page(&mut self) {
+        let class = self.character_sheet.get_class();
+        let level = self.character_sheet.get_class_level(class);
+        let ability = match class.get_spellcasting_ability() {
+            Some(ability) => ability,
+            None => {
+                self.current_page += 1;
+                return;
+            }
+        };
+        let modifier = self.character_sheet.ability_scores.get_modifier(ability);
+
+        let cantrips = get_class_spells(class, Level::Cantrip);
+        let answers = std::mem::take(&mut self.answers.cantrips);
+        self.character_sheet.cantrips = self.select_spells(
+            "cantrip",
+            cantrips,
+            class.get_cantrips_known(level),
+            answers,
+        );
+
+        self.character_sheet.spells = match class.get_max_spell_level(level) {
+            Some(max_level) => {
+                let spells = get_class_spells(class, max_level)
+                    .into_iter()
+                    .filter(|spell| spell.level != Level::Cantrip)
+                    .collect();
+                let answers = std::mem::take(&mut self.answers.spells);
+                let count = class.get_spells_known(level, modifier);
+                self.select_spells("spell", spells, count, answers)
+            }
+            None => Vec::new(),
+        };
+
+        self.current_page += 1;
+    }
+
+    /// Choose the feat a variant human starts with. Everyone else only gets feats in place of an
+    /// ability score improvement when they level up.
+    fn feats_page(&mut self) {
+        let grants_feat = self
+            .character_sheet
+            .subrace
+            .is_some_and(|subrace| subrace.grants_feat());
+        let answer = self.answers.feat.take();
+
+        // undo the feat chosen on an earlier visit to this page, keeping any taken since
+        if let Some(feat) = self.character_sheet.starting_feat.take() {
+            self.character_sheet.feats.retain(|taken| *taken != feat);
+            self.character_sheet.remove_grants(Source::Feat(feat));
+            self.character_sheet.recompute();
+        }
+
+        let result = match grants_feat {
+            true => {
+                let mut menu_items = vec![MenuItem {
+                    name: "No feat".to_string(),
+                    value: None,
+                }];
+                for feat in get_available_feats(&self.character_sheet) {
+                    menu_items.push(MenuItem {
+                        name: format!("{}: {}", feat, feat.get_description()),
+                        value: Some(feat),
+                    });
+                }
+                match answer {
+                    Some(feat) if menu_items.iter().any(|item| item.value == feat) => feat,
+                    _ => self.select(
+                        "Choose a feat (feats are an optional rule, check with your DM):",
+                        &menu_items,
                     ),
+                }
+            }
+            false => None,
+        };
+        if let Some(feat) = result {
+            self.apply_feat(feat);
+            self.character_sheet.starting_feat = Some(feat);
+        }
+
+        self.current_page += 1;
+    }
+
+    /// Record a feat on the character sheet and apply its effects, prompting for any choices.
+    fn apply_feat(&mut self, feat: AvailableFeats) {
+        self.character_sheet.feats.push(feat);
+
+        for effect in feat.get_effects() {
+            match effect {
+                FeatEffect::AbilityScoreIncrease(abilities, increase) => {
+                    let answer = self
+                        .answers
+                        .feat_ability
+                        .take()
+                        .filter(|ability| abilities.contains(ability));
+                    let ability = if abilities.len() == 1 {
+                        abilities[0]
+                    } else if let Some(ability) = answer {
+                        ability
+                    } else {
+                        let menu_items = to_menu_items(abilities);
+                        self.select(
+                            &format!("{}: which ability score would you like to increase?", feat),
+                            &menu_items,
+                        )
+                    };
+                    self.character_sheet
+                        .ability_score_increases
+                        .push(AbilityScoreIncrease {
+                            ability,
+                            increase,
+                            source: Source::Feat(feat),
+                        });
+                    self.character_sheet.recompute();
+                }
+                FeatEffect::SkillProficiencies(count) => {
+                    for _ in 0..count {
+                        self.learn_skill(
+                            &format!("{}: choose a skill proficiency", feat),
+                            Skill::iter(),
+                            Source::Feat(feat),
+                        );
+                    }
+                }
+                FeatEffect::Languages(count) => {
+                    for _ in 0..count {
+                        self.learn_language(
+                            &format!("{}: choose a language", feat),
+                            Source::Feat(feat),
+                        );
+                    }
+                }
+                FeatEffect::ArmorProficiency(category) => {
+                    self.character_sheet
+                        .armor_proficiencies
+                        .add(category, Source::Feat(feat));
+                }
+                FeatEffect::HitPointsPerLevel(_) => {}
+            }
+        }
+    }
+
+    fn equipment_page(&mut self) {
+        let class = self.character_sheet.get_class();
+        let background = self.character_sheet.background;
+        let (dice_count, mut die, multiplier) = class.get_starting_wealth();
+        let wealth = if multiplier == 1 {
+            format!("{}{} gp", dice_count, die)
+        } else {
+            format!("{}{} x {} gp", dice_count, die, multiplier)
+        };
+        let menu_items = vec![
+            MenuItem {
+                name: format!("Take the {} and {} starting equipment", class, background),
+                value: false,
+            },
+            MenuItem {
+                name: format!("Roll {} starting gold instead of equipment", wealth),
+                value: true,
+            },
+        ];
+        let starting_gold = match self.answers.starting_gold.take() {
+            Some(starting_gold) => starting_gold,
+            None => self.select("Take starting equipment or starting gold?", &menu_items),
+        };
+
+        self.character_sheet.inventory.clear();
+        if starting_gold {
+            let roll = self.roll("Starting gold", die.get_expression(dice_count as u32));
+            self.character_sheet.inventory.currency.gold = roll.total as u32 * multiplier;
+            self.current_page += 1;
+            return;
+        }
+
+        for choice in class.get_starting_equipment() {
+            let letters = "abcdefgh".chars();
+            // answers only cover the choices with more than one option
+            let answer = match self.answers.equipment.is_empty() || choice.len() == 1 {
+                true => None,
+                false => Some(self.answers.equipment.remove(0).to_ascii_lowercase()),
+            };
+            let answer = answer.and_then(|answer| letters.clone().position(|l| l == answer));
+            let bundle = if choice.len() == 1 {
+                choice.into_iter().next().unwrap()
+            } else if let Some(index) = answer.filter(|index| *index < choice.len()) {
+                choice.into_iter().nth(index).unwrap()
+            } else {
+                let menu_items = choice
+                    .iter()
+                    .zip(letters)
+                    .enumerate()
+                    .map(|(i, (bundle, letter))| MenuItem {
+                        name: format!("({}) {}", letter, join(bundle)),
+                        value: i,
+                    })
+                    .collect::<Vec<MenuItem<usize>>>();
+                let index = self.select(
+                    &format!("Choose your {} starting equipment:", class),
                     &menu_items,
-                )
-                .unwrap();
-                if !confirmed {
-                    i = 0;
+                );
+                choice.into_iter().nth(index).unwrap()
+            };
+            for item in bundle {
+                self.receive_starting_item(item);
+            }
+        }
+
+        for item in background.get_equipment() {
+            self.receive_starting_item(item);
+        }
+        self.character_sheet.inventory.currency.gold += background.get_starting_gold();
+
+        self.current_page += 1;
+    }
+
+    /// Add an item of starting equipment to the inventory, letting the player pick the item
+    /// when it can be any of a group.
+    fn receive_starting_item(&mut self, item: StartingItem) {
+        match item {
+            StartingItem::Item(item, quantity) => {
+                self.character_sheet.inventory.add(item, quantity);
+            }
+            StartingItem::Any(group, quantity) => {
+                let options = group.get_items();
+                for i in 0..quantity {
+                    let menu_items = options
+                        .iter()
+                        .enumerate()
+                        .map(|(index, item)| MenuItem {
+                            name: item.to_string(),
+                            value: index,
+                        })
+                        .collect::<Vec<MenuItem<usize>>>();
+                    let prompt = if quantity == 1 {
+                        format!("Choose a {}:", group)
+                    } else {
+                        format!("Choose a {} ({} of {}):", group, i + 1, quantity)
+                    };
+                    let answered = self
+                        .answers
+                        .items
+                        .iter()
+                        .enumerate()
+                        .find_map(|(i, answer)| {
+                            options
+                                .iter()
+                                .position(|item| normalize(&item.to_string()) == normalize(answer))
+                                .map(|index| (i, index))
+                        });
+                    let index = match answered {
+                        Some((answer, index)) => {
+                            self.answers.items.remove(answer);
+                            index
+                        }
+                        None => self.select(&prompt, &menu_items),
+                    };
+                    self.character_sheet
+                        .inventory
+                        .add(options[index].clone(), 1);
                 }
             }
         }
+    }
+
+    fn armor_page(&mut self) {
+        let proficiencies = &self.character_sheet.armor_proficiencies;
+        let inventory = &self.character_sheet.inventory;
+        let mut menu_items = vec![MenuItem {
+            name: "No armor".to_string(),
+            value: None,
+        }];
+        for armor in inventory.get_armor() {
+            if proficiencies.contains(armor.get_category()) {
+                menu_items.push(MenuItem {
+                    name: format!(
+                        "{} ({}, AC {})",
+                        armor,
+                        armor.get_category(),
+                        armor.get_base_armor_class()
+                    ),
+                    value: Some(armor),
+                });
+            }
+        }
+        let armor = match self.answers.armor.take() {
+            _ if menu_items.len() == 1 => None,
+            Some(armor) if menu_items.iter().any(|item| item.value == armor) => armor,
+            // without a terminal, wear whichever armor gives the best armor class
+            _ if !self.interactive => menu_items
+                .iter()
+                .map(|item| item.value)
+                .max_by_key(|armor| {
+                    let mut character_sheet = self.character_sheet.clone();
+                    character_sheet.armor = *armor;
+                    get_armor_class(&character_sheet).total
+                })
+                .unwrap(),
+            _ => self.select("What armor are you wearing?", &menu_items),
+        };
+
+        let mut shield = false;
+        if inventory.contains(&Item::Shield) && proficiencies.contains(ArmorCategory::Shields) {
+            let menu_items = vec![
+                MenuItem {
+                    name: "Yes".to_string(),
+                    value: true,
+                },
+                MenuItem {
+                    name: "No".to_string(),
+                    value: false,
+                },
+            ];
+            shield = match self.answers.shield.take() {
+                Some(shield) => shield,
+                None => self.select("Are you carrying a shield?", &menu_items),
+            };
+        }
+
+        self.character_sheet.armor = armor;
+        self.character_sheet.shield = shield;
+        self.current_page += 1;
+    }
+
+    fn bio_page(&mut self) {
+        let background = self.character_sheet.background;
+        let mut bio = self.character_sheet.bio.clone();
+
+        let mut traits = background.get_personality_traits();
+        let mut answered_traits = std::mem::take(&mut self.answers.personality_traits);
+        bio.personality_traits = Vec::new();
+        for i in 0..2 {
+            let personality_trait = match answered_traits.is_empty() {
+                true => self.read_table_entry(
+                    "Personality trait",
+                    &format!("Choose personality trait {} of 2:", i + 1),
+                    &traits,
+                ),
+                false => answered_traits.remove(0),
+            };
+            traits.retain(|entry| *entry != personality_trait);
+            bio.personality_traits.push(personality_trait);
+        }
 
-        self.character_sheet.ability_scores = ability_scores;
+        let answers = &mut self.answers;
+        let (ideal, bond, flaw) = (
+            answers.ideal.take(),
+            answers.bond.take(),
+            answers.flaw.take(),
+        );
+        bio.ideal = match ideal {
+            Some(ideal) => ideal,
+            None => self.read_table_entry("Ideal", "Choose an ideal:", &background.get_ideals()),
+        };
+        bio.bond = match bond {
+            Some(bond) => bond,
+            None => self.read_table_entry("Bond", "Choose a bond:", &background.get_bonds()),
+        };
+        bio.flaw = match flaw {
+            Some(flaw) => flaw,
+            None => self.read_table_entry("Flaw", "Choose a flaw:", &background.get_flaws()),
+        };
+
+        let answers = &mut self.answers;
+        let answered = [
+            answers.backstory.take(),
+            answers.age.take(),
+            answers.height.take(),
+            answers.weight.take(),
+            answers.eyes.take(),
+            answers.hair.take(),
+            answers.skin.take(),
+        ];
+        let fields = [
+            (
+                "Write a short backstory for your character:",
+                &mut bio.backstory,
+            ),
+            ("How old is your character?", &mut bio.age),
+            ("How tall is your character?", &mut bio.height),
+            ("How much does your character weigh?", &mut bio.weight),
+            ("What color are your character's eyes?", &mut bio.eyes),
+            ("What is your character's hair like?", &mut bio.hair),
+            ("What is your character's skin like?", &mut bio.skin),
+        ];
+        for ((prompt, field), answer) in fields.into_iter().zip(answered) {
+            *field = match answer {
+                Some(answer) => answer,
+                None => self.input(prompt, field),
+            };
+        }
+
+        self.character_sheet.bio = bio;
         self.current_page += 1;
     }
 
+    /// Gain hit points for a new level in a class, letting the player roll the hit die or take
+    /// the average.
+    fn gain_level_hit_points(&mut self, class: AvailableClasses) {
+        let mut hit_die = class.get_hit_die();
+        let menu_items = vec![
+            MenuItem {
+                name: format!("Roll 1{}", hit_die),
+                value: true,
+            },
+            MenuItem {
+                name: format!("Take the average ({})", hit_die.get_average()),
+                value: false,
+            },
+        ];
+        let roll = match self.answers.roll_hit_points {
+            Some(roll) => roll,
+            None => self.select(
+                &format!(
+                    "How would you like to gain hit points for your new {} level?",
+                    class
+                ),
+                &menu_items,
+            ),
+        };
+
+        let hit_points = if roll {
+            let purpose = format!("{} hit points", class);
+            self.roll(&purpose, hit_die.get_expression(1)).total as i8
+        } else {
+            hit_die.get_average()
+        };
+        self.character_sheet.add_hit_die(hit_die, hit_points);
+    }
+
+    fn skills_page(&mut self) {
+        let class = self.character_sheet.get_class();
+        self.character_sheet
+            .skill_proficiencies
+            .remove_source(Source::Class);
+        self.character_sheet
+            .skill_expertise
+            .remove_source(Source::Class);
+
+        let (count, options) = class.get_skill_choices();
+        for i in 0..count {
+            self.learn_skill(
+                &format!(
+                    "Choose a {} skill proficiency ({} of {}):",
+                    class,
+                    i + 1,
+                    count
+                ),
+                options.clone(),
+                Source::Class,
+            );
+        }
+
+        let expertise_count =
+            class.get_expertise_count(self.character_sheet.get_class_level(class));
+        for i in 0..expertise_count {
+            self.learn_expertise(
+                &format!(
+                    "Choose a skill to gain expertise in ({} of {}):",
+                    i + 1,
+                    expertise_count
+                ),
+                Source::Class,
+            );
+        }
+
+        self.current_page += 1;
+    }
+
+    /// Prompt the player to pick one of their proficient skills to gain expertise in.
+    fn learn_expertise(&mut self, prompt: &str, source: Source) {
+        let expertise = &self.character_sheet.skill_expertise;
+        let options = self
+            .character_sheet
+            .skill_proficiencies
+            .values()
+            .into_iter()
+            .filter(|skill| !expertise.contains(*skill))
+            .collect::<Vec<Skill>>();
+        if options.is_empty() {
+            return;
+        }
+        let skill = match take_answer(&mut self.answers.expertise, &options) {
+            Some(skill) => skill,
+            None => self.select(prompt, &to_menu_items(options)),
+        };
+        self.character_sheet.skill_expertise.add(skill, source);
+    }
+
+    /// Prompt the player to pick a skill proficiency they don't already have from the options.
+    fn learn_skill(&mut self, prompt: &str, options: Vec<Skill>, source: Source) {
+        let known = &self.character_sheet.skill_proficiencies;
+        let options = options
+            .into_iter()
+            .filter(|skill| !known.contains(*skill))
+            .collect::<Vec<Skill>>();
+        if options.is_empty() {
+            return;
+        }
+        let skill = match take_answer(&mut self.answers.skills, &options) {
+            Some(skill) => skill,
+            None => self.select(prompt, &to_menu_items(options)),
+        };
+        self.character_sheet.skill_proficiencies.add(skill, source);
+    }
+
+    /// Prompt the player to pick a language they don't already know.
+    fn learn_language(&mut self, prompt: &str, source: Source) {
+        let known = &self.character_sheet.languages;
+        let options = Language::iter()
+            .into_iter()
+            .filter(|language| !known.contains(*language))
+            .collect::<Vec<Language>>();
+        let language = match take_answer(&mut self.answers.languages, &options) {
+            Some(language) => language,
+            None => self.select(prompt, &to_menu_items(options)),
+        };
+        self.character_sheet.languages.add(language, source);
+    }
+
     fn roll_page(&mut self) {
         let rolls = self.ability_score_rolls.clone();
+        let method = self.ability_score_method;
         let mut rolls_clone = rolls.clone();
         let mut menu_items = Vec::new();
 
@@ -399,22 +1385,50 @@ impl App {
             menu_items.push(menu_item);
         }
 
-        for roll in rolls {
+        // scores rolled in order go straight to the abilities they were rolled for
+        if method.is_in_order() {
+            for (ability, roll) in AbilityName::iter().into_iter().zip(rolls.iter()) {
+                self.character_sheet.base_ability_scores.set(ability, *roll);
+            }
+        }
+
+        // answers give the abilities to assign the scores to from highest to lowest
+        let mut order = std::mem::take(&mut self.answers.ability_score_order);
+        if order.is_empty() && !self.interactive {
+            order = self.default_ability_order();
+        }
+        if !method.is_in_order() && order.len() == rolls.len() {
+            for (ability, roll) in order.into_iter().zip(rolls.iter().rev()) {
+                self.character_sheet.base_ability_scores.set(ability, *roll);
+            }
+            self.record_ability_scores();
+            self.character_sheet.recompute();
+            self.current_page += 1;
+            return;
+        }
+
+        let seed = match method.get_expression() {
+            Some(_) => format!(
+                "Seed {} (run with --seed {} to replay these rolls)\r\n",
+                self.seed, self.seed
+            ),
+            None => String::new(),
+        };
+        for roll in rolls.into_iter().filter(|_| !method.is_in_order()) {
             let available_scores = rolls_clone
                 .iter()
                 .filter(|r| !menu_items.iter().any(|item| item.name == r.to_string()))
                 .map(|r| r.to_string())
                 .collect::<Vec<String>>()
                 .join(", ");
-            let result = prompt_and_read_selection(
+            let result = self.select(
                 &format!(
-                    "{}\r\nWhat ability score would you like to assign {} to?",
-                    available_scores, roll
+                    "{}{}\r\nWhat ability score would you like to assign {} to?",
+                    seed, available_scores, roll
                 ),
                 &menu_items,
-            )
-            .unwrap();
-            self.character_sheet.ability_scores.set(result, roll);
+            );
+            self.character_sheet.base_ability_scores.set(result, roll);
             menu_items.retain(|item| item.value != result);
             // remove the first item from the rolls_clone
             rolls_clone.remove(0);
@@ -425,47 +1439,603 @@ impl App {
         let mut prompt = prompt.to_string();
 
         for ability in AbilityName::iter() {
-            let score = self.character_sheet.ability_scores.get(ability);
+            let score = self.character_sheet.base_ability_scores.get(ability);
             prompt += &format!("{}: {}\r\n", ability, score);
         }
 
         // remove the last \r\n from the prompt
         prompt.pop();
 
-        let menu_items = vec![
-            MenuItem {
-                name: "Yes".to_string(),
-                value: true,
-            },
-            MenuItem {
+        let mut menu_items = vec![MenuItem {
+            name: "Yes".to_string(),
+            value: true,
+        }];
+        // there's nothing to reassign when the scores were rolled in order
+        if !method.is_in_order() {
+            menu_items.push(MenuItem {
                 name: "No".to_string(),
                 value: false,
-            },
-        ];
-        let confirmed = prompt_and_read_selection(&prompt, &menu_items).unwrap();
+            });
+        }
+        let confirmed = self.select(&prompt, &menu_items);
         if !confirmed {
             self.roll_page();
             return;
         }
 
+        self.record_ability_scores();
+        self.character_sheet.recompute();
         self.current_page += 1;
     }
+
+    fn review_page(&mut self) {
+        self.reviewing = true;
+
+        let mut menu_items = vec![
+            MenuItem {
+                name: "Finish".to_string(),
+                value: ReviewChoice::Finish,
+            },
+            MenuItem {
+                name: "Save".to_string(),
+                value: ReviewChoice::Save,
+            },
+            MenuItem {
+                name: "Export to PDF".to_string(),
+                value: ReviewChoice::ExportPdf,
+            },
+        ];
+        menu_items.push(MenuItem {
+            name: "Award experience points".to_string(),
+            value: ReviewChoice::AwardExperience,
+        });
+        menu_items.push(MenuItem {
+            name: "Roll dice".to_string(),
+            value: ReviewChoice::RollDice,
+        });
+        if self.character_sheet.can_level_up() {
+            menu_items.push(MenuItem {
+                name: format!("Level up to {}", self.character_sheet.get_level() + 1),
+                value: ReviewChoice::LevelUp,
+            });
+        }
+        for page in Page::iter() {
+            if page != Page::Review {
+                menu_items.push(MenuItem {
+                    name: format!("Edit {}", page),
+                    value: ReviewChoice::Edit(page),
+                });
+            }
+        }
+
+        let mut prompt = render_character_sheet(&self.character_sheet);
+        if let Some(message) = self.review_message.take() {
+            prompt = format!("{}\r\n\r\n{}", message, prompt);
+        }
+        prompt.push_str("\r\nChoose a section to edit, save, or finish:");
+
+        match self.select(&prompt, &menu_items) {
+            ReviewChoice::Finish => self.current_page += 1,
+            ReviewChoice::Save => self.save_page(),
+            ReviewChoice::ExportPdf => self.export_pdf_page(),
+            ReviewChoice::AwardExperience => self.award_experience_page(),
+            ReviewChoice::RollDice => self.roll_dice_page(),
+            ReviewChoice::LevelUp => self.level_up(),
+            ReviewChoice::Edit(page) => self.go_to_page(page),
+        }
+    }
+
+    fn save_page(&mut self) {
+        let default_path = match &self.save_path {
+            Some(path) => path.display().to_string(),
+            None => default_file_name(&self.character_sheet.name),
+        };
+        let path = self.input("Save character to:", &default_path);
+        let path = path.trim();
+
+        self.review_message = Some(match self.save(path) {
+            Ok(()) => format!("Saved to {}", path),
+            Err(error) => format!("Could not save to {}: {}", path, error),
+        });
+    }
+
+    fn export_pdf_page(&mut self) {
+        let default_path = match &self.save_path {
+            Some(path) => path.with_extension("pdf"),
+            None => {
+                PathBuf::from(default_file_name(&self.character_sheet.name)).with_extension("pdf")
+            }
+        };
+        let path = self.input(
+            "Export character sheet to:",
+            &default_path.display().to_string(),
+        );
+        let path = path.trim();
+
+        self.review_message = Some(match export_pdf(&self.character_sheet, path) {
+            Ok(()) => format!("Exported to {}", path),
+            Err(error) => format!("Could not export to {}: {}", path, error),
+        });
+    }
+
+    fn award_experience_page(&mut self) {
+        let input = self.input("How many experience points?", &"0".to_string());
+        let experience_points = match input.trim().parse::<u32>() {
+            Ok(experience_points) => experience_points,
+            Err(_) => {
+                self.review_message =
+                    Some(format!("{} is not a number of experience points", input));
+                return;
+            }
+        };
+
+        self.review_message = Some(
+            match self.character_sheet.award_experience(experience_points) {
+                Some(level) => format!(
+                    "{} experience points awarded. {} can now advance to level {}!",
+                    experience_points, self.character_sheet.name, level
+                ),
+                None => format!("{} experience points awarded.", experience_points),
+            },
+        );
+    }
+
+    /// Roll dice for the character, recording the roll in their roll log.
+    fn roll(&mut self, purpose: &str, expression: DiceExpression) -> DiceRoll {
+        let roll = expression.roll(&mut self.rng);
+        self.character_sheet
+            .roll_log
+            .record(RollEvent::roll(purpose, &roll));
+        return roll;
+    }
+
+    /// The abilities to give the highest scores to without a player to ask, the class's first.
+    fn default_ability_order(&self) -> Vec<AbilityName> {
+        let primary = self.character_sheet.get_class().get_primary_abilities();
+        let rest = AbilityName::iter()
+            .into_iter()
+            .filter(|ability| !primary.contains(ability));
+        return primary.into_iter().chain(rest).collect();
+    }
+
+    /// Record the scores the player settled on, so the DM can check them against the rolls.
+    fn record_ability_scores(&mut self) {
+        for ability in AbilityName::iter() {
+            let score = self.character_sheet.base_ability_scores.get(ability);
+            self.character_sheet
+                .roll_log
+                .record(RollEvent::Assign { ability, score });
+        }
+    }
+
+    fn roll_dice_page(&mut self) {
+        let input = self.input(
+            "What would you like to roll? (e.g. 2d6+3, 4d6kh3, 1d20adv)",
+            &"1d20".to_string(),
+        );
+
+        self.review_message = Some(match input.parse::<DiceExpression>() {
+            Ok(expression) => self.roll("Dice roller", expression).to_string(),
+            Err(error) => format!("Could not roll {}: {}", input.trim(), error),
+        });
+    }
+
+    /// Advance the character by one level, walking through everything the new level brings.
+    pub fn level_up(&mut self) {
+        if self.character_sheet.get_level() >= MAX_LEVEL {
+            return;
+        }
+
+        let mut menu_items = Vec::new();
+        for class in AvailableClasses::iter() {
+            let class_level = self.character_sheet.get_class_level(class);
+            if class_level > 0 {
+                menu_items.push(MenuItem {
+                    name: format!("{} (level {})", class, class_level + 1),
+                    value: class,
+                });
+            } else if self.character_sheet.can_multiclass_into(class) {
+                menu_items.push(MenuItem {
+                    name: format!("{} (multiclass)", class),
+                    value: class,
+                });
+            }
+        }
+        let class = match self.answers.level_up_class.take() {
+            Some(class) if menu_items.iter().any(|item| item.value == class) => class,
+            // rather than multiclassing into whichever class comes first
+            _ if !self.interactive => self.character_sheet.get_class(),
+            _ => self.select(
+                "Which class would you like to gain a level in?",
+                &menu_items,
+            ),
+        };
+        let previous_level = self.character_sheet.get_class_level(class);
+        let level = previous_level + 1;
+
+        self.gain_level_hit_points(class);
+        self.character_sheet.add_class_level(class);
+        self.character_sheet
+            .features
+            .extend(class.get_features(level));
+        self.gain_subclass_features(class);
+        if previous_level == 0 {
+            self.multiclass_proficiencies(class);
+        }
+
+        if class.has_ability_score_improvement(level) {
+            self.ability_score_improvement();
+        }
+
+        let new_expertise =
+            class.get_expertise_count(level) - class.get_expertise_count(previous_level);
+        for _ in 0..new_expertise {
+            self.learn_expertise("Choose a skill to gain expertise in:", Source::LevelUp);
+        }
+
+        self.learn_level_spells(class, previous_level);
+        self.character_sheet.recompute();
+
+        self.review_message = Some(format!(
+            "{} reached level {}! Hit points: {}",
+            self.character_sheet.name,
+            self.character_sheet.get_level(),
+            self.character_sheet.get_max_hit_points()
+        ));
+    }
+
+    /// Prompt for the class's subclass if it has reached the level where it is chosen, then
+    /// grant any subclass features for the class's current level.
+    fn gain_subclass_features(&mut self, class: AvailableClasses) {
+        let level = self.character_sheet.get_class_level(class);
+        if level == class.get_subclass_level() {
+            let subclass = match self.answers.subclass {
+                Some(subclass) if subclass.get_class() == class => {
+                    self.answers.subclass = None;
+                    subclass
+                }
+                _ => {
+                    let menu_items = to_menu_items(class.get_subclasses());
+                    self.select(&format!("Choose your {} subclass:", class), &menu_items)
+                }
+            };
+            self.character_sheet.set_subclass(subclass);
+        }
+
+        if let Some(subclass) = self.character_sheet.get_subclass(class) {
+            self.character_sheet
+                .features
+                .extend(subclass.get_features(level));
+        }
+    }
+
+    /// Grant the restricted set of proficiencies a character gets when multiclassing into a
+    /// class.
+    fn multiclass_proficiencies(&mut self, class: AvailableClasses) {
+        for category in class.get_multiclass_armor_proficiencies() {
+            self.character_sheet
+                .armor_proficiencies
+                .add(category, Source::LevelUp);
+        }
+
+        let count = class.get_multiclass_skill_count();
+        let (_, options) = class.get_skill_choices();
+        for _ in 0..count {
+            self.learn_skill(
+                &format!("Choose a {} skill proficiency:", class),
+                options.clone(),
+                Source::LevelUp,
+            );
+        }
+
+        for options in class.get_multiclass_tool_proficiencies() {
+            let known = &self.character_sheet.tool_proficiencies;
+            let options = options
+                .into_iter()
+                .filter(|tool| !known.contains(*tool))
+                .collect::<Vec<Tool>>();
+            let answer = take_answer(&mut self.answers.tools, &options);
+            let tool = match (options.len(), answer) {
+                (0, _) => continue,
+                (1, _) => options[0],
+                (_, Some(tool)) => tool,
+                _ => {
+                    let menu_items = to_menu_items(options);
+                    self.select(
+                        &format!("Choose a {} tool proficiency:", class),
+                        &menu_items,
+                    )
+                }
+            };
+            self.character_sheet
+                .tool_proficiencies
+                .add(tool, Source::LevelUp);
+        }
+    }
+
+    /// Increase one ability score by 2 or two ability scores by 1, or take a feat instead.
+    fn ability_score_improvement(&mut self) {
+        let mut menu_items = vec![
+            MenuItem {
+                name: "Increase one ability score by 2".to_string(),
+                value: Improvement::IncreaseOne,
+            },
+            MenuItem {
+                name: "Increase two ability scores by 1".to_string(),
+                value: Improvement::IncreaseTwo,
+            },
+        ];
+        for feat in get_available_feats(&self.character_sheet) {
+            menu_items.push(MenuItem {
+                name: format!("{}: {}", feat, feat.get_description()),
+                value: Improvement::Feat(feat),
+            });
+        }
+        let answers = std::mem::take(&mut self.answers.ability_score_improvements);
+        let improvement = match answers.len() {
+            1 => Improvement::IncreaseOne,
+            2 => Improvement::IncreaseTwo,
+            _ => self.select(
+                "Ability Score Improvement: increase your ability scores or take a feat:",
+                &menu_items,
+            ),
+        };
+
+        let increases = match improvement {
+            Improvement::IncreaseOne => vec![2],
+            Improvement::IncreaseTwo => vec![1, 1],
+            Improvement::Feat(feat) => {
+                self.apply_feat(feat);
+                return;
+            }
+        };
+
+        let mut chosen = Vec::new();
+        for increase in increases {
+            // scores can't be raised above 20, and the two +1s must go to different abilities
+            let options = AbilityName::iter()
+                .into_iter()
+                .filter(|ability| !chosen.contains(ability))
+                .filter(|ability| self.character_sheet.ability_scores.get(*ability) < 20)
+                .collect::<Vec<AbilityName>>();
+            if options.is_empty() {
+                break;
+            }
+            let answer = answers.get(chosen.len());
+            let ability = match answer {
+                Some(ability) if options.contains(ability) => *ability,
+                _ if !self.interactive => self
+                    .default_ability_order()
+                    .into_iter()
+                    .find(|ability| options.contains(ability))
+                    .unwrap(),
+                _ => {
+                    let menu_items = to_menu_items(options);
+                    self.select(
+                        &format!(
+                            "Which ability score would you like to increase by {}?",
+                            increase
+                        ),
+                        &menu_items,
+                    )
+                }
+            };
+            chosen.push(ability);
+            self.character_sheet
+                .ability_score_increases
+                .push(AbilityScoreIncrease {
+                    ability,
+                    increase,
+                    source: Source::LevelUp,
+                });
+        }
+        self.character_sheet.recompute();
+    }
+
+    /// Learn any cantrips and spells a class gains on advancing from the previous class level.
+    /// Eldritch knights and arcane tricksters learn wizard spells from their subclass. Spells
+    /// are offered up to the highest level the class can cast or the catalog has, whichever is
+    /// lower.
+    fn learn_level_spells(&mut self, class: AvailableClasses, previous_level: i8) {
+        let level = self.character_sheet.get_class_level(class);
+        let subclass = self
+            .character_sheet
+            .get_subclass(class)
+            .filter(|subclass| subclass.get_spellcasting_ability().is_some());
+        let (ability, spell_list) = match subclass {
+            Some(subclass) => (
+                subclass.get_spellcasting_ability(),
+                AvailableClasses::Wizard,
+            ),
+            None => (class.get_spellcasting_ability(), class),
+        };
+        let ability = match ability {
+            Some(ability) => ability,
+            None => return,
+        };
+        let modifier = self.character_sheet.ability_scores.get_modifier(ability);
+        let max_spell_level = |level: i8| match subclass {
+            Some(subclass) => subclass.get_max_spell_level(level),
+            None => class.get_max_spell_level(level),
+        };
+        // each class learns spells as if it were the character's only class
+        let cantrips_known = |level: i8| match (level, subclass) {
+            (0, _) => 0,
+            (_, Some(subclass)) => subclass.get_cantrips_known(level),
+            (_, None) => class.get_cantrips_known(level),
+        };
+        let spells_known = |level: i8| match (max_spell_level(level), subclass) {
+            (Some(_), Some(subclass)) if level > 0 => subclass.get_spells_known(level),
+            (Some(_), None) if level > 0 => class.get_spells_known(level, modifier),
+            _ => 0,
+        };
+
+        let known = &self.character_sheet.cantrips;
+        let new_cantrips = cantrips_known(level).saturating_sub(cantrips_known(previous_level));
+        let cantrips = get_class_spells(spell_list, Level::Cantrip)
+            .into_iter()
+            .filter(|spell| !known.contains(&spell.name))
+            .collect();
+        let answers = std::mem::take(&mut self.answers.cantrips);
+        let cantrips = self.select_spells("new cantrip", cantrips, new_cantrips, answers);
+        self.character_sheet.cantrips.extend(cantrips);
+
+        if let Some(max_level) = max_spell_level(level) {
+            let max_level = cmp::min(max_level, get_highest_spell_level());
+            let known = &self.character_sheet.spells;
+            let new_spells = spells_known(level).saturating_sub(spells_known(previous_level));
+            let spells = get_class_spells(spell_list, max_level)
+                .into_iter()
+                .filter(|spell| spell.level != Level::Cantrip && !known.contains(&spell.name))
+                .collect();
+            let answers = std::mem::take(&mut self.answers.spells);
+            let spells = self.select_spells("new spell", spells, new_spells, answers);
+            self.character_sheet.spells.extend(spells);
+        }
+    }
+
+    /// Let the player roll on a table, pick an entry from it, or write their own. Rolls are
+    /// logged with the given purpose.
+    fn read_table_entry(&mut self, purpose: &str, prompt: &str, table: &[&str]) -> String {
+        let mut menu_items = vec![MenuItem {
+            name: "Roll".to_string(),
+            value: TableChoice::Roll,
+        }];
+        for (i, entry) in table.iter().enumerate() {
+            menu_items.push(MenuItem {
+                name: entry.to_string(),
+                value: TableChoice::Entry(i),
+            });
+        }
+        menu_items.push(MenuItem {
+            name: "Write your own".to_string(),
+            value: TableChoice::Custom,
+        });
+
+        match self.select(prompt, &menu_items) {
+            TableChoice::Roll => {
+                let roll = self.roll(purpose, DiceExpression::new(1, table.len() as u32));
+                table[roll.total as usize - 1].to_string()
+            }
+            TableChoice::Entry(i) => table[i].to_string(),
+            TableChoice::Custom => self.input(prompt, &String::new()).trim().to_string(),
+        }
+    }
+
+    /// Prompt the player to pick `count` spells from the given list, without repeats. Spells named
+    /// in the answers are picked first.
+    fn select_spells(
+        &self,
+        kind: &str,
+        mut options: Vec<Spell>,
+        count: usize,
+        answers: Vec<String>,
+    ) -> Vec<String> {
+        let mut selected = Vec::new();
+        let count = cmp::min(count, options.len());
+
+        for answer in answers {
+            let position = options
+                .iter()
+                .position(|spell| normalize(&spell.name) == normalize(&answer));
+            if let Some(index) = position.filter(|_| selected.len() < count) {
+                selected.push(options.remove(index).name);
+            }
+        }
+
+        while selected.len() < count {
+            let menu_items = options
+                .iter()
+                .enumerate()
+                .map(|(i, spell)| MenuItem {
+                    name: format!("{} ({})", spell.name, spell.school),
+                    value: i,
+                })
+                .collect::<Vec<MenuItem<usize>>>();
+            let prompt = format!(
+                "Choose a {} ({} of {}):\r\n{}",
+                kind,
+                selected.len() + 1,
+                count,
+                selected.join(", ")
+            );
+            let index = self.select(&prompt, &menu_items);
+            selected.push(options.remove(index).name);
+        }
+
+        return selected;
+    }
+
+    fn go_to_page(&mut self, page: Page) {
+        if let Some(index) = self.page_stack.iter().position(|p| *p == page) {
+            self.current_page = index;
+        }
+    }
 }
 
-#[derive(Debug, Sequence, EnumIter)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Sequence, EnumIter, EnumString)]
 enum Page {
     Name,
     Race,
     Class,
     Abilities,
     Background,
+    Skills,
     Spells,
     Feats,
+    Equipment,
+    Armor,
     Bio,
     Review,
 }
 
+#[derive(Copy, Clone)]
+enum Improvement {
+    IncreaseOne,
+    IncreaseTwo,
+    Feat(AvailableFeats),
+}
+
+#[derive(Copy, Clone)]
+enum ReviewChoice {
+    Finish,
+    Save,
+    ExportPdf,
+    AwardExperience,
+    RollDice,
+    LevelUp,
+    Edit(Page),
+}
+
+/// Build a file name for a character from its name, e.g. "Elara Moonwhisper" becomes
+/// "elara-moonwhisper.json".
+pub fn default_file_name(name: &str) -> String {
+    let slug = name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<String>>()
+        .join("-");
+    if slug.is_empty() {
+        return "character.json".to_string();
+    }
+    return slug + ".json";
+}
+
+#[derive(Copy, Clone)]
+enum TableChoice {
+    Roll,
+    Entry(usize),
+    Custom,
+}
+
 fn print_character_sheet(character_sheet: &CharacterSheet) {
+    render(render_character_sheet(character_sheet));
+}
+
+/// The character sheet as text, one field per line. Lines end in "\r\n" for the raw-mode
+/// terminal.
+pub fn render_character_sheet(character_sheet: &CharacterSheet) -> String {
     let mut to_render = String::new();
     for key in CharacterSheet::keys() {
         let value = character_sheet[key].to_string().replace("\n", "");
@@ -486,16 +2056,15 @@ fn print_character_sheet(character_sheet: &CharacterSheet) {
         to_render.push_str(&format!("{: <20}{}\r\n", key, value));
     }
 
-    render(to_render);
+    return to_render;
 }
 
 fn exit(stdout: &mut RawTerminal<Stdout>) {
     write!(
         stdout,
-        "{}{}{}{}",
+        "{}{}Goodbye!\r\n{}",
         termion::clear::All,
         termion::cursor::Goto(1, 1),
-        "Goodbye!\r\n",
         termion::cursor::Hide
     )
     .unwrap();
@@ -505,29 +2074,16 @@ fn exit(stdout: &mut RawTerminal<Stdout>) {
 
 fn prompt_and_read_score_inc_dec(
     prompt: &str,
-    existing_value: &i8,
-    pool: &mut i8,
-) -> Result<i8, io::Error> {
+    point_buy: &mut PointBuy,
+    ability: AbilityName,
+) -> Result<(), io::Error> {
     let mut stdout = stdout().into_raw_mode()?;
 
-    let prompt_remaining = format!("Pool Remaining: {}\r\n", pool);
+    let prompt_remaining = format!("Pool Remaining: {}\r\n", point_buy.get_remaining());
     let prompt = prompt.to_string() + "\r\n";
     let prompt = prompt_remaining + prompt.as_str();
 
-    let mut pool_used = 0;
-
-    let next_cursor_row: u16 = prompt.to_string().split("\n").count() as u16 + 1;
-
-    let mut input = existing_value.clone();
-
-    /// the minimum score
-    let min_score = 8;
-
-    /// the maximum score
-    let max_score = 15;
-
-    /// the threshold at which we start incrementing by 2
-    let threshold = 13;
+    let next_cursor_row: u16 = prompt.split('\n').count() as u16 + 1;
 
     write!(
         stdout,
@@ -536,85 +2092,408 @@ fn prompt_and_read_score_inc_dec(
         termion::cursor::Goto(1, 1),
         prompt,
         termion::cursor::Goto(1, next_cursor_row),
-        input,
+        point_buy.get_scores().get(ability),
         termion::cursor::Hide
     )?;
 
     stdout.flush()?;
 
     let stdin = stdin();
-    // show the input as the user types
+    // show the score and the remaining pool as the user adjusts it
     for c in stdin.keys() {
-        match c.unwrap() {
-            Key::Esc => exit(&mut stdout),
-            Key::Ctrl('c') => exit(&mut stdout),
+        let changed = match c.unwrap() {
+            Key::Esc | Key::Ctrl('c') => {
+                exit(&mut stdout);
+                false
+            }
             Key::Char('\r') => break,
             Key::Char('\n') => break,
-            Key::Up => {
-                if input >= max_score || pool_used >= pool.clone() {
-                    continue;
-                }
+            Key::Up => point_buy.increase(ability).is_ok(),
+            Key::Down => point_buy.decrease(ability).is_ok(),
+            _ => false,
+        };
+        if !changed {
+            continue;
+        }
 
-                input = input + 1;
+        write!(
+            stdout,
+            "{}{}{}{}{}{}{}",
+            termion::cursor::Goto(17, 1),
+            clear::UntilNewline,
+            point_buy.get_remaining(),
+            termion::cursor::Goto(1, next_cursor_row),
+            clear::CurrentLine,
+            point_buy.get_scores().get(ability),
+            termion::cursor::Hide
+        )?;
+
+        stdout.flush()?;
+    }
 
-                if input <= threshold {
-                    pool_used = pool_used + 1;
-                } else {
-                    pool_used = pool_used + 2;
-                }
+    write!(stdout, "{}", termion::cursor::Show)?;
 
-                let prompt_remaining = format!("Pool Remaining: {}\r\n", pool.clone() - pool_used);
+    return Ok(());
+}
 
-                write!(
-                    stdout,
-                    "{}{}{}{}{}{}{}",
-                    termion::cursor::Goto(17, 1),
-                    clear::UntilNewline,
-                    pool.clone() - pool_used,
-                    termion::cursor::Goto(1, next_cursor_row),
-                    clear::CurrentLine,
-                    input,
-                    termion::cursor::Hide
-                )?;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::classes::AvailableSubclasses;
+
+    /// Build a half-orc (a fighter unless answered otherwise) without a terminal, taking
+    /// defaults for anything not answered.
+    fn run(seed: u64, answers: Answers) -> CharacterSheet {
+        let mut app = App::with_seed(seed);
+        app.set_answers(Answers {
+            name: Some("Test".to_string()),
+            race: Some(AvailableRaces::HalfOrc),
+            class: answers.class.or(Some(AvailableClasses::Fighter)),
+            ..answers
+        });
+        app.set_interactive(false);
+        app.run();
+        return app.character_sheet;
+    }
 
-                stdout.flush()?;
-            }
-            Key::Down => {
-                if input <= min_score {
-                    continue;
+    /// Level a character up once without a terminal.
+    fn level_up(character_sheet: CharacterSheet, answers: Answers) -> CharacterSheet {
+        let mut app = App::with_seed(7);
+        app.character_sheet = character_sheet;
+        app.set_answers(answers);
+        app.set_interactive(false);
+        app.level_up();
+        return app.character_sheet;
+    }
+
+    fn ability_score_rolls(character_sheet: &CharacterSheet) -> Vec<i32> {
+        return character_sheet
+            .roll_log
+            .get_entries()
+            .iter()
+            .filter_map(|entry| match &entry.event {
+                RollEvent::Roll { purpose, total, .. } if purpose == ABILITY_SCORE_PURPOSE => {
+                    Some(*total)
                 }
+                _ => None,
+            })
+            .collect();
+    }
 
-                input = input - 1;
+    fn base_scores(character_sheet: &CharacterSheet) -> Vec<(AbilityName, i8)> {
+        return character_sheet.base_ability_scores.get_sorted();
+    }
 
-                if input < threshold {
-                    pool_used = cmp::max(0, pool_used - 1);
-                } else {
-                    pool_used = cmp::max(0, pool_used - 2);
-                }
+    #[test]
+    fn assigns_rolls_highest_first() {
+        let character_sheet = run(
+            7,
+            Answers {
+                ability_score_method: Some(AbilityScoreMethod::DropLowest),
+                ability_score_order: vec![
+                    AbilityName::Strength,
+                    AbilityName::Constitution,
+                    AbilityName::Dexterity,
+                    AbilityName::Wisdom,
+                    AbilityName::Charisma,
+                    AbilityName::Intelligence,
+                ],
+                ..Answers::default()
+            },
+        );
+
+        assert_eq!(
+            ability_score_rolls(&character_sheet),
+            vec![8, 15, 11, 12, 5, 14]
+        );
+        assert_eq!(
+            base_scores(&character_sheet),
+            vec![
+                (AbilityName::Strength, 15),
+                (AbilityName::Dexterity, 12),
+                (AbilityName::Constitution, 14),
+                (AbilityName::Intelligence, 5),
+                (AbilityName::Wisdom, 11),
+                (AbilityName::Charisma, 8),
+            ]
+        );
+        // half-orcs add 2 to Strength and 1 to Constitution
+        let ability_scores = &character_sheet.ability_scores;
+        assert_eq!(ability_scores.get(AbilityName::Strength), 17);
+        assert_eq!(ability_scores.get(AbilityName::Constitution), 15);
+        let base_ability_scores = &character_sheet.base_ability_scores;
+        assert_eq!(character_sheet.roll_log.verify(base_ability_scores), vec![]);
+    }
 
-                write!(
-                    stdout,
-                    "{}{}{}{}{}{}{}",
-                    termion::cursor::Goto(17, 1),
-                    clear::UntilNewline,
-                    pool.clone() - pool_used,
-                    termion::cursor::Goto(1, next_cursor_row),
-                    clear::CurrentLine,
-                    input,
-                    termion::cursor::Hide
-                )?;
+    #[test]
+    fn assigns_rolls_in_order() {
+        let character_sheet = run(
+            7,
+            Answers {
+                ability_score_method: Some(AbilityScoreMethod::InOrder),
+                ..Answers::default()
+            },
+        );
+
+        assert_eq!(
+            ability_score_rolls(&character_sheet),
+            vec![5, 10, 15, 9, 12, 5]
+        );
+        assert_eq!(
+            base_scores(&character_sheet),
+            vec![
+                (AbilityName::Strength, 5),
+                (AbilityName::Dexterity, 10),
+                (AbilityName::Constitution, 15),
+                (AbilityName::Intelligence, 9),
+                (AbilityName::Wisdom, 12),
+                (AbilityName::Charisma, 5),
+            ]
+        );
+    }
 
-                stdout.flush()?;
-            }
-            _ => {}
+    #[test]
+    fn same_seed_gives_same_scores() {
+        let answers = || Answers {
+            ability_score_method: Some(AbilityScoreMethod::Heroic),
+            ability_score_order: AbilityName::iter(),
+            ..Answers::default()
+        };
+        let first = run(11, answers());
+        let second = run(11, answers());
+        let other = run(12, answers());
+
+        assert_eq!(base_scores(&first), base_scores(&second));
+        assert_eq!(ability_score_rolls(&first), ability_score_rolls(&second));
+        assert_ne!(ability_score_rolls(&first), ability_score_rolls(&other));
+    }
+
+    #[test]
+    fn logs_bought_scores() {
+        let mut app = App::with_seed(1);
+        for ability in AbilityName::iter() {
+            app.character_sheet.base_ability_scores.set(ability, 10);
         }
+        app.set_answers(Answers {
+            ability_score_method: Some(AbilityScoreMethod::PointBuy),
+            ..Answers::default()
+        });
+        app.set_interactive(false);
+        app.abilities_page();
+
+        // without a terminal the standard array is bought, highest for the barbarian's Strength
+        let character_sheet = &app.character_sheet;
+        let base_ability_scores = &character_sheet.base_ability_scores;
+        assert_eq!(
+            base_scores(character_sheet),
+            vec![
+                (AbilityName::Strength, 15),
+                (AbilityName::Dexterity, 13),
+                (AbilityName::Constitution, 14),
+                (AbilityName::Intelligence, 12),
+                (AbilityName::Wisdom, 10),
+                (AbilityName::Charisma, 8),
+            ]
+        );
+        assert_eq!(character_sheet.roll_log.verify(base_ability_scores), vec![]);
     }
 
-    write!(stdout, "{}", termion::cursor::Show)?;
+    #[test]
+    fn defaults_to_primary_abilities_and_best_armor() {
+        let character_sheet = run(
+            7,
+            Answers {
+                ability_score_method: Some(AbilityScoreMethod::DropLowest),
+                ..Answers::default()
+            },
+        );
+
+        // the highest rolls go to a fighter's Strength and Constitution
+        assert_eq!(
+            base_scores(&character_sheet),
+            vec![
+                (AbilityName::Strength, 15),
+                (AbilityName::Dexterity, 12),
+                (AbilityName::Constitution, 14),
+                (AbilityName::Intelligence, 11),
+                (AbilityName::Wisdom, 8),
+                (AbilityName::Charisma, 5),
+            ]
+        );
+        // and they wear the chain mail they start with
+        assert_eq!(character_sheet.armor, Some(AvailableArmor::ChainMail));
+        assert_eq!(get_armor_class(&character_sheet).total, 18);
+    }
 
-    if pool.clone() > 0 {
-        *pool = pool.clone() - pool_used;
+    #[test]
+    fn answers_seed_replays_rolls() {
+        let answers = Answers {
+            seed: Some(7),
+            ability_score_method: Some(AbilityScoreMethod::DropLowest),
+            ..Answers::default()
+        };
+        let character_sheet = run(1, answers);
+        assert_eq!(
+            ability_score_rolls(&character_sheet),
+            vec![8, 15, 11, 12, 5, 14]
+        );
     }
 
-    return Ok(input);
+    #[test]
+    fn learns_spells_on_level_up() {
+        let wizard = Answers {
+            class: Some(AvailableClasses::Wizard),
+            level_up_class: Some(AvailableClasses::Wizard),
+            ..Answers::default()
+        };
+        let character_sheet = run(7, wizard.clone());
+        assert_eq!(character_sheet.spells.len(), 6);
+
+        let character_sheet = level_up(character_sheet, wizard.clone());
+        assert_eq!(character_sheet.spells.len(), 8);
+
+        // a 3rd level wizard can cast 2nd-level spells, but the catalog stops at 1st level
+        let character_sheet = level_up(character_sheet, wizard);
+        assert_eq!(character_sheet.spells.len(), 10);
+        assert!(character_sheet.spells.iter().all(|name| {
+            get_spell_by_name(name).is_some_and(|spell| spell.level == Level::Level1)
+        }));
+    }
+
+    #[test]
+    fn eldritch_knights_learn_wizard_spells() {
+        let answers = Answers {
+            level_up_class: Some(AvailableClasses::Fighter),
+            subclass: Some(AvailableSubclasses::EldritchKnight),
+            ..Answers::default()
+        };
+        let character_sheet = run(7, Answers::default());
+        let character_sheet = level_up(character_sheet, answers.clone());
+        assert!(character_sheet.spells.is_empty());
+
+        let character_sheet = level_up(character_sheet, answers);
+        assert_eq!(character_sheet.cantrips.len(), 2);
+        assert_eq!(character_sheet.spells.len(), 3);
+        assert!(character_sheet.spells.iter().all(|name| {
+            get_spell_by_name(name)
+                .is_some_and(|spell| spell.is_available_to(AvailableClasses::Wizard))
+        }));
+        assert_eq!(character_sheet.get_spell_slots()[0], 2);
+    }
+
+    /// Choose a class for a character being edited from the review page.
+    fn change_class(character_sheet: CharacterSheet, class: AvailableClasses) -> CharacterSheet {
+        let mut app = App::with_seed(7);
+        app.character_sheet = character_sheet;
+        app.set_answers(Answers {
+            class: Some(class),
+            ..Answers::default()
+        });
+        app.set_interactive(false);
+        app.reviewing = true;
+        app.class_page();
+        return app.character_sheet;
+    }
+
+    /// A 4th level fighter who took an ability score improvement and the Actor feat.
+    fn levelled_fighter() -> CharacterSheet {
+        let answers = Answers {
+            level_up_class: Some(AvailableClasses::Fighter),
+            ..Answers::default()
+        };
+        let mut character_sheet = run(7, Answers::default());
+        for _ in 0..3 {
+            character_sheet = level_up(character_sheet, answers.clone());
+        }
+        let mut app = App::with_seed(7);
+        app.character_sheet = character_sheet;
+        app.apply_feat(AvailableFeats::Actor);
+        return app.character_sheet;
+    }
+
+    fn increases_from(character_sheet: &CharacterSheet, source: Source) -> usize {
+        return character_sheet
+            .ability_score_increases
+            .iter()
+            .filter(|increase| increase.source == source)
+            .count();
+    }
+
+    #[test]
+    fn rechoosing_class_keeps_levels() {
+        let character_sheet = change_class(levelled_fighter(), AvailableClasses::Fighter);
+        assert_eq!(
+            character_sheet.get_class_level(AvailableClasses::Fighter),
+            4
+        );
+        assert_eq!(character_sheet.hit_dice.len(), 4);
+        assert_eq!(character_sheet.feats, vec![AvailableFeats::Actor]);
+        assert_eq!(increases_from(&character_sheet, Source::LevelUp), 1);
+    }
+
+    #[test]
+    fn changing_class_removes_level_up_choices() {
+        let character_sheet = change_class(levelled_fighter(), AvailableClasses::Wizard);
+        assert_eq!(
+            character_sheet.classes,
+            vec![ClassLevel::new(AvailableClasses::Wizard)]
+        );
+        assert_eq!(character_sheet.hit_dice.len(), 1);
+        assert!(character_sheet.feats.is_empty());
+        assert_eq!(increases_from(&character_sheet, Source::LevelUp), 0);
+        assert_eq!(
+            increases_from(&character_sheet, Source::Feat(AvailableFeats::Actor)),
+            0
+        );
+        // back to the scores the fighter started with
+        assert_eq!(
+            character_sheet.ability_scores.get_sorted(),
+            run(7, Answers::default()).ability_scores.get_sorted()
+        );
+    }
+
+    #[test]
+    fn logs_table_rolls() {
+        let character_sheet = run(7, Answers::default());
+        let ideals = character_sheet.background.get_ideals();
+        let rolled = character_sheet
+            .roll_log
+            .get_entries()
+            .iter()
+            .find_map(|entry| match &entry.event {
+                RollEvent::Roll { purpose, total, .. } if purpose == "Ideal" => Some(*total),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(character_sheet.bio.ideal, ideals[rolled as usize - 1]);
+    }
+
+    #[test]
+    fn levels_up_in_the_same_class() {
+        let wizard = Answers {
+            class: Some(AvailableClasses::Wizard),
+            ..Answers::default()
+        };
+        let mut character_sheet = run(7, wizard);
+        for _ in 0..3 {
+            character_sheet = level_up(character_sheet, Answers::default());
+        }
+        assert_eq!(
+            character_sheet.classes,
+            vec![ClassLevel {
+                class: AvailableClasses::Wizard,
+                level: 4,
+                subclass: Some(AvailableSubclasses::Abjuration),
+            }]
+        );
+
+        // the 4th level ability score improvement goes to the wizard's Intelligence
+        let improvements = character_sheet
+            .ability_score_increases
+            .iter()
+            .filter(|increase| increase.source == Source::LevelUp)
+            .map(|increase| (increase.ability, increase.increase))
+            .collect::<Vec<(AbilityName, i8)>>();
+        assert_eq!(improvements, vec![(AbilityName::Intelligence, 2)]);
+    }
 }
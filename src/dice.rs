@@ -0,0 +1,489 @@
+use super::*;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::{error::Error, fmt, str::FromStr};
+
+/// The most dice a single expression may roll, so a typo like `10000d6` can't stall the roller.
+pub const MAX_DICE: u32 = 1000;
+
+/// The most sides a die in an expression may have.
+pub const MAX_SIDES: u32 = 1000;
+
+/// The random number generator used for every roll. It's seedable so that a run's rolls can be
+/// replayed exactly.
+pub type DiceRng = StdRng;
+
+/// A generator that always produces the same rolls for the same seed.
+pub fn seeded_rng(seed: u64) -> DiceRng {
+    return StdRng::seed_from_u64(seed);
+}
+
+/// Which dice count towards the total when more are rolled than are kept.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Keep {
+    Highest(u32),
+    Lowest(u32),
+}
+
+impl fmt::Display for Keep {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Keep::Highest(count) => write!(f, "kh{}", count),
+            Keep::Lowest(count) => write!(f, "kl{}", count),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DiceExpressionError {
+    Empty,
+    /// A character that doesn't belong in dice notation.
+    Unexpected(char),
+    /// A number was expected but missing, e.g. after `d` or `+`.
+    MissingNumber,
+    /// The die count or size is zero or too large.
+    OutOfRange(u32),
+    /// More dice are kept or dropped than are rolled.
+    KeepOutOfRange {
+        keep: u32,
+        count: u32,
+    },
+    /// Every face of the die would be rerolled.
+    RerollOutOfRange {
+        reroll: u32,
+        sides: u32,
+    },
+    /// Advantage and disadvantage only apply to a single d20-style roll.
+    AdvantageWithMultipleDice,
+}
+
+impl fmt::Display for DiceExpressionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DiceExpressionError::Empty => write!(f, "the dice expression is empty"),
+            DiceExpressionError::Unexpected(c) => write!(f, "unexpected '{}'", c),
+            DiceExpressionError::MissingNumber => write!(f, "expected a number"),
+            DiceExpressionError::OutOfRange(number) => write!(
+                f,
+                "{} is out of range; dice and sides must be between 1 and {}",
+                number,
+                MAX_DICE.max(MAX_SIDES)
+            ),
+            DiceExpressionError::KeepOutOfRange { keep, count } => {
+                write!(f, "can't keep or drop {} of {} dice", keep, count)
+            }
+            DiceExpressionError::RerollOutOfRange { reroll, sides } => write!(
+                f,
+                "can't reroll {} or lower on a die with {} sides",
+                reroll, sides
+            ),
+            DiceExpressionError::AdvantageWithMultipleDice => {
+                write!(f, "advantage and disadvantage only apply to a single die")
+            }
+        }
+    }
+}
+
+impl Error for DiceExpressionError {}
+
+/// A roll in standard dice notation: a number of dice, optionally keeping only the highest or
+/// lowest few, plus a flat modifier. Supported forms include `2d6+3`, `d100`, `4d6kh3`,
+/// `4d6dl1`, `4d6r1kh3` (rerolling ones), `2d20kl1`, `1d20adv`, `1d20dis` and a plain number
+/// such as `1`.
+///
+/// ```
+/// use dnd_character_builder::dice::{seeded_rng, DiceExpression, Keep};
+///
+/// let expression: DiceExpression = "4d6kh3".parse().unwrap();
+/// assert_eq!(expression, DiceExpression::new(4, 6).keep(Keep::Highest(3)));
+///
+/// let roll = expression.roll(&mut seeded_rng(7));
+/// // the same seed always gives the same dice
+/// assert_eq!(roll, expression.roll(&mut seeded_rng(7)));
+/// assert_eq!(roll.dice.len(), 4);
+/// assert_eq!(roll.get_kept().count(), 3);
+/// assert_eq!(roll.get_dropped().count(), 1);
+/// assert_eq!(roll.total, roll.get_kept().map(|die| die.value as i32).sum::<i32>());
+///
+/// // advantage rolls a second d20 and keeps the better one
+/// let advantage: DiceExpression = "1d20adv+5".parse().unwrap();
+/// assert_eq!(advantage.to_string(), "2d20kh1+5");
+/// assert!("3d20adv".parse::<DiceExpression>().is_err());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DiceExpression {
+    pub count: u32,
+    pub sides: u32,
+    /// Dice showing this value or lower are rolled again until they don't.
+    pub reroll: Option<u32>,
+    pub keep: Option<Keep>,
+    pub modifier: i32,
+}
+
+impl DiceExpression {
+    pub fn new(count: u32, sides: u32) -> DiceExpression {
+        return DiceExpression {
+            count,
+            sides,
+            reroll: None,
+            keep: None,
+            modifier: 0,
+        };
+    }
+
+    pub fn reroll(mut self, reroll: u32) -> DiceExpression {
+        self.reroll = Some(reroll);
+        return self;
+    }
+
+    pub fn keep(mut self, keep: Keep) -> DiceExpression {
+        self.keep = Some(keep);
+        return self;
+    }
+
+    pub fn modifier(mut self, modifier: i32) -> DiceExpression {
+        self.modifier = modifier;
+        return self;
+    }
+
+    pub fn get_minimum(&self) -> i32 {
+        let lowest = self.reroll.unwrap_or(0) + 1;
+        return (self.get_kept_count() * lowest) as i32 + self.modifier;
+    }
+
+    pub fn get_maximum(&self) -> i32 {
+        return (self.get_kept_count() * self.sides) as i32 + self.modifier;
+    }
+
+    fn get_kept_count(&self) -> u32 {
+        return match self.keep {
+            Some(Keep::Highest(count)) | Some(Keep::Lowest(count)) => count,
+            None => self.count,
+        };
+    }
+
+    pub fn roll<R: Rng + ?Sized>(&self, rng: &mut R) -> DiceRoll {
+        let mut dice = Vec::new();
+        for _ in 0..self.count {
+            let mut value = rng.gen_range(1..=self.sides);
+            while value <= self.reroll.unwrap_or(0) {
+                dice.push(DieRoll {
+                    value,
+                    kept: false,
+                    rerolled: true,
+                });
+                value = rng.gen_range(1..=self.sides);
+            }
+            dice.push(DieRoll {
+                value,
+                kept: false,
+                rerolled: false,
+            });
+        }
+        return self.evaluate(dice);
+    }
+
+    /// Work out which of the rolled dice are kept and the resulting total.
+    fn evaluate(&self, mut dice: Vec<DieRoll>) -> DiceRoll {
+        let mut order: Vec<usize> = (0..dice.len()).filter(|i| !dice[*i].rerolled).collect();
+        let kept_count = match self.keep {
+            Some(Keep::Highest(count)) => {
+                order.sort_by(|a, b| dice[*b].value.cmp(&dice[*a].value));
+                count as usize
+            }
+            Some(Keep::Lowest(count)) => {
+                order.sort_by(|a, b| dice[*a].value.cmp(&dice[*b].value));
+                count as usize
+            }
+            None => order.len(),
+        };
+
+        for index in order.into_iter().take(kept_count) {
+            dice[index].kept = true;
+        }
+
+        let total = dice
+            .iter()
+            .filter(|die| die.kept)
+            .map(|die| die.value as i32)
+            .sum::<i32>()
+            + self.modifier;
+
+        return DiceRoll {
+            expression: *self,
+            dice,
+            total,
+        };
+    }
+}
+
+impl fmt::Display for DiceExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.count == 0 {
+            return write!(f, "{}", self.modifier);
+        }
+        write!(f, "{}d{}", self.count, self.sides)?;
+        if let Some(reroll) = self.reroll {
+            write!(f, "r{}", reroll)?;
+        }
+        if let Some(keep) = self.keep {
+            write!(f, "{}", keep)?;
+        }
+        match self.modifier {
+            0 => Ok(()),
+            modifier if modifier > 0 => write!(f, "+{}", modifier),
+            modifier => write!(f, "{}", modifier),
+        }
+    }
+}
+
+impl FromStr for DiceExpression {
+    type Err = DiceExpressionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input: String = s
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .flat_map(|c| c.to_lowercase())
+            .collect();
+        if input.is_empty() {
+            return Err(DiceExpressionError::Empty);
+        }
+        let mut parser = Parser {
+            chars: input.chars().collect(),
+            position: 0,
+        };
+
+        let leading = parser.read_number();
+        let mut expression = if parser.eat("d") {
+            let count = leading.unwrap_or(1);
+            let sides = parser
+                .read_number()
+                .ok_or(DiceExpressionError::MissingNumber)?;
+            if count == 0 || count > MAX_DICE {
+                return Err(DiceExpressionError::OutOfRange(count));
+            }
+            if sides == 0 || sides > MAX_SIDES {
+                return Err(DiceExpressionError::OutOfRange(sides));
+            }
+
+            // advantage rolls the die twice and keeps the better result, disadvantage the worse
+            let advantage = if parser.eat("adv") {
+                Some(Keep::Highest(1))
+            } else if parser.eat("dis") {
+                Some(Keep::Lowest(1))
+            } else {
+                None
+            };
+            match advantage {
+                Some(_) if count != 1 => {
+                    return Err(DiceExpressionError::AdvantageWithMultipleDice)
+                }
+                Some(keep) => DiceExpression::new(2, sides).keep(keep),
+                None => {
+                    let reroll = match parser.eat("r") {
+                        true => Some(parser.read_number().unwrap_or(1)),
+                        false => None,
+                    };
+                    if let Some(reroll) = reroll.filter(|reroll| *reroll >= sides) {
+                        return Err(DiceExpressionError::RerollOutOfRange { reroll, sides });
+                    }
+                    let keep = parser.read_keep(count)?;
+                    DiceExpression {
+                        reroll,
+                        keep,
+                        ..DiceExpression::new(count, sides)
+                    }
+                }
+            }
+        } else {
+            // a flat number with no dice, such as a blowgun's 1 damage
+            let modifier = leading.ok_or_else(|| parser.unexpected())?;
+            DiceExpression::new(0, 0).modifier(modifier as i32)
+        };
+
+        while let Some(sign) = parser.peek() {
+            let sign = match sign {
+                '+' => 1,
+                '-' => -1,
+                _ => return Err(parser.unexpected()),
+            };
+            parser.position += 1;
+            let number = parser
+                .read_number()
+                .ok_or(DiceExpressionError::MissingNumber)?;
+            expression.modifier += sign * number as i32;
+        }
+
+        return Ok(expression);
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        return self.chars.get(self.position).copied();
+    }
+
+    fn unexpected(&self) -> DiceExpressionError {
+        return match self.peek() {
+            Some(c) => DiceExpressionError::Unexpected(c),
+            None => DiceExpressionError::MissingNumber,
+        };
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        let token: Vec<char> = token.chars().collect();
+        if self.chars[self.position..].starts_with(&token) {
+            self.position += token.len();
+            return true;
+        }
+        return false;
+    }
+
+    fn read_number(&mut self) -> Option<u32> {
+        let start = self.position;
+        while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+            self.position += 1;
+        }
+        if start == self.position {
+            return None;
+        }
+        // saturate rather than overflow so huge numbers are reported as out of range
+        let digits: String = self.chars[start..self.position].iter().collect();
+        return Some(digits.parse().unwrap_or(u32::MAX));
+    }
+
+    /// Read an optional keep or drop suffix for a roll of `count` dice.
+    fn read_keep(&mut self, count: u32) -> Result<Option<Keep>, DiceExpressionError> {
+        let (highest, drop) = if self.eat("kh") {
+            (true, false)
+        } else if self.eat("kl") {
+            (false, false)
+        } else if self.eat("k") {
+            (true, false)
+        } else if self.eat("dl") {
+            (true, true)
+        } else if self.eat("dh") {
+            (false, true)
+        } else if self.eat("d") {
+            (true, true)
+        } else {
+            return Ok(None);
+        };
+
+        let number = self.read_number().unwrap_or(1);
+        if number > count || (!drop && number == 0) {
+            return Err(DiceExpressionError::KeepOutOfRange {
+                keep: number,
+                count,
+            });
+        }
+        let kept = if drop { count - number } else { number };
+        return Ok(Some(match highest {
+            true => Keep::Highest(kept),
+            false => Keep::Lowest(kept),
+        }));
+    }
+}
+
+/// One die from a roll, and whether it counted towards the total.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DieRoll {
+    pub value: u32,
+    pub kept: bool,
+    /// The die was rolled again because it came up too low, so it never counts.
+    pub rerolled: bool,
+}
+
+/// The outcome of rolling a dice expression: every die rolled, in order, and the total.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiceRoll {
+    pub expression: DiceExpression,
+    pub dice: Vec<DieRoll>,
+    pub total: i32,
+}
+
+impl DiceRoll {
+    pub fn get_kept(&self) -> impl Iterator<Item = &DieRoll> {
+        return self.dice.iter().filter(|die| die.kept);
+    }
+
+    pub fn get_dropped(&self) -> impl Iterator<Item = &DieRoll> {
+        return self.dice.iter().filter(|die| !die.kept && !die.rerolled);
+    }
+}
+
+/// Shows each die with dropped and rerolled dice in parentheses, e.g.
+/// "4d6kh3: [6, 5, 3, (1)] = 14".
+impl fmt::Display for DiceRoll {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.dice.is_empty() {
+            return write!(f, "{} = {}", self.expression, self.total);
+        }
+        let dice = self
+            .dice
+            .iter()
+            .map(|die| match die.kept {
+                true => die.value.to_string(),
+                false => format!("({})", die.value),
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+        write!(f, "{}: [{}]", self.expression, dice)?;
+        match self.expression.modifier {
+            0 => {}
+            modifier if modifier > 0 => write!(f, " + {}", modifier)?,
+            modifier => write!(f, " - {}", -modifier)?,
+        }
+        write!(f, " = {}", self.total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use character::AbilityScoreMethod;
+
+    fn values(roll: &DiceRoll) -> Vec<(u32, bool)> {
+        return roll.dice.iter().map(|die| (die.value, die.kept)).collect();
+    }
+
+    #[test]
+    fn seeded_rolls_are_repeatable() {
+        let expression = "4d6kh3".parse::<DiceExpression>().unwrap();
+        let roll = expression.roll(&mut seeded_rng(1));
+        assert_eq!(
+            values(&roll),
+            vec![(5, true), (2, true), (3, true), (2, false)]
+        );
+        assert_eq!(roll.total, 10);
+        assert_eq!(roll, expression.roll(&mut seeded_rng(1)));
+    }
+
+    #[test]
+    fn seeded_ability_scores() {
+        let method = AbilityScoreMethod::DropLowest;
+        let rolls = method.roll(&mut seeded_rng(7));
+        let dice = rolls
+            .iter()
+            .map(|roll| roll.dice.iter().map(|die| die.value).collect())
+            .collect::<Vec<Vec<u32>>>();
+        assert_eq!(
+            dice,
+            vec![
+                vec![3, 1, 1, 4],
+                vec![4, 2, 5, 6],
+                vec![4, 2, 5, 2],
+                vec![3, 5, 4, 3],
+                vec![1, 1, 1, 3],
+                vec![2, 5, 6, 3],
+            ]
+        );
+        assert_eq!(method.get_scores(&rolls), Some(vec![5, 8, 11, 12, 14, 15]));
+    }
+}
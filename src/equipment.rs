@@ -0,0 +1,486 @@
+use super::*;
+use armor::AvailableArmor;
+use character::Tool;
+use dice::DiceExpression;
+use enum_iterator::{all, Sequence};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use util::{EnumIter, EnumString};
+
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Sequence, EnumIter, EnumString, Serialize, Deserialize,
+)]
+pub enum Weapon {
+    Club,
+    Dagger,
+    Greatclub,
+    Handaxe,
+    Javelin,
+    LightHammer,
+    Mace,
+    Quarterstaff,
+    Sickle,
+    Spear,
+    LightCrossbow,
+    Dart,
+    Shortbow,
+    Sling,
+    Battleaxe,
+    Flail,
+    Glaive,
+    Greataxe,
+    Greatsword,
+    Halberd,
+    Lance,
+    Longsword,
+    Maul,
+    Morningstar,
+    Pike,
+    Rapier,
+    Scimitar,
+    Shortsword,
+    Trident,
+    WarPick,
+    Warhammer,
+    Whip,
+    Blowgun,
+    HandCrossbow,
+    HeavyCrossbow,
+    Longbow,
+    Net,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Sequence, EnumIter, EnumString)]
+pub enum WeaponCategory {
+    Simple,
+    Martial,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Sequence, EnumIter, EnumString)]
+pub enum DamageType {
+    Bludgeoning,
+    Piercing,
+    Slashing,
+}
+
+impl Weapon {
+    pub fn get_category(&self) -> WeaponCategory {
+        match *self {
+            Weapon::Club
+            | Weapon::Dagger
+            | Weapon::Greatclub
+            | Weapon::Handaxe
+            | Weapon::Javelin
+            | Weapon::LightHammer
+            | Weapon::Mace
+            | Weapon::Quarterstaff
+            | Weapon::Sickle
+            | Weapon::Spear
+            | Weapon::LightCrossbow
+            | Weapon::Dart
+            | Weapon::Shortbow
+            | Weapon::Sling => WeaponCategory::Simple,
+            _ => WeaponCategory::Martial,
+        }
+    }
+
+    pub fn is_ranged(&self) -> bool {
+        return matches!(
+            *self,
+            Weapon::LightCrossbow
+                | Weapon::Dart
+                | Weapon::Shortbow
+                | Weapon::Sling
+                | Weapon::Blowgun
+                | Weapon::HandCrossbow
+                | Weapon::HeavyCrossbow
+                | Weapon::Longbow
+                | Weapon::Net
+        );
+    }
+
+    /// The weapon's damage dice, e.g. "1d8". A net deals no damage.
+    pub fn get_damage(&self) -> Option<&'static str> {
+        let damage = match *self {
+            Weapon::Club
+            | Weapon::Dagger
+            | Weapon::LightHammer
+            | Weapon::Sickle
+            | Weapon::Dart
+            | Weapon::Sling
+            | Weapon::Whip => "1d4",
+            Weapon::Handaxe
+            | Weapon::Javelin
+            | Weapon::Mace
+            | Weapon::Quarterstaff
+            | Weapon::Spear
+            | Weapon::Shortbow
+            | Weapon::Scimitar
+            | Weapon::Shortsword
+            | Weapon::Trident
+            | Weapon::HandCrossbow => "1d6",
+            Weapon::Greatclub
+            | Weapon::LightCrossbow
+            | Weapon::Battleaxe
+            | Weapon::Flail
+            | Weapon::Longsword
+            | Weapon::Morningstar
+            | Weapon::Rapier
+            | Weapon::WarPick
+            | Weapon::Warhammer
+            | Weapon::Longbow => "1d8",
+            Weapon::Glaive | Weapon::Halberd | Weapon::Pike | Weapon::HeavyCrossbow => "1d10",
+            Weapon::Greataxe | Weapon::Lance => "1d12",
+            Weapon::Greatsword | Weapon::Maul => "2d6",
+            Weapon::Blowgun => "1",
+            Weapon::Net => return None,
+        };
+        return Some(damage);
+    }
+
+    /// The weapon's damage as a rollable expression.
+    pub fn get_damage_dice(&self) -> Option<DiceExpression> {
+        return self.get_damage().map(|damage| damage.parse().unwrap());
+    }
+
+    pub fn get_damage_type(&self) -> Option<DamageType> {
+        match *self {
+            Weapon::Club
+            | Weapon::Greatclub
+            | Weapon::LightHammer
+            | Weapon::Mace
+            | Weapon::Quarterstaff
+            | Weapon::Sling
+            | Weapon::Flail
+            | Weapon::Maul
+            | Weapon::Warhammer => Some(DamageType::Bludgeoning),
+            Weapon::Handaxe
+            | Weapon::Sickle
+            | Weapon::Battleaxe
+            | Weapon::Glaive
+            | Weapon::Greataxe
+            | Weapon::Greatsword
+            | Weapon::Halberd
+            | Weapon::Longsword
+            | Weapon::Scimitar
+            | Weapon::Whip => Some(DamageType::Slashing),
+            Weapon::Net => None,
+            _ => Some(DamageType::Piercing),
+        }
+    }
+}
+
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Sequence, EnumIter, EnumString, Serialize, Deserialize,
+)]
+pub enum Pack {
+    BurglarsPack,
+    DiplomatsPack,
+    DungeoneersPack,
+    EntertainersPack,
+    ExplorersPack,
+    PriestsPack,
+    ScholarsPack,
+}
+
+impl Pack {
+    pub fn get_contents(&self) -> Vec<&'static str> {
+        match *self {
+            Pack::BurglarsPack => vec![
+                "Backpack",
+                "Bag of 1,000 ball bearings",
+                "10 feet of string",
+                "Bell",
+                "5 candles",
+                "Crowbar",
+                "Hammer",
+                "10 pitons",
+                "Hooded lantern",
+                "2 flasks of oil",
+                "5 days of rations",
+                "Tinderbox",
+                "Waterskin",
+                "50 feet of hempen rope",
+            ],
+            Pack::DiplomatsPack => vec![
+                "Chest",
+                "2 cases for maps and scrolls",
+                "Fine clothes",
+                "Bottle of ink",
+                "Ink pen",
+                "Lamp",
+                "2 flasks of oil",
+                "5 sheets of paper",
+                "Vial of perfume",
+                "Sealing wax",
+                "Soap",
+            ],
+            Pack::DungeoneersPack => vec![
+                "Backpack",
+                "Crowbar",
+                "Hammer",
+                "10 pitons",
+                "10 torches",
+                "Tinderbox",
+                "10 days of rations",
+                "Waterskin",
+                "50 feet of hempen rope",
+            ],
+            Pack::EntertainersPack => vec![
+                "Backpack",
+                "Bedroll",
+                "2 costumes",
+                "5 candles",
+                "5 days of rations",
+                "Waterskin",
+                "Disguise kit",
+            ],
+            Pack::ExplorersPack => vec![
+                "Backpack",
+                "Bedroll",
+                "Mess kit",
+                "Tinderbox",
+                "10 torches",
+                "10 days of rations",
+                "Waterskin",
+                "50 feet of hempen rope",
+            ],
+            Pack::PriestsPack => vec![
+                "Backpack",
+                "Blanket",
+                "10 candles",
+                "Tinderbox",
+                "Alms box",
+                "2 blocks of incense",
+                "Censer",
+                "Vestments",
+                "2 days of rations",
+                "Waterskin",
+            ],
+            Pack::ScholarsPack => vec![
+                "Backpack",
+                "Book of lore",
+                "Bottle of ink",
+                "Ink pen",
+                "10 sheets of parchment",
+                "Little bag of sand",
+                "Small knife",
+            ],
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Item {
+    Weapon(Weapon),
+    Armor(AvailableArmor),
+    Shield,
+    Tool(Tool),
+    Pack(Pack),
+    Gear(String),
+}
+
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Item::Weapon(weapon) => write!(f, "{}", weapon),
+            Item::Armor(armor) => write!(f, "{}", armor),
+            Item::Shield => write!(f, "Shield"),
+            Item::Tool(tool) => write!(f, "{}", tool),
+            Item::Pack(pack) => write!(f, "{}", pack),
+            Item::Gear(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Coins, in each of the five denominations.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Currency {
+    pub copper: u32,
+    pub silver: u32,
+    pub electrum: u32,
+    pub gold: u32,
+    pub platinum: u32,
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let coins = [
+            (self.platinum, "pp"),
+            (self.gold, "gp"),
+            (self.electrum, "ep"),
+            (self.silver, "sp"),
+            (self.copper, "cp"),
+        ]
+        .iter()
+        .filter(|(amount, _)| *amount > 0)
+        .map(|(amount, denomination)| format!("{} {}", amount, denomination))
+        .collect::<Vec<String>>();
+        write!(f, "{}", coins.join(", "))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InventoryItem {
+    pub item: Item,
+    pub quantity: u32,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Inventory {
+    pub items: Vec<InventoryItem>,
+    pub currency: Currency,
+}
+
+impl Inventory {
+    /// Add some of an item, stacking it with any of the same item already carried.
+    pub fn add(&mut self, item: Item, quantity: u32) {
+        match self.items.iter_mut().find(|existing| existing.item == item) {
+            Some(existing) => existing.quantity += quantity,
+            None => self.items.push(InventoryItem { item, quantity }),
+        }
+    }
+
+    pub fn contains(&self, item: &Item) -> bool {
+        return self.items.iter().any(|existing| existing.item == *item);
+    }
+
+    pub fn get_armor(&self) -> Vec<AvailableArmor> {
+        return self
+            .items
+            .iter()
+            .filter_map(|existing| match existing.item {
+                Item::Armor(armor) => Some(armor),
+                _ => None,
+            })
+            .collect();
+    }
+
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.currency = Currency::default();
+    }
+}
+
+impl fmt::Display for Inventory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut entries = self
+            .items
+            .iter()
+            .map(|existing| match existing.quantity {
+                1 => existing.item.to_string(),
+                quantity => format!("{} ({})", existing.item, quantity),
+            })
+            .collect::<Vec<String>>();
+        if self.currency != Currency::default() {
+            entries.push(self.currency.to_string());
+        }
+        write!(f, "{}", entries.join(", "))
+    }
+}
+
+/// A kind of item where the player gets to pick which one they receive.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ItemGroup {
+    SimpleWeapon,
+    SimpleMeleeWeapon,
+    MartialWeapon,
+    MartialMeleeWeapon,
+    MusicalInstrument,
+    ArtisansTools,
+}
+
+impl ItemGroup {
+    pub fn get_items(&self) -> Vec<Item> {
+        let weapons = |category: WeaponCategory, melee_only: bool| {
+            Weapon::iter()
+                .into_iter()
+                .filter(|weapon| weapon.get_category() == category)
+                .filter(|weapon| !melee_only || !weapon.is_ranged())
+                .map(Item::Weapon)
+                .collect()
+        };
+        match *self {
+            ItemGroup::SimpleWeapon => weapons(WeaponCategory::Simple, false),
+            ItemGroup::SimpleMeleeWeapon => weapons(WeaponCategory::Simple, true),
+            ItemGroup::MartialWeapon => weapons(WeaponCategory::Martial, false),
+            ItemGroup::MartialMeleeWeapon => weapons(WeaponCategory::Martial, true),
+            ItemGroup::MusicalInstrument => Tool::musical_instruments()
+                .into_iter()
+                .map(Item::Tool)
+                .collect(),
+            ItemGroup::ArtisansTools => {
+                Tool::artisans_tools().into_iter().map(Item::Tool).collect()
+            }
+        }
+    }
+}
+
+impl fmt::Display for ItemGroup {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            ItemGroup::SimpleWeapon => "simple weapon",
+            ItemGroup::SimpleMeleeWeapon => "simple melee weapon",
+            ItemGroup::MartialWeapon => "martial weapon",
+            ItemGroup::MartialMeleeWeapon => "martial melee weapon",
+            ItemGroup::MusicalInstrument => "musical instrument",
+            ItemGroup::ArtisansTools => "set of artisan's tools",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// An entry in a class's or background's starting equipment: either a specific item or a pick
+/// from a group of items.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StartingItem {
+    Item(Item, u32),
+    Any(ItemGroup, u32),
+}
+
+impl StartingItem {
+    pub fn weapon(weapon: Weapon, quantity: u32) -> StartingItem {
+        return StartingItem::Item(Item::Weapon(weapon), quantity);
+    }
+
+    pub fn armor(armor: AvailableArmor) -> StartingItem {
+        return StartingItem::Item(Item::Armor(armor), 1);
+    }
+
+    pub fn shield() -> StartingItem {
+        return StartingItem::Item(Item::Shield, 1);
+    }
+
+    pub fn tool(tool: Tool) -> StartingItem {
+        return StartingItem::Item(Item::Tool(tool), 1);
+    }
+
+    pub fn pack(pack: Pack) -> StartingItem {
+        return StartingItem::Item(Item::Pack(pack), 1);
+    }
+
+    pub fn gear(name: &str, quantity: u32) -> StartingItem {
+        return StartingItem::Item(Item::Gear(name.to_string()), quantity);
+    }
+
+    pub fn any(group: ItemGroup, quantity: u32) -> StartingItem {
+        return StartingItem::Any(group, quantity);
+    }
+}
+
+impl fmt::Display for StartingItem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StartingItem::Item(item, 1) => write!(f, "{}", item),
+            StartingItem::Item(item, quantity) => write!(f, "{} ({})", item, quantity),
+            StartingItem::Any(group, 1) => write!(f, "Any {}", group),
+            StartingItem::Any(group, quantity) => write!(f, "Any {} ({})", group, quantity),
+        }
+    }
+}
+
+/// One of the choices offered by a class's starting equipment, e.g. "(a) a greataxe or (b) any
+/// martial melee weapon". Each option is a bundle of items taken together.
+pub type EquipmentChoice = Vec<Vec<StartingItem>>;
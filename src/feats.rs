@@ -0,0 +1,313 @@
+use super::*;
+use character::*;
+use enum_iterator::{all, Sequence};
+use races::AvailableRaces;
+use serde::{Deserialize, Serialize};
+use util::{EnumIter, EnumString};
+
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Sequence, EnumIter, EnumString, Serialize, Deserialize,
+)]
+pub enum AvailableFeats {
+    Actor,
+    Alert,
+    Athlete,
+    Charger,
+    CrossbowExpert,
+    DefensiveDuelist,
+    DualWielder,
+    DungeonDelver,
+    Durable,
+    DwarvenFortitude,
+    ElementalAdept,
+    ElvenAccuracy,
+    FadeAway,
+    Grappler,
+    GreatWeaponMaster,
+    Healer,
+    HeavilyArmored,
+    HeavyArmorMaster,
+    InfernalConstitution,
+    InspiringLeader,
+    KeenMind,
+    LightlyArmored,
+    Linguist,
+    Lucky,
+    MageSlayer,
+    MediumArmorMaster,
+    Mobile,
+    ModeratelyArmored,
+    MountedCombatant,
+    Observant,
+    OrcishFury,
+    PolearmMaster,
+    RitualCaster,
+    SavageAttacker,
+    SecondChance,
+    Sentinel,
+    Sharpshooter,
+    ShieldMaster,
+    Skilled,
+    Skulker,
+    SpellSniper,
+    TavernBrawler,
+    Tough,
+    WarCaster,
+}
+
+/// A requirement a character must meet before taking a feat.
+#[derive(Clone, Debug)]
+pub enum Prerequisite {
+    /// The ability score must be at least the given value.
+    AbilityScore(AbilityName, i8),
+    /// At least one of the ability scores must be at least the given value.
+    AnyAbilityScore(Vec<AbilityName>, i8),
+    Race(Vec<AvailableRaces>),
+    Spellcasting,
+    ArmorProficiency(ArmorCategory),
+}
+
+impl Prerequisite {
+    pub fn is_met_by(&self, character_sheet: &CharacterSheet) -> bool {
+        match self {
+            Prerequisite::AbilityScore(ability, minimum) => {
+                character_sheet.ability_scores.get(*ability) >= *minimum
+            }
+            Prerequisite::AnyAbilityScore(abilities, minimum) => abilities
+                .iter()
+                .any(|ability| character_sheet.ability_scores.get(*ability) >= *minimum),
+            Prerequisite::Race(races) => races.contains(&character_sheet.race),
+            Prerequisite::Spellcasting => character_sheet.can_cast_spells(),
+            Prerequisite::ArmorProficiency(category) => {
+                character_sheet.armor_proficiencies.contains(*category)
+            }
+        }
+    }
+}
+
+/// A mechanical benefit granted when a feat is taken.
+#[derive(Clone, Debug)]
+pub enum FeatEffect {
+    /// Increase one of the listed ability scores, to a maximum of 20.
+    AbilityScoreIncrease(Vec<AbilityName>, i8),
+    /// Gain proficiency in the given number of skills of the player's choice.
+    SkillProficiencies(usize),
+    /// Learn the given number of languages of the player's choice.
+    Languages(usize),
+    ArmorProficiency(ArmorCategory),
+    /// Increase hit point maximum by the given amount per character level.
+    HitPointsPerLevel(i8),
+}
+
+const PHYSICAL: [AbilityName; 2] = [AbilityName::Strength, AbilityName::Dexterity];
+
+impl AvailableFeats {
+    pub fn get_description(&self) -> &'static str {
+        match *self {
+            AvailableFeats::Actor => "Advantage on Deception and Performance checks when passing yourself off as someone else, and you can mimic speech.",
+            AvailableFeats::Alert => "+5 to initiative, you can't be surprised while conscious, and hidden attackers don't gain advantage against you.",
+            AvailableFeats::Athlete => "Standing up and climbing cost less movement, and you can jump with only a 5-foot running start.",
+            AvailableFeats::Charger => "After you Dash, make a melee attack or shove as a bonus action with extra damage or push.",
+            AvailableFeats::CrossbowExpert => "Ignore the loading property of crossbows and fire a hand crossbow as a bonus action.",
+            AvailableFeats::DefensiveDuelist => "Use your reaction to add your proficiency bonus to AC against a melee attack while wielding a finesse weapon.",
+            AvailableFeats::DualWielder => "+1 AC while wielding a melee weapon in each hand, and two-weapon fighting with non-light weapons.",
+            AvailableFeats::DungeonDelver => "Advantage on checks to detect secret doors and on saves against traps, and resistance to trap damage.",
+            AvailableFeats::Durable => "When you roll a Hit Die to regain hit points, the minimum you regain is twice your Constitution modifier.",
+            AvailableFeats::DwarvenFortitude => "When you take the Dodge action, you can spend one Hit Die to heal.",
+            AvailableFeats::ElementalAdept => "Spells you cast ignore resistance to a chosen damage type, and 1s on damage dice count as 2s.",
+            AvailableFeats::ElvenAccuracy => "When you have advantage on an attack roll using Dexterity, Intelligence, Wisdom or Charisma, reroll one die.",
+            AvailableFeats::FadeAway => "Immediately after you take damage, you can use your reaction to magically become invisible.",
+            AvailableFeats::Grappler => "Advantage on attack rolls against a creature you are grappling, and you can pin it.",
+            AvailableFeats::GreatWeaponMaster => "Extra attacks on critical hits and kills, and trade -5 to hit for +10 damage with heavy weapons.",
+            AvailableFeats::Healer => "Stabilize creatures and restore hit points with a healer's kit.",
+            AvailableFeats::HeavilyArmored => "You gain proficiency with heavy armor.",
+            AvailableFeats::HeavyArmorMaster => "While wearing heavy armor, bludgeoning, piercing and slashing damage from nonmagical weapons is reduced by 3.",
+            AvailableFeats::InfernalConstitution => "Resistance to cold and poison damage, and advantage on saving throws against being poisoned.",
+            AvailableFeats::InspiringLeader => "Spend 10 minutes to grant up to six creatures temporary hit points equal to your level + Charisma modifier.",
+            AvailableFeats::KeenMind => "Always know north, the hours until sunrise or sunset, and recall anything from the past month.",
+            AvailableFeats::LightlyArmored => "You gain proficiency with light armor.",
+            AvailableFeats::Linguist => "Learn three languages and create written ciphers.",
+            AvailableFeats::Lucky => "Three luck points per long rest to reroll attack rolls, ability checks or saving throws.",
+            AvailableFeats::MageSlayer => "Punish nearby spellcasters with reaction attacks and gain advantage on saves against their spells.",
+            AvailableFeats::MediumArmorMaster => "Medium armor doesn't impose disadvantage on Stealth, and its Dexterity cap is 3 instead of 2.",
+            AvailableFeats::Mobile => "+10 feet speed, Dash ignores difficult terrain, and no opportunity attacks from creatures you attack.",
+            AvailableFeats::ModeratelyArmored => "You gain proficiency with medium armor and shields.",
+            AvailableFeats::MountedCombatant => "Advantage on melee attacks against unmounted creatures smaller than your mount, and protect your mount.",
+            AvailableFeats::Observant => "+5 to passive Perception and Investigation, and you can read lips.",
+            AvailableFeats::OrcishFury => "Once per rest, roll one additional weapon damage die, and attack as a reaction when Relentless Endurance triggers.",
+            AvailableFeats::PolearmMaster => "Bonus action butt-end attacks and opportunity attacks when creatures enter your reach.",
+            AvailableFeats::RitualCaster => "Gain a ritual book with two 1st-level ritual spells, and learn more rituals as you find them.",
+            AvailableFeats::SavageAttacker => "Once per turn, reroll a melee weapon's damage dice and use either total.",
+            AvailableFeats::SecondChance => "When a creature hits you with an attack roll, you can use your reaction to force it to reroll.",
+            AvailableFeats::Sentinel => "Opportunity attacks reduce speed to 0, and you can attack creatures that attack your allies.",
+            AvailableFeats::Sharpshooter => "Ignore long range disadvantage and cover, and trade -5 to hit for +10 damage with ranged weapons.",
+            AvailableFeats::ShieldMaster => "Shove as a bonus action, add your shield's AC bonus to Dexterity saves, and evade area damage.",
+            AvailableFeats::Skilled => "You gain proficiency in three skills of your choice.",
+            AvailableFeats::Skulker => "Hide when lightly obscured, stay hidden after missing a ranged attack, and see normally in dim light.",
+            AvailableFeats::SpellSniper => "Double the range of attack-roll spells, ignore cover, and learn an attack cantrip.",
+            AvailableFeats::TavernBrawler => "Proficiency with improvised weapons, d4 unarmed strikes, and bonus action grapples.",
+            AvailableFeats::Tough => "Your hit point maximum increases by 2 for every level you have.",
+            AvailableFeats::WarCaster => "Advantage on concentration saves, cast with your hands full, and cast spells as opportunity attacks.",
+        }
+    }
+
+    pub fn get_prerequisites(&self) -> Vec<Prerequisite> {
+        match *self {
+            AvailableFeats::DefensiveDuelist | AvailableFeats::Skulker => {
+                vec![Prerequisite::AbilityScore(AbilityName::Dexterity, 13)]
+            }
+            AvailableFeats::DwarvenFortitude => {
+                vec![Prerequisite::Race(vec![AvailableRaces::Dwarf])]
+            }
+            AvailableFeats::ElementalAdept
+            | AvailableFeats::SpellSniper
+            | AvailableFeats::WarCaster => vec![Prerequisite::Spellcasting],
+            AvailableFeats::ElvenAccuracy => vec![Prerequisite::Race(vec![
+                AvailableRaces::Elf,
+                AvailableRaces::HalfElf,
+            ])],
+            AvailableFeats::FadeAway => vec![Prerequisite::Race(vec![AvailableRaces::Gnome])],
+            AvailableFeats::Grappler => {
+                vec![Prerequisite::AbilityScore(AbilityName::Strength, 13)]
+            }
+            AvailableFeats::HeavilyArmored => {
+                vec![Prerequisite::ArmorProficiency(ArmorCategory::Medium)]
+            }
+            AvailableFeats::HeavyArmorMaster => {
+                vec![Prerequisite::ArmorProficiency(ArmorCategory::Heavy)]
+            }
+            AvailableFeats::InfernalConstitution => {
+                vec![Prerequisite::Race(vec![AvailableRaces::Tiefling])]
+            }
+            AvailableFeats::InspiringLeader => {
+                vec![Prerequisite::AbilityScore(AbilityName::Charisma, 13)]
+            }
+            AvailableFeats::MediumArmorMaster => {
+                vec![Prerequisite::ArmorProficiency(ArmorCategory::Medium)]
+            }
+            AvailableFeats::ModeratelyArmored => {
+                vec![Prerequisite::ArmorProficiency(ArmorCategory::Light)]
+            }
+            AvailableFeats::OrcishFury => vec![Prerequisite::Race(vec![AvailableRaces::HalfOrc])],
+            AvailableFeats::RitualCaster => vec![Prerequisite::AnyAbilityScore(
+                vec![AbilityName::Intelligence, AbilityName::Wisdom],
+                13,
+            )],
+            AvailableFeats::SecondChance => {
+                vec![Prerequisite::Race(vec![AvailableRaces::Halfling])]
+            }
+            _ => vec![],
+        }
+    }
+
+    pub fn get_effects(&self) -> Vec<FeatEffect> {
+        match *self {
+            AvailableFeats::Actor => {
+                vec![FeatEffect::AbilityScoreIncrease(
+                    vec![AbilityName::Charisma],
+                    1,
+                )]
+            }
+            AvailableFeats::Athlete => {
+                vec![FeatEffect::AbilityScoreIncrease(PHYSICAL.to_vec(), 1)]
+            }
+            AvailableFeats::Durable | AvailableFeats::DwarvenFortitude => {
+                vec![FeatEffect::AbilityScoreIncrease(
+                    vec![AbilityName::Constitution],
+                    1,
+                )]
+            }
+            AvailableFeats::ElvenAccuracy => vec![FeatEffect::AbilityScoreIncrease(
+                vec![
+                    AbilityName::Dexterity,
+                    AbilityName::Intelligence,
+                    AbilityName::Wisdom,
+                    AbilityName::Charisma,
+                ],
+                1,
+            )],
+            AvailableFeats::FadeAway => {
+                vec![FeatEffect::AbilityScoreIncrease(
+                    vec![AbilityName::Dexterity, AbilityName::Intelligence],
+                    1,
+                )]
+            }
+            AvailableFeats::HeavilyArmored => vec![
+                FeatEffect::AbilityScoreIncrease(vec![AbilityName::Strength], 1),
+                FeatEffect::ArmorProficiency(ArmorCategory::Heavy),
+            ],
+            AvailableFeats::HeavyArmorMaster => {
+                vec![FeatEffect::AbilityScoreIncrease(
+                    vec![AbilityName::Strength],
+                    1,
+                )]
+            }
+            AvailableFeats::InfernalConstitution => {
+                vec![FeatEffect::AbilityScoreIncrease(
+                    vec![AbilityName::Constitution],
+                    1,
+                )]
+            }
+            AvailableFeats::KeenMind => {
+                vec![FeatEffect::AbilityScoreIncrease(
+                    vec![AbilityName::Intelligence],
+                    1,
+                )]
+            }
+            AvailableFeats::LightlyArmored => vec![
+                FeatEffect::AbilityScoreIncrease(PHYSICAL.to_vec(), 1),
+                FeatEffect::ArmorProficiency(ArmorCategory::Light),
+            ],
+            AvailableFeats::Linguist => vec![
+                FeatEffect::AbilityScoreIncrease(vec![AbilityName::Intelligence], 1),
+                FeatEffect::Languages(3),
+            ],
+            AvailableFeats::ModeratelyArmored => vec![
+                FeatEffect::AbilityScoreIncrease(PHYSICAL.to_vec(), 1),
+                FeatEffect::ArmorProficiency(ArmorCategory::Medium),
+                FeatEffect::ArmorProficiency(ArmorCategory::Shields),
+            ],
+            AvailableFeats::Observant => vec![FeatEffect::AbilityScoreIncrease(
+                vec![AbilityName::Intelligence, AbilityName::Wisdom],
+                1,
+            )],
+            AvailableFeats::OrcishFury => vec![FeatEffect::AbilityScoreIncrease(
+                vec![AbilityName::Strength, AbilityName::Constitution],
+                1,
+            )],
+            AvailableFeats::SecondChance => vec![FeatEffect::AbilityScoreIncrease(
+                vec![
+                    AbilityName::Dexterity,
+                    AbilityName::Constitution,
+                    AbilityName::Charisma,
+                ],
+                1,
+            )],
+            AvailableFeats::Skilled => vec![FeatEffect::SkillProficiencies(3)],
+            AvailableFeats::TavernBrawler => vec![FeatEffect::AbilityScoreIncrease(
+                vec![AbilityName::Strength, AbilityName::Constitution],
+                1,
+            )],
+            AvailableFeats::Tough => vec![FeatEffect::HitPointsPerLevel(2)],
+            _ => vec![],
+        }
+    }
+
+    /// Whether the character meets every prerequisite of the feat.
+    pub fn is_available_to(&self, character_sheet: &CharacterSheet) -> bool {
+        return self
+            .get_prerequisites()
+            .iter()
+            .all(|prerequisite| prerequisite.is_met_by(character_sheet));
+    }
+}
+
+/// Get the feats the character qualifies for and hasn't already taken.
+pub fn get_available_feats(character_sheet: &CharacterSheet) -> Vec<AvailableFeats> {
+    return AvailableFeats::iter()
+        .into_iter()
+        .filter(|feat| {
+            !character_sheet.feats.contains(feat) && feat.is_available_to(character_sheet)
+        })
+        .collect();
+}
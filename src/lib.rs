@@ -1,8 +1,20 @@
 #![allow(unused)]
+#![allow(clippy::needless_return)]
+pub mod answers;
+pub mod armor;
 pub mod backgrounds;
 pub mod character;
 pub mod classes;
+pub mod cli;
 pub mod controller;
+pub mod dice;
+pub mod equipment;
+pub mod feats;
 pub mod macros;
+pub mod pdf;
+pub mod point_buy;
 pub mod races;
+pub mod random;
+pub mod roll_log;
 pub mod spells;
+pub mod storage;
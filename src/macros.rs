@@ -4,6 +4,7 @@ This macro is used to create a macro that itself derives a set of traits.
 # Example
 
 ```
+# use dnd_character_builder::derive_alias;
 derive_alias! {
     derive_stuff => Eq, PartialEq, Ord, PartialOrd
 }
@@ -29,18 +30,20 @@ This macro is used to create multiple macros that themselves derive a set of tra
 # Example
 
 ```
-derive_alias! {
+# use dnd_character_builder::{derive_alias, derive_aliases};
+# use enum_iterator::Sequence;
+derive_aliases! {
     derive_stuff => Eq, PartialEq, Ord, PartialOrd;
     derive_stuff2 => Clone, Copy, Debug;
-    derive_stuff3 => Hash, Sequence, MyEnumIter;
+    derive_stuff3 => Hash, Sequence;
 }
 
 derive_stuff! { struct Foo(i32); }
 derive_stuff2! { struct Bar(i32); }
 derive_stuff3! {
     struct Baz {
-        a: i32,
-        b: i32,
+        a: u8,
+        b: u8,
     }
 }
 ```
@@ -1,5 +1,15 @@
-use dnd_character_builder::controller::App;
+use dnd_character_builder::cli::{self, CliError};
+use std::{env, process};
+
 fn main() {
-    let mut app = App::new();
-    app.run();
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    match cli::run(&args) {
+        Ok(()) => {}
+        // the problems have already been printed
+        Err(CliError::VerificationFailed(_)) => process::exit(1),
+        Err(error) => {
+            eprintln!("Error: {}", error);
+            process::exit(2);
+        }
+    }
 }
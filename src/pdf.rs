@@ -0,0 +1,830 @@
+use super::*;
+use armor::get_armor_class;
+use character::{AbilityName, CharacterSheet, Skill};
+use std::{fs, io, path::Path};
+use util::EnumIter;
+
+/// US Letter, in points.
+const PAGE_WIDTH: f32 = 612.0;
+const PAGE_HEIGHT: f32 = 792.0;
+const MARGIN: f32 = 36.0;
+const LABEL_SIZE: f32 = 6.0;
+const MIN_TEXT_SIZE: f32 = 4.0;
+
+/// Widths of the printable ASCII characters in Helvetica, in thousandths of the font size.
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556, 1015, 667, 667, 722, 722, 667,
+    611, 778, 722, 278, 500, 667, 556, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667,
+    667, 611, 278, 278, 278, 469, 556, 333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500,
+    222, 833, 556, 556, 556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+
+/// The width of some text set in Helvetica at the given size.
+fn text_width(text: &str, size: f32) -> f32 {
+    let width = text
+        .chars()
+        .map(|c| match c as u32 {
+            32..=126 => HELVETICA_WIDTHS[c as usize - 32] as f32,
+            _ => 556.0,
+        })
+        .sum::<f32>();
+    return width * size / 1000.0;
+}
+
+/// Break text into lines no wider than the given width, keeping existing line breaks.
+fn wrap(text: &str, size: f32, width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let candidate = match line.is_empty() {
+                true => word.to_string(),
+                false => format!("{} {}", line, word),
+            };
+            if line.is_empty() || text_width(&candidate, size) <= width {
+                line = candidate;
+            } else {
+                lines.push(std::mem::replace(&mut line, word.to_string()));
+            }
+        }
+        lines.push(line);
+    }
+    return lines;
+}
+
+/// Write text as a PDF string in the WinAnsi encoding used by the standard fonts, replacing
+/// anything it can't represent.
+fn pdf_string(text: &str) -> String {
+    let mut string = String::from("(");
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => {
+                string.push('\\');
+                string.push(c);
+            }
+            '\n' => string.push_str("\\n"),
+            ' '..='~' => string.push(c),
+            '\u{a0}'..='\u{ff}' => string.push_str(&format!("\\{:03o}", c as u32)),
+            '\u{2018}' => string.push_str("\\221"),
+            '\u{2019}' => string.push_str("\\222"),
+            '\u{201c}' => string.push_str("\\223"),
+            '\u{201d}' => string.push_str("\\224"),
+            '\u{2013}' => string.push_str("\\226"),
+            '\u{2014}' => string.push_str("\\227"),
+            '\u{2022}' => string.push_str("\\225"),
+            '\u{2026}' => string.push_str("\\205"),
+            _ => string.push('?'),
+        }
+    }
+    string.push(')');
+    return string;
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Align {
+    Left,
+    Center,
+}
+
+/// A fillable text field, which shows its value until the player types over it.
+struct Field {
+    name: String,
+    value: String,
+    /// Left, bottom, right and top edges, in PDF coordinates.
+    rect: [f32; 4],
+    size: f32,
+    align: Align,
+    multiline: bool,
+}
+
+impl Field {
+    /// The text drawn for the field's current value, so it shows in viewers that don't draw
+    /// fields themselves.
+    fn appearance(&self) -> String {
+        let width = self.rect[2] - self.rect[0];
+        let height = self.rect[3] - self.rect[1];
+        let mut stream = format!("/Tx BMC q BT /Helv {} Tf 0 g\n", self.size);
+        let lines = match self.multiline {
+            true => wrap(&self.value, self.size, width - 4.0),
+            false => vec![self.value.clone()],
+        };
+        let leading = self.size * 1.15;
+        for (i, line) in lines.iter().enumerate() {
+            let x = match self.align {
+                Align::Left => 2.0,
+                Align::Center => (width - text_width(line, self.size)) / 2.0,
+            };
+            let y = match self.multiline {
+                true => height - 2.0 - self.size - leading * i as f32,
+                false => (height - self.size * 0.7) / 2.0,
+            };
+            stream.push_str(&format!(
+                "1 0 0 1 {:.2} {:.2} Tm {} Tj\n",
+                x,
+                y,
+                pdf_string(line)
+            ));
+        }
+        stream.push_str("ET Q EMC");
+        return stream;
+    }
+}
+
+/// A one-page sheet being laid out. Positions are measured in points from the top left corner
+/// of the page, and flipped to PDF's bottom left origin as they're drawn.
+#[derive(Default)]
+struct Page {
+    content: String,
+    fields: Vec<Field>,
+}
+
+impl Page {
+    fn rect(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        self.content.push_str(&format!(
+            "{:.2} {:.2} {:.2} {:.2} re S\n",
+            x,
+            PAGE_HEIGHT - y - height,
+            width,
+            height
+        ));
+    }
+
+    fn fill_rect(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        self.content.push_str(&format!(
+            "{:.2} {:.2} {:.2} {:.2} re f\n",
+            x,
+            PAGE_HEIGHT - y - height,
+            width,
+            height
+        ));
+    }
+
+    /// Draw text with its baseline at `y`.
+    fn text(&mut self, x: f32, y: f32, size: f32, bold: bool, text: &str) {
+        let font = if bold { "HeBo" } else { "Helv" };
+        self.content.push_str(&format!(
+            "BT /{} {} Tf {:.2} {:.2} Td {} Tj ET\n",
+            font,
+            size,
+            x,
+            PAGE_HEIGHT - y,
+            pdf_string(text)
+        ));
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn field(
+        &mut self,
+        name: &str,
+        value: &str,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        size: f32,
+        align: Align,
+    ) {
+        // shrink the text until it fits
+        let mut size = size;
+        while size > MIN_TEXT_SIZE && text_width(value, size) > width - 4.0 {
+            size -= 0.5;
+        }
+        self.fields.push(Field {
+            name: name.to_string(),
+            value: value.to_string(),
+            rect: [x, PAGE_HEIGHT - y - height, x + width, PAGE_HEIGHT - y],
+            size,
+            align,
+            multiline: false,
+        });
+    }
+
+    fn multiline_field(&mut self, name: &str, value: &str, x: f32, y: f32, w: f32, h: f32) {
+        // shrink the text until every line fits
+        let mut size = 8.0;
+        while size > MIN_TEXT_SIZE
+            && wrap(value, size, w - 4.0).len() as f32 * size * 1.15 > h - 4.0
+        {
+            size -= 0.5;
+        }
+        self.fields.push(Field {
+            name: name.to_string(),
+            value: value.to_string(),
+            rect: [x, PAGE_HEIGHT - y - h, x + w, PAGE_HEIGHT - y],
+            size,
+            align: Align::Left,
+            multiline: true,
+        });
+    }
+
+    /// A bordered box with a label along its top and a single line field filling the rest.
+    #[allow(clippy::too_many_arguments)]
+    fn labelled_field(
+        &mut self,
+        name: &str,
+        label: &str,
+        value: &str,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        size: f32,
+    ) {
+        self.rect(x, y, width, height);
+        self.text(x + 3.0, y + LABEL_SIZE + 2.0, LABEL_SIZE, true, label);
+        let top = LABEL_SIZE + 3.0;
+        self.field(
+            name,
+            value,
+            x + 1.0,
+            y + top,
+            width - 2.0,
+            height - top - 1.0,
+            size,
+            Align::Center,
+        );
+    }
+
+    /// A bordered box with a label along its top and a multiline field filling the rest.
+    #[allow(clippy::too_many_arguments)]
+    fn labelled_multiline_field(
+        &mut self,
+        name: &str,
+        label: &str,
+        value: &str,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+    ) {
+        self.rect(x, y, width, height);
+        self.text(x + 3.0, y + LABEL_SIZE + 2.0, LABEL_SIZE, true, label);
+        let top = LABEL_SIZE + 3.0;
+        self.multiline_field(
+            name,
+            value,
+            x + 1.0,
+            y + top,
+            width - 2.0,
+            height - top - 1.0,
+        );
+    }
+
+    /// A row of a saving throw or skill list: a marker for proficiency, a field for the modifier
+    /// and the name. Expertise is marked with a second square.
+    #[allow(clippy::too_many_arguments)]
+    fn modifier_row(
+        &mut self,
+        name: &str,
+        label: &str,
+        modifier: i8,
+        proficient: bool,
+        expertise: bool,
+        x: f32,
+        y: f32,
+    ) {
+        let square = |page: &mut Page, x: f32| match proficient {
+            true => page.fill_rect(x, y + 3.0, 5.0, 5.0),
+            false => page.rect(x, y + 3.0, 5.0, 5.0),
+        };
+        square(self, x);
+        if expertise {
+            self.fill_rect(x + 7.0, y + 3.0, 5.0, 5.0);
+        }
+        self.field(
+            name,
+            &format!("{:+}", modifier),
+            x + 14.0,
+            y,
+            20.0,
+            11.0,
+            7.0,
+            Align::Center,
+        );
+        self.content.push_str(&format!(
+            "{:.2} {:.2} m {:.2} {:.2} l S\n",
+            x + 14.0,
+            PAGE_HEIGHT - y - 11.0,
+            x + 34.0,
+            PAGE_HEIGHT - y - 11.0
+        ));
+        self.text(x + 37.0, y + 8.5, 7.0, false, label);
+    }
+
+    /// Assemble the page into a complete PDF file.
+    fn finish(self) -> Vec<u8> {
+        // objects 1 to 6 are the catalog, page tree, page, fonts and page content, then each
+        // field takes two objects, one for itself and one for its appearance
+        let field_object = |i: usize| 7 + i * 2;
+        let form_object = field_object(self.fields.len());
+        let field_refs = (0..self.fields.len())
+            .map(|i| format!("{} 0 R", field_object(i)))
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        let mut objects = vec![
+            format!(
+                "<< /Type /Catalog /Pages 2 0 R /AcroForm {} 0 R >>",
+                form_object
+            ),
+            "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_string(),
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+                 /Resources << /Font << /Helv 4 0 R /HeBo 5 0 R >> >> \
+                 /Contents 6 0 R /Annots [{}] >>",
+                PAGE_WIDTH, PAGE_HEIGHT, field_refs
+            ),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica \
+             /Encoding /WinAnsiEncoding >>"
+                .to_string(),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold \
+             /Encoding /WinAnsiEncoding >>"
+                .to_string(),
+            stream_object("", &self.content),
+        ];
+        for (i, field) in self.fields.iter().enumerate() {
+            let [left, bottom, right, top] = field.rect;
+            objects.push(format!(
+                "<< /Type /Annot /Subtype /Widget /FT /Tx /F 4 /P 3 0 R /T {} /V {} \
+                 /Rect [{:.2} {:.2} {:.2} {:.2}] /DA (/Helv {} Tf 0 g) /Q {} /Ff {} \
+                 /AP << /N {} 0 R >> >>",
+                pdf_string(&field.name),
+                pdf_string(&field.value),
+                left,
+                bottom,
+                right,
+                top,
+                field.size,
+                match field.align {
+                    Align::Left => 0,
+                    Align::Center => 1,
+                },
+                // multiline fields allow line breaks, others don't scroll past the box
+                match field.multiline {
+                    true => 4096,
+                    false => 8388608,
+                },
+                field_object(i) + 1
+            ));
+            objects.push(stream_object(
+                &format!(
+                    "/Type /XObject /Subtype /Form /BBox [0 0 {:.2} {:.2}] \
+                     /Resources << /Font << /Helv 4 0 R >> >> ",
+                    right - left,
+                    top - bottom
+                ),
+                &field.appearance(),
+            ));
+        }
+        objects.push(format!(
+            "<< /Fields [{}] /NeedAppearances true /DA (/Helv 0 Tf 0 g) \
+             /DR << /Font << /Helv 4 0 R >> >> >>",
+            field_refs
+        ));
+
+        let mut pdf = b"%PDF-1.7\n%\xe2\xe3\xcf\xd3\n".to_vec();
+        let mut offsets = Vec::new();
+        for (i, object) in objects.iter().enumerate() {
+            offsets.push(pdf.len());
+            pdf.extend(format!("{} 0 obj\n{}\nendobj\n", i + 1, object).as_bytes());
+        }
+        let xref = pdf.len();
+        pdf.extend(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes());
+        for offset in offsets {
+            pdf.extend(format!("{:010} 00000 n \n", offset).as_bytes());
+        }
+        pdf.extend(
+            format!(
+                "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+                objects.len() + 1,
+                xref
+            )
+            .as_bytes(),
+        );
+        return pdf;
+    }
+}
+
+fn stream_object(dictionary: &str, contents: &str) -> String {
+    return format!(
+        "<< {}/Length {} >>\nstream\n{}\nendstream",
+        dictionary,
+        contents.len(),
+        contents
+    );
+}
+
+/// Lay out a complete one-page character sheet as a PDF with fillable fields, so players can
+/// print it or keep it up to date in a PDF viewer.
+pub fn to_pdf(character_sheet: &CharacterSheet) -> Vec<u8> {
+    let sheet = character_sheet;
+    let mut page = Page::default();
+    let ability_scores = &sheet.ability_scores;
+    let proficiency_bonus = sheet.get_proficiency_bonus();
+
+    // header
+    let right = PAGE_WIDTH - MARGIN;
+    page.rect(MARGIN, MARGIN, 220.0, 60.0);
+    page.field(
+        "name",
+        &sheet.name,
+        MARGIN + 4.0,
+        MARGIN + 8.0,
+        212.0,
+        30.0,
+        16.0,
+        Align::Left,
+    );
+    page.text(
+        MARGIN + 6.0,
+        MARGIN + 52.0,
+        LABEL_SIZE,
+        true,
+        "CHARACTER NAME",
+    );
+    let header = [
+        [
+            (
+                "class",
+                "CLASS & LEVEL",
+                format!("{} {}", &sheet["class"], sheet.get_level()),
+            ),
+            ("background", "BACKGROUND", sheet["background"].to_string()),
+            ("player", "PLAYER NAME", String::new()),
+        ],
+        [
+            ("race", "RACE", sheet["race"].to_string()),
+            ("alignment", "ALIGNMENT", sheet["alignment"].to_string()),
+            (
+                "experience_points",
+                "EXPERIENCE POINTS",
+                sheet["experience_points"].to_string(),
+            ),
+        ],
+    ];
+    let column_width = (right - (MARGIN + 228.0)) / 3.0;
+    for (row, fields) in header.iter().enumerate() {
+        for (column, (name, label, value)) in fields.iter().enumerate() {
+            let x = MARGIN + 228.0 + column as f32 * column_width;
+            let y = MARGIN + row as f32 * 30.0;
+            page.labelled_field(name, label, value, x, y, column_width - 4.0, 28.0, 9.0);
+        }
+    }
+
+    // ability scores and modifiers
+    let top = 104.0;
+    for (i, ability) in AbilityName::iter().into_iter().enumerate() {
+        let y = top + i as f32 * 76.0;
+        let key = ability.to_string().to_lowercase();
+        page.rect(MARGIN, y, 70.0, 70.0);
+        let label = ability.to_string().to_uppercase();
+        let label_x = MARGIN + (70.0 - text_width(&label, 7.0)) / 2.0;
+        page.text(label_x, y + 10.0, 7.0, true, &label);
+        let modifier = format!("{:+}", ability_scores.get_modifier(ability));
+        page.field(
+            &format!("{}_modifier", key),
+            &modifier,
+            MARGIN + 10.0,
+            y + 14.0,
+            50.0,
+            28.0,
+            20.0,
+            Align::Center,
+        );
+        page.rect(MARGIN + 20.0, y + 48.0, 30.0, 16.0);
+        page.field(
+            &format!("{}_score", key),
+            &ability_scores.get(ability).to_string(),
+            MARGIN + 21.0,
+            y + 49.0,
+            28.0,
+            14.0,
+            10.0,
+            Align::Center,
+        );
+    }
+
+    // proficiency bonus, saving throws and skills
+    let x = MARGIN + 78.0;
+    let width = 150.0;
+    page.labelled_field("inspiration", "INSPIRATION", "", x, top, 72.0, 26.0, 10.0);
+    page.labelled_field(
+        "proficiency_bonus",
+        "PROFICIENCY BONUS",
+        &format!("{:+}", proficiency_bonus),
+        x + 78.0,
+        top,
+        72.0,
+        26.0,
+        10.0,
+    );
+
+    let saves_top = top + 32.0;
+    let abilities = AbilityName::iter();
+    page.rect(x, saves_top, width, 16.0 + abilities.len() as f32 * 12.0);
+    page.text(x + 3.0, saves_top + 8.0, LABEL_SIZE, true, "SAVING THROWS");
+    for (i, ability) in abilities.iter().enumerate() {
+        page.modifier_row(
+            &format!("{}_save", ability.to_string().to_lowercase()),
+            &ability.to_string(),
+            sheet.get_saving_throw_modifier(*ability),
+            sheet.saving_throw_proficiencies.contains(*ability),
+            false,
+            x + 4.0,
+            saves_top + 12.0 + i as f32 * 12.0,
+        );
+    }
+
+    let skills_top = saves_top + 22.0 + abilities.len() as f32 * 12.0;
+    let skills = Skill::iter();
+    page.rect(x, skills_top, width, 16.0 + skills.len() as f32 * 12.0);
+    page.text(x + 3.0, skills_top + 8.0, LABEL_SIZE, true, "SKILLS");
+    for (i, skill) in skills.iter().enumerate() {
+        let name = skill.to_string().replace('-', " ");
+        page.modifier_row(
+            &name.to_lowercase().replace(' ', "_"),
+            &format!("{} ({})", name, &skill.get_ability().to_string()[..3]),
+            sheet.get_skill_modifier(*skill),
+            sheet.skill_proficiencies.contains(*skill),
+            sheet.skill_expertise.contains(*skill),
+            x + 4.0,
+            skills_top + 12.0 + i as f32 * 12.0,
+        );
+    }
+
+    let passive_top = skills_top + 22.0 + skills.len() as f32 * 12.0;
+    let passive_perception = 10 + sheet.get_skill_modifier(Skill::Perception);
+    page.labelled_field(
+        "passive_perception",
+        "PASSIVE WISDOM (PERCEPTION)",
+        &passive_perception.to_string(),
+        x,
+        passive_top,
+        width,
+        26.0,
+        10.0,
+    );
+    let mut proficiencies = Vec::new();
+    for (label, key) in [
+        ("Armor", "armor_proficiencies"),
+        ("Tools", "tool_proficiencies"),
+        ("Languages", "languages"),
+        ("Feats", "feats"),
+    ] {
+        if !sheet[key].is_empty() {
+            proficiencies.push(format!("{}: {}", label, &sheet[key]));
+        }
+    }
+    let proficiencies_top = passive_top + 32.0;
+    page.labelled_multiline_field(
+        "proficiencies",
+        "OTHER PROFICIENCIES & LANGUAGES",
+        &proficiencies.join("\n"),
+        x,
+        proficiencies_top,
+        width,
+        top + 456.0 - proficiencies_top,
+    );
+
+    // combat
+    let x = MARGIN + 236.0;
+    let width = 140.0;
+    let armor_class = get_armor_class(sheet).total.to_string();
+    let initiative = format!("{:+}", ability_scores.get_modifier(AbilityName::Dexterity));
+    let combat = [
+        ("armor_class", "ARMOR CLASS", armor_class),
+        ("initiative", "INITIATIVE", initiative),
+        ("speed", "SPEED", String::new()),
+    ];
+    for (i, (name, label, value)) in combat.iter().enumerate() {
+        let column_x = x + i as f32 * 47.0;
+        page.labelled_field(name, label, value, column_x, top, 46.0, 40.0, 16.0);
+    }
+    let max_hit_points = sheet.get_max_hit_points().to_string();
+    page.labelled_field(
+        "max_hit_points",
+        "HIT POINT MAXIMUM",
+        &max_hit_points,
+        x,
+        top + 46.0,
+        width,
+        24.0,
+        10.0,
+    );
+    page.labelled_field(
+        "current_hit_points",
+        "CURRENT HIT POINTS",
+        "",
+        x,
+        top + 76.0,
+        width,
+        44.0,
+        18.0,
+    );
+    page.labelled_field(
+        "temporary_hit_points",
+        "TEMPORARY HIT POINTS",
+        "",
+        x,
+        top + 126.0,
+        width,
+        30.0,
+        12.0,
+    );
+    page.labelled_field(
+        "hit_dice",
+        "HIT DICE",
+        &sheet["hit_dice"],
+        x,
+        top + 162.0,
+        width,
+        30.0,
+        10.0,
+    );
+    page.labelled_multiline_field(
+        "equipment",
+        "EQUIPMENT",
+        &sheet["equipment"].replace(", ", "\n"),
+        x,
+        top + 198.0,
+        width,
+        258.0,
+    );
+
+    // personality and features
+    let x = MARGIN + 384.0;
+    let width = right - x;
+    let bio = [
+        ("personality_traits", "PERSONALITY TRAITS", 58.0),
+        ("ideal", "IDEALS", 40.0),
+        ("bond", "BONDS", 40.0),
+        ("flaw", "FLAWS", 40.0),
+    ];
+    let mut y = top;
+    for (key, label, height) in bio {
+        page.labelled_multiline_field(key, label, &sheet[key], x, y, width, height);
+        y += height + 6.0;
+    }
+    let features = sheet
+        .features
+        .iter()
+        .map(|feature| feature.name.clone())
+        .collect::<Vec<String>>()
+        .join("\n");
+    page.labelled_multiline_field(
+        "features",
+        "FEATURES & TRAITS",
+        &features,
+        x,
+        y,
+        width,
+        top + 456.0 - y,
+    );
+
+    // spellcasting
+    let y = top + 462.0;
+    let (ability, save_dc, attack_bonus) = match sheet.get_spellcasting_ability() {
+        Some(ability) => {
+            let modifier = ability_scores.get_modifier(ability);
+            (
+                ability.to_string(),
+                (8 + proficiency_bonus + modifier).to_string(),
+                format!("{:+}", proficiency_bonus + modifier),
+            )
+        }
+        None => (String::new(), String::new(), String::new()),
+    };
+    let spellcasting = [
+        (
+            "spellcasting_ability",
+            "SPELLCASTING ABILITY",
+            ability,
+            100.0,
+        ),
+        ("spell_save_dc", "SPELL SAVE DC", save_dc, 70.0),
+        (
+            "spell_attack_bonus",
+            "SPELL ATTACK BONUS",
+            attack_bonus,
+            80.0,
+        ),
+    ];
+    let mut x = MARGIN;
+    for (name, label, value, width) in spellcasting.iter() {
+        page.labelled_field(name, label, value, x, y, *width, 26.0, 10.0);
+        x += width + 6.0;
+    }
+    page.labelled_field(
+        "spell_slots",
+        "SPELL SLOTS",
+        &sheet["spell_slots"],
+        x,
+        y,
+        right - x,
+        26.0,
+        9.0,
+    );
+    let y = y + 32.0;
+    let height = PAGE_HEIGHT - MARGIN - y;
+    page.labelled_multiline_field(
+        "cantrips",
+        "CANTRIPS",
+        &sheet.cantrips.join("\n"),
+        MARGIN,
+        y,
+        170.0,
+        height,
+    );
+    page.labelled_multiline_field(
+        "spells",
+        "SPELLS KNOWN OR PREPARED",
+        &sheet.spells.join(", "),
+        MARGIN + 176.0,
+        y,
+        right - MARGIN - 176.0,
+        height,
+    );
+
+    return page.finish();
+}
+
+/// Write a character's sheet to a PDF file, ready to print before a session.
+pub fn export_pdf<P: AsRef<Path>>(character_sheet: &CharacterSheet, path: P) -> io::Result<()> {
+    return fs::write(path, to_pdf(character_sheet));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find(haystack: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+        return haystack[from..]
+            .windows(needle.len())
+            .position(|window| window == needle)
+            .map(|position| from + position);
+    }
+
+    /// The number written in ASCII digits at the start of the bytes.
+    fn number(bytes: &[u8]) -> usize {
+        let digits = bytes
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+        return std::str::from_utf8(&bytes[..digits])
+            .unwrap()
+            .parse()
+            .unwrap();
+    }
+
+    fn sheet() -> Vec<u8> {
+        let mut character_sheet = CharacterSheet::new();
+        character_sheet.name = "Tordek (the Bold)".to_string();
+        return to_pdf(&character_sheet);
+    }
+
+    #[test]
+    fn xref_entries_point_at_objects() {
+        let pdf = sheet();
+        let startxref = find(&pdf, b"startxref\n", 0).unwrap();
+        let xref = number(&pdf[startxref + b"startxref\n".len()..]);
+        assert!(pdf[xref..].starts_with(b"xref\n0 "));
+
+        let count = number(&pdf[xref + b"xref\n0 ".len()..]);
+        let entries = find(&pdf, b"0000000000 65535 f \n", xref).unwrap() + 20;
+        for object in 1..count {
+            let entry = &pdf[entries + (object - 1) * 20..entries + object * 20];
+            assert!(entry.ends_with(b" 00000 n \n"));
+            let offset = number(entry);
+            let header = format!("{} 0 obj\n", object);
+            assert!(
+                pdf[offset..].starts_with(header.as_bytes()),
+                "object {}",
+                object
+            );
+        }
+        assert!(pdf[entries + (count - 1) * 20..].starts_with(b"trailer\n"));
+    }
+
+    #[test]
+    fn streams_have_their_lengths() {
+        let pdf = sheet();
+        let mut from = 0;
+        while let Some(start) = find(&pdf, b"/Length ", from) {
+            let length = number(&pdf[start + b"/Length ".len()..]);
+            let stream = find(&pdf, b">>\nstream\n", start).unwrap() + b">>\nstream\n".len();
+            assert!(pdf[stream + length..].starts_with(b"\nendstream"));
+            from = stream + length;
+        }
+        assert!(from > 0);
+    }
+
+    #[test]
+    fn sheet_text_is_drawn() {
+        let pdf = sheet();
+        // the labels are drawn on the page and the values in their fields' appearances
+        assert!(find(&pdf, b"(CHARACTER NAME) Tj", 0).is_some());
+        assert!(find(&pdf, b"(Tordek \\(the Bold\\)) Tj", 0).is_some());
+        assert!(find(&pdf, b"/V (Tordek \\(the Bold\\))", 0).is_some());
+    }
+}
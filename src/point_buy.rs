@@ -0,0 +1,291 @@
+use super::*;
+use character::{AbilityName, AbilityScores};
+use std::{error::Error, fmt};
+use util::EnumIter;
+
+/// The number of points a player has to spend under the standard point-buy rules.
+pub const DEFAULT_BUDGET: i8 = 27;
+
+pub const MIN_SCORE: i8 = 8;
+
+pub const MAX_SCORE: i8 = 15;
+
+/// The total cost of raising an ability from 8 to the given score, using the official cost
+/// table. Returns `None` for scores that can't be bought.
+pub fn get_point_cost(score: i8) -> Option<i8> {
+    return match score {
+        8 => Some(0),
+        9 => Some(1),
+        10 => Some(2),
+        11 => Some(3),
+        12 => Some(4),
+        13 => Some(5),
+        14 => Some(7),
+        15 => Some(9),
+        _ => None,
+    };
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum PointBuyError {
+    /// The score is outside the range point-buy allows.
+    OutOfRange(i8),
+    /// The change would cost more points than remain.
+    OverBudget { cost: i8, remaining: i8 },
+}
+
+impl fmt::Display for PointBuyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PointBuyError::OutOfRange(score) => write!(
+                f,
+                "{} is outside the point-buy range of {} to {}",
+                score, MIN_SCORE, MAX_SCORE
+            ),
+            PointBuyError::OverBudget { cost, remaining } => write!(
+                f,
+                "that costs {} points but only {} remain",
+                cost, remaining
+            ),
+        }
+    }
+}
+
+impl Error for PointBuyError {}
+
+/// Ability scores bought with a budget of points. The cost is always worked out from all six
+/// scores, so abilities can be adjusted in any order.
+///
+/// ```
+/// use dnd_character_builder::character::AbilityName;
+/// use dnd_character_builder::point_buy::PointBuy;
+///
+/// let mut point_buy = PointBuy::new();
+/// point_buy.set(AbilityName::Strength, 15).unwrap();
+/// point_buy.set(AbilityName::Dexterity, 14).unwrap();
+/// assert_eq!(point_buy.get_total_cost(), 16);
+/// assert_eq!(point_buy.get_remaining(), 11);
+///
+/// // lowering a score refunds exactly what it cost
+/// point_buy.decrease(AbilityName::Strength).unwrap();
+/// assert_eq!(point_buy.get_remaining(), 13);
+///
+/// // scores can't go past 15 or cost more than the remaining points
+/// assert!(point_buy.set(AbilityName::Wisdom, 16).is_err());
+/// point_buy.set(AbilityName::Constitution, 15).unwrap();
+/// assert!(point_buy.set(AbilityName::Intelligence, 15).is_err());
+/// ```
+#[derive(Clone, Debug)]
+pub struct PointBuy {
+    scores: AbilityScores,
+    budget: i8,
+}
+
+impl Default for PointBuy {
+    fn default() -> Self {
+        return PointBuy::new();
+    }
+}
+
+impl PointBuy {
+    /// Start with every score at 8 and the standard budget of 27 points.
+    pub fn new() -> PointBuy {
+        return PointBuy::with_budget(DEFAULT_BUDGET);
+    }
+
+    /// Start with every score at 8 and a custom budget, for tables that use a different one.
+    pub fn with_budget(budget: i8) -> PointBuy {
+        return PointBuy {
+            scores: AbilityScores::default(),
+            budget,
+        };
+    }
+
+    /// Pick up from scores bought earlier, e.g. when revisiting the page. Returns `None` if the
+    /// scores couldn't have been bought with the budget.
+    pub fn from_scores(scores: &AbilityScores, budget: i8) -> Option<PointBuy> {
+        let mut point_buy = PointBuy::with_budget(budget);
+        for ability in AbilityName::iter() {
+            point_buy.set(ability, scores.get(ability)).ok()?;
+        }
+        return Some(point_buy);
+    }
+
+    pub fn get_scores(&self) -> &AbilityScores {
+        return &self.scores;
+    }
+
+    pub fn get_budget(&self) -> i8 {
+        return self.budget;
+    }
+
+    pub fn get_total_cost(&self) -> i8 {
+        return AbilityName::iter()
+            .into_iter()
+            .map(|ability| get_point_cost(self.scores.get(ability)).unwrap_or(0))
+            .sum();
+    }
+
+    pub fn get_remaining(&self) -> i8 {
+        return self.budget - self.get_total_cost();
+    }
+
+    /// Set an ability to a score, as long as it's in range and the new total fits the budget.
+    pub fn set(&mut self, ability: AbilityName, score: i8) -> Result<(), PointBuyError> {
+        let cost = get_point_cost(score).ok_or(PointBuyError::OutOfRange(score))?;
+        let current = get_point_cost(self.scores.get(ability)).unwrap_or(0);
+        let remaining = self.get_remaining();
+        if cost - current > remaining {
+            return Err(PointBuyError::OverBudget {
+                cost: cost - current,
+                remaining,
+            });
+        }
+        self.scores.set(ability, score);
+        return Ok(());
+    }
+
+    pub fn increase(&mut self, ability: AbilityName) -> Result<(), PointBuyError> {
+        return self.set(ability, self.scores.get(ability) + 1);
+    }
+
+    pub fn decrease(&mut self, ability: AbilityName) -> Result<(), PointBuyError> {
+        return self.set(ability, self.scores.get(ability) - 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use character::STANDARD_ARRAY;
+
+    fn scores(values: [i8; 6]) -> AbilityScores {
+        let mut scores = AbilityScores::default();
+        for (ability, score) in AbilityName::iter().into_iter().zip(values) {
+            scores.set(ability, score);
+        }
+        return scores;
+    }
+
+    #[test]
+    fn cost_table() {
+        let costs = (8..=15).map(get_point_cost).collect::<Vec<_>>();
+        let expected = [0, 1, 2, 3, 4, 5, 7, 9].map(Some);
+        assert_eq!(costs, expected);
+        assert_eq!(get_point_cost(7), None);
+        assert_eq!(get_point_cost(16), None);
+    }
+
+    #[test]
+    fn starts_at_eight_with_full_budget() {
+        let point_buy = PointBuy::new();
+        for ability in AbilityName::iter() {
+            assert_eq!(point_buy.get_scores().get(ability), MIN_SCORE);
+        }
+        assert_eq!(point_buy.get_total_cost(), 0);
+        assert_eq!(point_buy.get_remaining(), DEFAULT_BUDGET);
+    }
+
+    #[test]
+    fn rejects_scores_out_of_range() {
+        let mut point_buy = PointBuy::new();
+        assert_eq!(
+            point_buy.decrease(AbilityName::Strength),
+            Err(PointBuyError::OutOfRange(7))
+        );
+        point_buy.set(AbilityName::Strength, MAX_SCORE).unwrap();
+        assert_eq!(
+            point_buy.increase(AbilityName::Strength),
+            Err(PointBuyError::OutOfRange(16))
+        );
+        assert_eq!(point_buy.get_scores().get(AbilityName::Strength), 15);
+        assert_eq!(point_buy.get_remaining(), 18);
+    }
+
+    #[test]
+    fn rejects_overspending() {
+        let mut point_buy = PointBuy::new();
+        point_buy.set(AbilityName::Strength, 15).unwrap();
+        point_buy.set(AbilityName::Dexterity, 15).unwrap();
+        point_buy.set(AbilityName::Constitution, 14).unwrap();
+        assert_eq!(point_buy.get_remaining(), 2);
+        assert_eq!(
+            point_buy.set(AbilityName::Wisdom, 12),
+            Err(PointBuyError::OverBudget {
+                cost: 4,
+                remaining: 2
+            })
+        );
+        assert_eq!(point_buy.get_scores().get(AbilityName::Wisdom), 8);
+        point_buy.set(AbilityName::Wisdom, 10).unwrap();
+        assert_eq!(point_buy.get_remaining(), 0);
+        assert!(point_buy.increase(AbilityName::Charisma).is_err());
+    }
+
+    #[test]
+    fn adjusts_in_any_order() {
+        let targets = [
+            (AbilityName::Strength, 15),
+            (AbilityName::Dexterity, 14),
+            (AbilityName::Constitution, 13),
+            (AbilityName::Intelligence, 12),
+            (AbilityName::Wisdom, 10),
+            (AbilityName::Charisma, 8),
+        ];
+
+        // one step at a time, going round the abilities
+        let mut stepped = PointBuy::new();
+        loop {
+            let mut changed = false;
+            for (ability, target) in targets.iter().rev() {
+                if stepped.get_scores().get(*ability) < *target {
+                    stepped.increase(*ability).unwrap();
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        // overshooting some abilities first and lowering them back afterwards
+        let mut adjusted = PointBuy::new();
+        adjusted.set(AbilityName::Wisdom, 15).unwrap();
+        adjusted.set(AbilityName::Charisma, 13).unwrap();
+        adjusted.set(AbilityName::Wisdom, 10).unwrap();
+        adjusted.set(AbilityName::Charisma, 8).unwrap();
+        for (ability, target) in targets {
+            adjusted.set(ability, target).unwrap();
+        }
+
+        for point_buy in [stepped, adjusted] {
+            for (ability, target) in targets {
+                assert_eq!(point_buy.get_scores().get(ability), target);
+            }
+            assert_eq!(point_buy.get_total_cost(), 9 + 7 + 5 + 4 + 2);
+            assert_eq!(point_buy.get_remaining(), 0);
+        }
+    }
+
+    #[test]
+    fn from_scores_accepts_legal_arrays() {
+        let point_buy = PointBuy::from_scores(&scores(STANDARD_ARRAY), DEFAULT_BUDGET).unwrap();
+        assert_eq!(point_buy.get_remaining(), 0);
+        let point_buy = PointBuy::from_scores(&scores([8; 6]), DEFAULT_BUDGET).unwrap();
+        assert_eq!(point_buy.get_remaining(), 27);
+        let point_buy =
+            PointBuy::from_scores(&scores([15, 15, 15, 8, 8, 8]), DEFAULT_BUDGET).unwrap();
+        assert_eq!(point_buy.get_remaining(), 0);
+    }
+
+    #[test]
+    fn from_scores_rejects_illegal_arrays() {
+        // over budget
+        assert!(PointBuy::from_scores(&scores([15, 15, 15, 9, 8, 8]), DEFAULT_BUDGET).is_none());
+        // out of range
+        assert!(PointBuy::from_scores(&scores([16, 8, 8, 8, 8, 8]), DEFAULT_BUDGET).is_none());
+        assert!(PointBuy::from_scores(&scores([7, 10, 8, 8, 8, 8]), DEFAULT_BUDGET).is_none());
+        // legal with the standard budget but not a smaller one
+        assert!(PointBuy::from_scores(&scores(STANDARD_ARRAY), 20).is_none());
+    }
+}
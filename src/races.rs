@@ -2,10 +2,13 @@ use super::*;
 use character::*;
 use enum_iterator::{all, Sequence};
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, error::Error, io};
 use util::{EnumIter, EnumString};
 
-#[derive(Copy, Clone, Debug, Sequence, EnumIter, EnumString)]
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Sequence, EnumIter, EnumString, Serialize, Deserialize,
+)]
 pub enum AvailableRaces {
     Dragonborn,
     Dwarf,
@@ -54,13 +57,129 @@ impl AvailableRaces {
                 vec![(AbilityName::Strength, 2), (AbilityName::Constitution, 1)]
             }
             AvailableRaces::Halfling => vec![(AbilityName::Dexterity, 2)],
-            AvailableRaces::Human => vec![(AbilityName::ANY, 1), (AbilityName::ANY, 1)],
+            // standard and variant humans get different increases, so they come from the subrace
+            AvailableRaces::Human => vec![],
             AvailableRaces::Tiefling => {
                 vec![(AbilityName::Intelligence, 1), (AbilityName::Charisma, 2)]
             }
         }
     }
 
+    pub fn get_skill_proficiencies(&self) -> Vec<Skill> {
+        match *self {
+            AvailableRaces::Elf => vec![Skill::Perception],
+            AvailableRaces::HalfOrc => vec![Skill::Intimidation],
+            _ => vec![],
+        }
+    }
+
+    /// The subraces a player chooses between after picking the race, if it has any.
+    pub fn get_subraces(&self) -> Vec<AvailableSubraces> {
+        match *self {
+            AvailableRaces::Dwarf => vec![
+                AvailableSubraces::HillDwarf,
+                AvailableSubraces::MountainDwarf,
+            ],
+            AvailableRaces::Elf => vec![
+                AvailableSubraces::HighElf,
+                AvailableSubraces::WoodElf,
+                AvailableSubraces::DarkElf,
+            ],
+            AvailableRaces::Gnome => {
+                vec![AvailableSubraces::ForestGnome, AvailableSubraces::RockGnome]
+            }
+            AvailableRaces::Halfling => vec![
+                AvailableSubraces::LightfootHalfling,
+                AvailableSubraces::StoutHalfling,
+            ],
+            AvailableRaces::Human => vec![
+                AvailableSubraces::StandardHuman,
+                AvailableSubraces::VariantHuman,
+            ],
+            _ => vec![],
+        }
+    }
+
+    /// The number of skill proficiencies of the player's choice granted by the race.
+    pub fn get_skill_choice_count(&self) -> usize {
+        match *self {
+            AvailableRaces::HalfElf => 2,
+            _ => 0,
+        }
+    }
+
+    /// Example names for characters of the race, from the Player's Handbook.
+    pub fn get_names(&self) -> Vec<&'static str> {
+        match *self {
+            AvailableRaces::Dragonborn => vec![
+                "Arjhan", "Balasar", "Bharash", "Donaar", "Ghesh", "Heskan", "Akra", "Biri",
+                "Daar", "Farideh", "Harann", "Kava",
+            ],
+            AvailableRaces::Dwarf => vec![
+                "Adrik", "Baern", "Bruenor", "Dain", "Eberk", "Thorin", "Amber", "Bardryn",
+                "Dagnal", "Eldeth", "Gunnloda", "Vistra",
+            ],
+            AvailableRaces::Elf => vec![
+                "Adran",
+                "Aelar",
+                "Berrian",
+                "Erevan",
+                "Galinndan",
+                "Thamior",
+                "Adrie",
+                "Birel",
+                "Enna",
+                "Keyleth",
+                "Naivara",
+                "Shava",
+            ],
+            AvailableRaces::Gnome => vec![
+                "Alston",
+                "Boddynock",
+                "Dimble",
+                "Fonkin",
+                "Orryn",
+                "Zook",
+                "Bimpnottin",
+                "Carlin",
+                "Ellyjobell",
+                "Nissa",
+                "Orla",
+                "Zanna",
+            ],
+            AvailableRaces::HalfElf => vec![
+                "Aelar",
+                "Erevan",
+                "Ivellios",
+                "Bram",
+                "Randal",
+                "Tharivol",
+                "Adrie",
+                "Enna",
+                "Jelenneth",
+                "Kethra",
+                "Sariel",
+                "Valanthe",
+            ],
+            AvailableRaces::HalfOrc => vec![
+                "Dench", "Feng", "Gell", "Henk", "Holg", "Imsh", "Baggi", "Emen", "Engong", "Myev",
+                "Ovak", "Volen",
+            ],
+            AvailableRaces::Halfling => vec![
+                "Alton", "Cade", "Eldon", "Garret", "Milo", "Roscoe", "Andry", "Cora", "Kithri",
+                "Lavinia", "Merla", "Verna",
+            ],
+            AvailableRaces::Human => vec![
+                "Bram", "Darvin", "Geth", "Marcon", "Randal", "Stedd", "Arveene", "Esvele",
+                "Jhessail", "Kethra", "Rowan", "Tessele",
+            ],
+            AvailableRaces::Tiefling => vec![
+                "Akmenos", "Barakas", "Damakos", "Ekemon", "Leucis", "Skamos", "Akta", "Bryseis",
+                "Kallista", "Nemeia", "Orianna", "Rieta",
+            ],
+        }
+    }
+
     fn from<T: Into<String>>(string: T) -> AvailableRaces {
         let string = string.into();
         for race in AvailableRaces::iter() {
@@ -72,3 +191,178 @@ impl AvailableRaces {
         return AvailableRaces::Human;
     }
 }
+
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Sequence, EnumIter, EnumString, Serialize, Deserialize,
+)]
+pub enum AvailableSubraces {
+    HillDwarf,
+    MountainDwarf,
+    HighElf,
+    WoodElf,
+    DarkElf,
+    ForestGnome,
+    RockGnome,
+    LightfootHalfling,
+    StoutHalfling,
+    StandardHuman,
+    VariantHuman,
+}
+
+impl AvailableSubraces {
+    pub fn get_race(&self) -> AvailableRaces {
+        return AvailableRaces::iter()
+            .into_iter()
+            .find(|race| race.get_subraces().contains(self))
+            .unwrap();
+    }
+
+    /// The subrace's ability score increases, on top of those of the race.
+    pub fn get_ability_score_increases(&self) -> Vec<(AbilityName, i8)> {
+        match *self {
+            AvailableSubraces::HillDwarf => vec![(AbilityName::Wisdom, 1)],
+            AvailableSubraces::MountainDwarf => vec![(AbilityName::Strength, 2)],
+            AvailableSubraces::HighElf => vec![(AbilityName::Intelligence, 1)],
+            AvailableSubraces::WoodElf => vec![(AbilityName::Wisdom, 1)],
+            AvailableSubraces::DarkElf => vec![(AbilityName::Charisma, 1)],
+            AvailableSubraces::ForestGnome => vec![(AbilityName::Dexterity, 1)],
+            AvailableSubraces::RockGnome => vec![(AbilityName::Constitution, 1)],
+            AvailableSubraces::LightfootHalfling => vec![(AbilityName::Charisma, 1)],
+            AvailableSubraces::StoutHalfling => vec![(AbilityName::Constitution, 1)],
+            AvailableSubraces::StandardHuman => AbilityName::iter()
+                .into_iter()
+                .map(|ability| (ability, 1))
+                .collect(),
+            AvailableSubraces::VariantHuman => vec![(AbilityName::ANY, 1), (AbilityName::ANY, 1)],
+        }
+    }
+
+    pub fn get_traits(&self) -> Vec<Feature> {
+        match *self {
+            AvailableSubraces::HillDwarf => vec![Feature::new(
+                "Dwarven Toughness",
+                "Your hit point maximum increases by 1, and it increases by 1 every time you gain a level.",
+            )],
+            AvailableSubraces::MountainDwarf => vec![Feature::new(
+                "Dwarven Armor Training",
+                "You have proficiency with light and medium armor.",
+            )],
+            AvailableSubraces::HighElf => vec![
+                Feature::new(
+                    "Elf Weapon Training",
+                    "You have proficiency with the longsword, shortsword, shortbow and longbow.",
+                ),
+                Feature::new(
+                    "Cantrip",
+                    "You know one cantrip of your choice from the wizard spell list, using Intelligence to cast it.",
+                ),
+                Feature::new(
+                    "Extra Language",
+                    "You can speak, read and write one extra language of your choice.",
+                ),
+            ],
+            AvailableSubraces::WoodElf => vec![
+                Feature::new(
+                    "Elf Weapon Training",
+                    "You have proficiency with the longsword, shortsword, shortbow and longbow.",
+                ),
+                Feature::new("Fleet of Foot", "Your base walking speed increases to 35 feet."),
+                Feature::new(
+                    "Mask of the Wild",
+                    "You can attempt to hide even when you are only lightly obscured by natural phenomena.",
+                ),
+            ],
+            AvailableSubraces::DarkElf => vec![
+                Feature::new("Superior Darkvision", "Your darkvision has a radius of 120 feet."),
+                Feature::new(
+                    "Sunlight Sensitivity",
+                    "You have disadvantage on attack rolls and sight-based Perception checks in direct sunlight.",
+                ),
+                Feature::new(
+                    "Drow Magic",
+                    "You know the dancing lights cantrip, and learn faerie fire at 3rd level and darkness at 5th level.",
+                ),
+                Feature::new(
+                    "Drow Weapon Training",
+                    "You have proficiency with rapiers, shortswords and hand crossbows.",
+                ),
+            ],
+            AvailableSubraces::ForestGnome => vec![
+                Feature::new(
+                    "Natural Illusionist",
+                    "You know the minor illusion cantrip, using Intelligence to cast it.",
+                ),
+                Feature::new(
+                    "Speak with Small Beasts",
+                    "You can communicate simple ideas with Small or smaller beasts.",
+                ),
+            ],
+            AvailableSubraces::RockGnome => vec![
+                Feature::new(
+                    "Artificer's Lore",
+                    "Add twice your proficiency bonus to History checks about magic items, alchemical objects or technological devices.",
+                ),
+                Feature::new(
+                    "Tinker",
+                    "Using tinker's tools, you can construct tiny clockwork devices such as toys, fire starters and music boxes.",
+                ),
+            ],
+            AvailableSubraces::LightfootHalfling => vec![Feature::new(
+                "Naturally Stealthy",
+                "You can attempt to hide even when you are obscured only by a creature at least one size larger than you.",
+            )],
+            AvailableSubraces::StoutHalfling => vec![Feature::new(
+                "Stout Resilience",
+                "You have advantage on saving throws against poison, and resistance against poison damage.",
+            )],
+            AvailableSubraces::StandardHuman => vec![],
+            AvailableSubraces::VariantHuman => vec![
+                Feature::new("Skills", "You gain proficiency in one skill of your choice."),
+                Feature::new("Feat", "You gain one feat of your choice."),
+            ],
+        }
+    }
+
+    pub fn get_armor_proficiencies(&self) -> Vec<ArmorCategory> {
+        match *self {
+            AvailableSubraces::MountainDwarf => vec![ArmorCategory::Light, ArmorCategory::Medium],
+            _ => vec![],
+        }
+    }
+
+    pub fn get_tool_proficiencies(&self) -> Vec<Tool> {
+        match *self {
+            AvailableSubraces::RockGnome => vec![Tool::TinkersTools],
+            _ => vec![],
+        }
+    }
+
+    /// The number of extra languages of the player's choice granted by the subrace.
+    pub fn get_language_count(&self) -> usize {
+        match *self {
+            AvailableSubraces::HighElf => 1,
+            _ => 0,
+        }
+    }
+
+    /// The number of skill proficiencies of the player's choice granted by the subrace.
+    pub fn get_skill_choice_count(&self) -> usize {
+        match *self {
+            AvailableSubraces::VariantHuman => 1,
+            _ => 0,
+        }
+    }
+
+    /// Whether the subrace grants a feat at 1st level.
+    pub fn grants_feat(&self) -> bool {
+        return *self == AvailableSubraces::VariantHuman;
+    }
+
+    /// Extra hit points gained at every level.
+    pub fn get_hit_points_per_level(&self) -> i8 {
+        match *self {
+            AvailableSubraces::HillDwarf => 1,
+            _ => 0,
+        }
+    }
+}
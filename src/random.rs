@@ -0,0 +1,300 @@
+use super::*;
+use answers::Answers;
+use backgrounds::AvailableBackgrounds;
+use character::{
+    AbilityName, AbilityScoreMethod, AbilityScores, Alignment, ArmorCategory, Language, Skill,
+    Tool, STANDARD_ARRAY,
+};
+use classes::AvailableClasses;
+use equipment::{Item, StartingItem};
+use races::AvailableRaces;
+use rand::{seq::SliceRandom, Rng};
+use spells::{get_class_spells, Level};
+use std::{error::Error, fmt};
+use util::EnumIter;
+
+/// Limits on what the random generator may pick. An empty list allows anything.
+#[derive(Clone, Debug, Default)]
+pub struct Constraints {
+    pub races: Vec<AvailableRaces>,
+    pub classes: Vec<AvailableClasses>,
+    pub backgrounds: Vec<AvailableBackgrounds>,
+    pub alignments: Vec<Alignment>,
+    pub ability_score_methods: Vec<AbilityScoreMethod>,
+}
+
+/// The constraints rule out every option for a choice.
+#[derive(Debug, PartialEq, Eq)]
+pub struct NoOptions(pub &'static str);
+
+impl fmt::Display for NoOptions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the constraints don't allow any {}", self.0)
+    }
+}
+
+impl Error for NoOptions {}
+
+/// Pick one of the allowed options, or any option when none are listed.
+fn pick<T: EnumIter + Copy, R: Rng + ?Sized>(
+    allowed: &[T],
+    kind: &'static str,
+    rng: &mut R,
+) -> Result<T, NoOptions> {
+    let options = match allowed.is_empty() {
+        true => T::iter(),
+        false => allowed.to_vec(),
+    };
+    return options.choose(rng).copied().ok_or(NoOptions(kind));
+}
+
+fn shuffled<T: EnumIter, R: Rng + ?Sized>(rng: &mut R) -> Vec<T> {
+    let mut options = T::iter();
+    options.shuffle(rng);
+    return options;
+}
+
+/// Make up answers for everything the pinned answers leave out, so the wizard builds a complete
+/// character without asking anything. Scores are assigned highest first to the class's primary
+/// abilities, and the character starts with the best armor their class can wear.
+///
+/// ```
+/// use dnd_character_builder::answers::Answers;
+/// use dnd_character_builder::character::{AbilityName, Alignment};
+/// use dnd_character_builder::controller::App;
+/// use dnd_character_builder::dice::seeded_rng;
+/// use dnd_character_builder::races::AvailableRaces;
+/// use dnd_character_builder::random::{random_answers, Constraints};
+/// use util::EnumIter;
+///
+/// // a random elf of any class who isn't evil
+/// let pinned = Answers {
+///     race: Some(AvailableRaces::Elf),
+///     ..Answers::default()
+/// };
+/// let constraints = Constraints {
+///     alignments: Alignment::iter().into_iter().filter(|a| !a.is_evil()).collect(),
+///     ..Constraints::default()
+/// };
+/// let answers = random_answers(pinned, &constraints, &mut seeded_rng(1)).unwrap();
+///
+/// let mut app = App::new();
+/// app.set_answers(answers);
+/// app.set_interactive(false);
+/// app.run();
+///
+/// let character_sheet = app.character_sheet();
+/// assert_eq!(character_sheet.race, AvailableRaces::Elf);
+/// assert!(!character_sheet.alignment.is_evil());
+/// let class = character_sheet.get_class();
+/// let primary = class.get_primary_abilities()[0];
+/// let scores = &character_sheet.base_ability_scores;
+/// assert!(AbilityName::iter().iter().all(|a| scores.get(*a) <= scores.get(primary)));
+/// ```
+pub fn random_answers<R: Rng + ?Sized>(
+    pinned: Answers,
+    constraints: &Constraints,
+    rng: &mut R,
+) -> Result<Answers, NoOptions> {
+    let race = match (pinned.race, pinned.subrace) {
+        (Some(race), _) => race,
+        (None, Some(subrace)) => subrace.get_race(),
+        (None, None) => pick(&constraints.races, "race", rng)?,
+    };
+    if let Some(subrace) = pinned.subrace {
+        let allowed = constraints.races.is_empty() || constraints.races.contains(&race);
+        if subrace.get_race() != race || !allowed {
+            return Err(NoOptions("race"));
+        }
+    }
+    let class = match (pinned.class, pinned.subclass) {
+        (Some(class), _) => class,
+        (None, Some(subclass)) => subclass.get_class(),
+        (None, None) => pick(&constraints.classes, "class", rng)?,
+    };
+    let background = match pinned.background {
+        Some(background) => background,
+        None => pick(&constraints.backgrounds, "background", rng)?,
+    };
+    // scores rolled in order can't go to the class's primary abilities, so they're only used
+    // when asked for
+    let methods = match constraints.ability_score_methods.is_empty() {
+        true => AbilityScoreMethod::iter()
+            .into_iter()
+            .filter(|method| !method.is_in_order())
+            .collect(),
+        false => constraints.ability_score_methods.clone(),
+    };
+    let method = match pinned.ability_score_method {
+        Some(method) => method,
+        None => pick(&methods, "ability score method", rng)?,
+    };
+
+    // the class's primary abilities get the highest scores, the rest are shuffled
+    let primary = class.get_primary_abilities();
+    let mut rest = AbilityName::iter()
+        .into_iter()
+        .filter(|ability| !primary.contains(ability))
+        .collect::<Vec<AbilityName>>();
+    rest.shuffle(rng);
+    let order = primary
+        .into_iter()
+        .chain(rest)
+        .collect::<Vec<AbilityName>>();
+
+    // point buy has no rolls to assign, so buy the standard array, which costs the full budget
+    let ability_scores = match method {
+        AbilityScoreMethod::PointBuy => {
+            let mut ability_scores = AbilityScores::default();
+            for (ability, score) in order.iter().zip(STANDARD_ARRAY) {
+                ability_scores.set(*ability, score);
+            }
+            Some(ability_scores)
+        }
+        _ => None,
+    };
+
+    let mut equipment = Vec::new();
+    let mut items = Vec::new();
+    let mut starting_items = Vec::new();
+    for choice in class.get_starting_equipment() {
+        let index = rng.gen_range(0..choice.len());
+        if choice.len() > 1 {
+            equipment.push((b'a' + index as u8) as char);
+        }
+        starting_items.extend(choice.into_iter().nth(index).unwrap());
+    }
+    starting_items.extend(background.get_equipment());
+    let mut armor = Vec::new();
+    for item in starting_items {
+        match item {
+            StartingItem::Item(Item::Armor(item), _) => armor.push(item),
+            StartingItem::Item(_, _) => {}
+            StartingItem::Any(group, quantity) => {
+                for _ in 0..quantity {
+                    if let Some(item) = group.get_items().choose(rng) {
+                        items.push(item.to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    // wear the armor with the best base armor class the class is proficient with
+    let proficiencies = class.get_armor_proficiencies();
+    let armor = armor
+        .into_iter()
+        .filter(|armor| proficiencies.contains(&armor.get_category()))
+        .max_by_key(|armor| armor.get_base_armor_class());
+
+    let mut spells = get_class_spells(class, Level::Level9)
+        .into_iter()
+        .map(|spell| spell.name)
+        .collect::<Vec<String>>();
+    spells.shuffle(rng);
+
+    let name = race.get_names().choose(rng).unwrap().to_string();
+    let subrace = race.get_subraces().choose(rng).copied();
+    let subclass = class.get_subclasses().choose(rng).copied();
+    let random = Answers {
+        seed: None,
+        name: Some(name),
+        race: Some(race),
+        subrace,
+        racial_ability_increases: order.clone(),
+        alignment: Some(pick(&constraints.alignments, "alignment", rng)?),
+        class: Some(class),
+        subclass,
+        ability_score_method: Some(method),
+        ability_scores,
+        ability_score_order: match method {
+            _ if pinned.ability_scores.is_some() => Vec::new(),
+            AbilityScoreMethod::PointBuy => Vec::new(),
+            _ => order.clone(),
+        },
+        background: Some(background),
+        tools: shuffled::<Tool, R>(rng),
+        languages: shuffled::<Language, R>(rng),
+        skills: shuffled::<Skill, R>(rng),
+        expertise: shuffled::<Skill, R>(rng),
+        cantrips: spells.clone(),
+        spells,
+        feat: Some(None),
+        feat_ability: Some(order[0]),
+        starting_gold: Some(false),
+        equipment,
+        items,
+        armor: Some(armor),
+        shield: Some(proficiencies.contains(&ArmorCategory::Shields)),
+        ..Answers::default()
+    };
+    return Ok(pinned.or(random));
+}
+
+/// Alignments allowed after ruling some out, where "good", "evil", "lawful" and "chaotic" rule
+/// out every alignment of that kind.
+pub fn exclude_alignments(excluded: &[&str]) -> Result<Vec<Alignment>, answers::InvalidChoice> {
+    let mut alignments = Alignment::iter();
+    for name in excluded {
+        let matches: fn(&Alignment) -> bool = match answers::normalize(name).as_str() {
+            "good" => Alignment::is_good,
+            "evil" => Alignment::is_evil,
+            "lawful" => Alignment::is_lawful,
+            "chaotic" => Alignment::is_chaotic,
+            _ => {
+                let excluded = answers::parse_choice::<Alignment>("alignment", name)?;
+                alignments.retain(|alignment| *alignment != excluded);
+                continue;
+            }
+        };
+        alignments.retain(|alignment| !matches(alignment));
+    }
+    return Ok(alignments);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dice::seeded_rng;
+    use races::AvailableSubraces;
+
+    fn pinned_subrace(subrace: AvailableSubraces) -> Answers {
+        return Answers {
+            subrace: Some(subrace),
+            ..Answers::default()
+        };
+    }
+
+    #[test]
+    fn race_follows_pinned_subrace() {
+        for seed in 0..10 {
+            let pinned = pinned_subrace(AvailableSubraces::HillDwarf);
+            let answers = random_answers(pinned, &Constraints::default(), &mut seeded_rng(seed));
+            let answers = answers.unwrap();
+            assert_eq!(answers.race, Some(AvailableRaces::Dwarf));
+            assert_eq!(answers.subrace, Some(AvailableSubraces::HillDwarf));
+        }
+    }
+
+    #[test]
+    fn pinned_subrace_must_be_allowed() {
+        let constraints = Constraints {
+            races: vec![AvailableRaces::Elf, AvailableRaces::Halfling],
+            ..Constraints::default()
+        };
+        let pinned = pinned_subrace(AvailableSubraces::HillDwarf);
+        assert_eq!(
+            random_answers(pinned, &constraints, &mut seeded_rng(1)).unwrap_err(),
+            NoOptions("race")
+        );
+
+        let pinned = Answers {
+            race: Some(AvailableRaces::Elf),
+            ..pinned_subrace(AvailableSubraces::HillDwarf)
+        };
+        assert_eq!(
+            random_answers(pinned, &Constraints::default(), &mut seeded_rng(1)).unwrap_err(),
+            NoOptions("race")
+        );
+    }
+}
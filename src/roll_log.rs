@@ -0,0 +1,479 @@
+use super::*;
+use character::{AbilityName, AbilityScoreMethod, AbilityScores, STANDARD_ARRAY};
+use dice::{DiceRoll, DieRoll};
+use point_buy::{PointBuy, DEFAULT_BUDGET};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{fmt, time::SystemTime};
+use util::EnumIter;
+
+/// The purpose recorded for each ability score roll.
+pub const ABILITY_SCORE_PURPOSE: &str = "Ability score";
+
+/// Something that happened while generating a character that the DM may want to check.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RollEvent {
+    /// The player chose how to generate their ability scores, starting a new set of scores.
+    Method(AbilityScoreMethod),
+    Roll {
+        purpose: String,
+        expression: String,
+        dice: Vec<DieRoll>,
+        total: i32,
+    },
+    /// The player assigned a score to an ability.
+    Assign { ability: AbilityName, score: i8 },
+}
+
+impl RollEvent {
+    pub fn roll(purpose: &str, roll: &DiceRoll) -> RollEvent {
+        return RollEvent::Roll {
+            purpose: purpose.to_string(),
+            expression: roll.expression.to_string(),
+            dice: roll.dice.clone(),
+            total: roll.total,
+        };
+    }
+}
+
+impl fmt::Display for RollEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RollEvent::Method(method) => write!(f, "Method: {}", method.get_description()),
+            RollEvent::Roll {
+                purpose,
+                expression,
+                dice,
+                total,
+            } => {
+                let dice = dice
+                    .iter()
+                    .map(|die| match die.kept {
+                        true => die.value.to_string(),
+                        false => format!("({})", die.value),
+                    })
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                write!(f, "{}: {} [{}] = {}", purpose, expression, dice, total)
+            }
+            RollEvent::Assign { ability, score } => write!(f, "Assign {} to {}", score, ability),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RollLogEntry {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub event: RollEvent,
+    /// The hash of the entry before this one, or an empty string for the first entry.
+    pub previous_hash: String,
+    /// A SHA-256 hash of this entry's contents and the previous hash.
+    pub hash: String,
+}
+
+impl RollLogEntry {
+    fn compute_hash(&self) -> String {
+        let contents = serde_json::to_string(&(&self.previous_hash, self.timestamp, &self.event))
+            .expect("roll log entries always serialize");
+        return format!("{:x}", Sha256::digest(contents.as_bytes()));
+    }
+}
+
+impl fmt::Display for RollLogEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", format_timestamp(self.timestamp), self.event)
+    }
+}
+
+/// Format seconds since the Unix epoch as a UTC date and time, e.g. "2023-04-01 12:30:00 UTC".
+fn format_timestamp(timestamp: u64) -> String {
+    let days = (timestamp / 86400) as i64;
+    let seconds = timestamp % 86400;
+
+    // convert days since the epoch to a civil date (Howard Hinnant's days_from_civil, reversed)
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    return format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    );
+}
+
+/// A problem found when checking a roll log against its character.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RollLogIssue {
+    /// The entry doesn't follow on from the entry before it, so entries were removed, added or
+    /// reordered.
+    BrokenChain(usize),
+    /// The entry's contents no longer match its hash, so it was edited.
+    Tampered(usize),
+    NoAbilityScores,
+    /// The ability score dice were rolled more than once.
+    Rerolled(usize),
+    /// The scores assigned with point buy couldn't have been bought with the budget.
+    NotBought,
+    /// A score was assigned that the dice didn't produce.
+    NotRolled {
+        ability: AbilityName,
+        score: i8,
+    },
+    /// The character's score differs from the one assigned in the log.
+    Edited {
+        ability: AbilityName,
+        logged: Option<i8>,
+        actual: i8,
+    },
+}
+
+impl fmt::Display for RollLogIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RollLogIssue::BrokenChain(index) => write!(
+                f,
+                "entry {} doesn't follow the entry before it; entries were added, removed or reordered",
+                index + 1
+            ),
+            RollLogIssue::Tampered(index) => {
+                write!(f, "entry {} was changed after it was logged", index + 1)
+            }
+            RollLogIssue::NoAbilityScores => write!(f, "no ability score rolls were logged"),
+            RollLogIssue::Rerolled(count) => write!(
+                f,
+                "ability scores were rolled again {} time(s) after the first",
+                count
+            ),
+            RollLogIssue::NotBought => write!(
+                f,
+                "the point buy scores cost more than the {} point budget",
+                DEFAULT_BUDGET
+            ),
+            RollLogIssue::NotRolled { ability, score } => {
+                write!(f, "{} of {} wasn't produced by the logged rolls", ability, score)
+            }
+            RollLogIssue::Edited {
+                ability,
+                logged: Some(logged),
+                actual,
+            } => write!(
+                f,
+                "{} is {} but {} was assigned in the log",
+                ability, actual, logged
+            ),
+            RollLogIssue::Edited {
+                ability,
+                logged: None,
+                actual,
+            } => write!(f, "{} is {} but was never assigned in the log", ability, actual),
+        }
+    }
+}
+
+/// An append-only record of every roll made while creating a character. Each entry includes the
+/// hash of the one before it, so editing, removing or reordering entries breaks the chain. A
+/// determined cheat could recompute every hash, so the DM can also note the latest hash at the
+/// table and compare it later.
+///
+/// ```
+/// use dnd_character_builder::character::{
+///     AbilityName, AbilityScoreMethod, AbilityScores, STANDARD_ARRAY,
+/// };
+/// use dnd_character_builder::roll_log::{RollEvent, RollLog, RollLogIssue};
+/// use util::EnumIter;
+///
+/// let mut log = RollLog::default();
+/// let mut scores = AbilityScores::default();
+/// log.record(RollEvent::Method(AbilityScoreMethod::StandardArray));
+/// for (ability, score) in AbilityName::iter().into_iter().zip(STANDARD_ARRAY) {
+///     log.record(RollEvent::Assign { ability, score });
+///     scores.set(ability, score);
+/// }
+/// assert!(log.verify(&scores).is_empty());
+///
+/// // bumping a score on the sheet is caught
+/// scores.set(AbilityName::Strength, 17);
+/// assert!(matches!(log.verify(&scores)[..], [RollLogIssue::Edited { .. }]));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RollLog {
+    entries: Vec<RollLogEntry>,
+}
+
+impl RollLog {
+    pub fn get_entries(&self) -> &Vec<RollLogEntry> {
+        return &self.entries;
+    }
+
+    /// The hash of the latest entry, which vouches for the whole log.
+    pub fn get_head_hash(&self) -> Option<&str> {
+        return self.entries.last().map(|entry| entry.hash.as_str());
+    }
+
+    pub fn record(&mut self, event: RollEvent) {
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+        let previous_hash = match self.entries.last() {
+            Some(entry) => entry.hash.clone(),
+            None => String::new(),
+        };
+        let mut entry = RollLogEntry {
+            timestamp,
+            event,
+            previous_hash,
+            hash: String::new(),
+        };
+        entry.hash = entry.compute_hash();
+        self.entries.push(entry);
+    }
+
+    /// Check that the log hasn't been tampered with and that the given ability scores are the
+    /// ones it records. Returns every issue found, so an empty list means the scores check out.
+    pub fn verify(&self, base_ability_scores: &AbilityScores) -> Vec<RollLogIssue> {
+        let mut issues = Vec::new();
+
+        let mut previous_hash = String::new();
+        for (index, entry) in self.entries.iter().enumerate() {
+            if entry.previous_hash != previous_hash {
+                issues.push(RollLogIssue::BrokenChain(index));
+            }
+            if entry.hash != entry.compute_hash() {
+                issues.push(RollLogIssue::Tampered(index));
+            }
+            previous_hash = entry.hash.clone();
+        }
+
+        // only the most recent set of ability scores counts, and choosing a method again is only
+        // a reroll if dice were rolled for more than one of them
+        let events = self.entries.iter().map(|entry| &entry.event);
+        let mut rolled_sessions = 0;
+        let mut rolled = false;
+        for event in events.clone() {
+            match event {
+                RollEvent::Method(_) => rolled = false,
+                RollEvent::Roll { purpose, .. } if purpose == ABILITY_SCORE_PURPOSE && !rolled => {
+                    rolled = true;
+                    rolled_sessions += 1;
+                }
+                _ => {}
+            }
+        }
+        let start = match self
+            .entries
+            .iter()
+            .rposition(|entry| matches!(entry.event, RollEvent::Method(_)))
+        {
+            Some(start) => start,
+            None => {
+                issues.push(RollLogIssue::NoAbilityScores);
+                return issues;
+            }
+        };
+        if rolled_sessions > 1 {
+            issues.push(RollLogIssue::Rerolled(rolled_sessions - 1));
+        }
+
+        let method = match self.entries[start].event {
+            RollEvent::Method(method) => method,
+            _ => unreachable!(),
+        };
+        let mut available: Vec<i8> = match method {
+            AbilityScoreMethod::StandardArray => STANDARD_ARRAY.to_vec(),
+            _ => Vec::new(),
+        };
+        let mut assigned: Vec<(AbilityName, i8)> = Vec::new();
+        for event in events.skip(start + 1) {
+            match event {
+                RollEvent::Roll { purpose, total, .. } if purpose == ABILITY_SCORE_PURPOSE => {
+                    available.push(*total as i8)
+                }
+                RollEvent::Assign { ability, score } => {
+                    assigned.retain(|(assigned, _)| assigned != ability);
+                    assigned.push((*ability, *score));
+                }
+                _ => {}
+            }
+        }
+
+        // point buy scores aren't rolled, so any legal purchase is fine
+        if method == AbilityScoreMethod::PointBuy {
+            let mut scores = AbilityScores::default();
+            for (ability, score) in &assigned {
+                scores.set(*ability, *score);
+            }
+            if PointBuy::from_scores(&scores, DEFAULT_BUDGET).is_none() {
+                issues.push(RollLogIssue::NotBought);
+            }
+        } else {
+            for (index, (ability, score)) in assigned.iter().enumerate() {
+                let rolled = match method.is_in_order() {
+                    true => available.get(index) == Some(score),
+                    false => match available.iter().position(|value| value == score) {
+                        Some(position) => {
+                            available.remove(position);
+                            true
+                        }
+                        None => false,
+                    },
+                };
+                if !rolled {
+                    issues.push(RollLogIssue::NotRolled {
+                        ability: *ability,
+                        score: *score,
+                    });
+                }
+            }
+        }
+
+        for ability in AbilityName::iter() {
+            let logged = assigned
+                .iter()
+                .find(|(assigned, _)| *assigned == ability)
+                .map(|(_, score)| *score);
+            let actual = base_ability_scores.get(ability);
+            if logged != Some(actual) {
+                issues.push(RollLogIssue::Edited {
+                    ability,
+                    logged,
+                    actual,
+                });
+            }
+        }
+
+        return issues;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Record a set of ability scores assigned in order, rolling them first if there are rolls.
+    fn record_scores(
+        log: &mut RollLog,
+        method: AbilityScoreMethod,
+        rolls: &[i32],
+        scores: [i8; 6],
+    ) -> AbilityScores {
+        log.record(RollEvent::Method(method));
+        for total in rolls {
+            log.record(RollEvent::Roll {
+                purpose: ABILITY_SCORE_PURPOSE.to_string(),
+                expression: "4d6kh3".to_string(),
+                dice: Vec::new(),
+                total: *total,
+            });
+        }
+        let mut ability_scores = AbilityScores::default();
+        for (ability, score) in AbilityName::iter().into_iter().zip(scores) {
+            log.record(RollEvent::Assign { ability, score });
+            ability_scores.set(ability, score);
+        }
+        return ability_scores;
+    }
+
+    fn rolled_log() -> (RollLog, AbilityScores) {
+        let mut log = RollLog::default();
+        let scores = record_scores(
+            &mut log,
+            AbilityScoreMethod::DropLowest,
+            &[12, 15, 9, 14, 10, 13],
+            [15, 14, 13, 12, 10, 9],
+        );
+        return (log, scores);
+    }
+
+    #[test]
+    fn untouched_log_checks_out() {
+        let (log, scores) = rolled_log();
+        assert_eq!(log.verify(&scores), vec![]);
+    }
+
+    #[test]
+    fn edited_entry_is_tampered() {
+        let (mut log, scores) = rolled_log();
+        log.entries[2].event = RollEvent::Roll {
+            purpose: ABILITY_SCORE_PURPOSE.to_string(),
+            expression: "4d6kh3".to_string(),
+            dice: Vec::new(),
+            total: 18,
+        };
+        let issues = log.verify(&scores);
+        assert!(issues.contains(&RollLogIssue::Tampered(2)));
+        assert!(!issues
+            .iter()
+            .any(|issue| matches!(issue, RollLogIssue::BrokenChain(_))));
+    }
+
+    #[test]
+    fn removed_entry_breaks_chain() {
+        let (mut log, scores) = rolled_log();
+        log.entries.remove(1);
+        let issues = log.verify(&scores);
+        assert!(issues.contains(&RollLogIssue::BrokenChain(1)));
+        assert!(!issues
+            .iter()
+            .any(|issue| matches!(issue, RollLogIssue::Tampered(_))));
+    }
+
+    #[test]
+    fn rolling_again_is_rerolled() {
+        let (mut log, _) = rolled_log();
+        let scores = record_scores(
+            &mut log,
+            AbilityScoreMethod::DropLowest,
+            &[17, 16, 15, 14, 13, 12],
+            [17, 16, 15, 14, 13, 12],
+        );
+        assert_eq!(log.verify(&scores), vec![RollLogIssue::Rerolled(1)]);
+    }
+
+    #[test]
+    fn revisiting_point_buy_is_not_a_reroll() {
+        let mut log = RollLog::default();
+        record_scores(
+            &mut log,
+            AbilityScoreMethod::PointBuy,
+            &[],
+            [15, 15, 15, 8, 8, 8],
+        );
+        let scores = record_scores(
+            &mut log,
+            AbilityScoreMethod::PointBuy,
+            &[],
+            [15, 14, 13, 12, 10, 8],
+        );
+        assert_eq!(log.verify(&scores), vec![]);
+    }
+
+    #[test]
+    fn overspent_point_buy_is_not_bought() {
+        let mut log = RollLog::default();
+        let scores = record_scores(
+            &mut log,
+            AbilityScoreMethod::PointBuy,
+            &[],
+            [15, 15, 15, 15, 8, 8],
+        );
+        assert_eq!(log.verify(&scores), vec![RollLogIssue::NotBought]);
+    }
+}
@@ -5,21 +5,22 @@ use rand::Rng;
 use std::{collections::HashMap, error::Error, io};
 use util::{EnumIter, EnumString};
 
-struct Spell {
-    name: String,
-    level: Level,
-    school: School,
-    casting_time: CastingTime,
-    range: Range,
-    components: Components,
-    duration: Duration,
-    description: String,
-    higher_level: Option<String>,
-    ritual: bool,
-    concentration: bool,
-    classes: Vec<AvailableClasses>,
-    tags: Vec<String>,
-    effect: Option<Effect>,
+#[derive(Clone, Debug)]
+pub struct Spell {
+    pub name: String,
+    pub level: Level,
+    pub school: School,
+    pub casting_time: CastingTime,
+    pub range: Range,
+    pub components: Components,
+    pub duration: Duration,
+    pub description: String,
+    pub higher_level: Option<String>,
+    pub ritual: bool,
+    pub concentration: bool,
+    pub classes: Vec<AvailableClasses>,
+    pub tags: Vec<String>,
+    pub effect: Option<Effect>,
 }
 
 impl Spell {
@@ -41,12 +42,16 @@ impl Spell {
             effect: None,
         };
     }
+
+    pub fn is_available_to(&self, class: AvailableClasses) -> bool {
+        return self.classes.contains(&class);
+    }
 }
 
 type Name = String;
 
-#[derive(Debug, Sequence, EnumIter)]
-enum School {
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Sequence, EnumIter, EnumString)]
+pub enum School {
     Abjuration,
     Conjuration,
     Divination,
@@ -57,8 +62,8 @@ enum School {
     Transmutation,
 }
 
-#[derive(Debug, Sequence, EnumIter)]
-enum CastingTime {
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Sequence, EnumIter, EnumString)]
+pub enum CastingTime {
     Action,
     BonusAction,
     Reaction,
@@ -66,7 +71,8 @@ enum CastingTime {
     Hour,
 }
 
-enum Range {
+#[derive(Clone, Debug)]
+pub enum Range {
     OnSelf,
     Touch,
     Feet(i32),
@@ -74,8 +80,8 @@ enum Range {
     Special,
 }
 
-#[derive(Debug, Sequence, EnumIter)]
-enum Level {
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Sequence, EnumIter, EnumString)]
+pub enum Level {
     Cantrip,
     Level1,
     Level2,
@@ -88,14 +94,35 @@ enum Level {
     Level9,
 }
 
-#[derive(Default)]
-struct Components {
-    verbal: bool,
-    somatic: bool,
-    material: Option<String>,
+impl Level {
+    /// Get the spell level for a number from 0 (cantrip) to 9.
+    pub fn from_number(number: u8) -> Level {
+        return Level::iter()
+            .into_iter()
+            .nth(number as usize)
+            .unwrap_or(Level::Level9);
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Components {
+    pub verbal: bool,
+    pub somatic: bool,
+    pub material: Option<String>,
+}
+
+impl Components {
+    fn new(verbal: bool, somatic: bool, material: Option<&str>) -> Components {
+        return Components {
+            verbal,
+            somatic,
+            material: material.map(|material| material.to_string()),
+        };
+    }
 }
 
-enum Duration {
+#[derive(Clone, Debug)]
+pub enum Duration {
     Concentration,
     Instantaneous,
     Rounds(i32),
@@ -104,7 +131,9 @@ enum Duration {
     Days(i32),
     Special,
 }
-enum Effect {
+
+#[derive(Copy, Clone, Debug)]
+pub enum Effect {
     Damage,
     Healing,
     Buff,
@@ -113,21 +142,1040 @@ enum Effect {
     Other,
 }
 
-fn get_spell_by_name(name: &Name) -> Spell {
-    let mut spell = Spell::new();
-    spell.name = name.to_string();
-    return spell;
+pub fn get_spell_by_name(name: &str) -> Option<Spell> {
+    return get_spells().remove(name);
 }
 
-fn get_spells() -> HashMap<Name, Spell> {
-    let mut spells = HashMap::new();
-    for school in School::iter() {
-        for level in Level::iter() {
-            let name: String = format!("{:?} {:?}", school, level);
-            let spell = get_spell_by_name(&name);
+/// Get the spells a class can learn, up to and including the given spell level, sorted by
+/// level and then by name.
+pub fn get_class_spells(class: AvailableClasses, max_level: Level) -> Vec<Spell> {
+    let mut spells = get_spells()
+        .into_values()
+        .filter(|spell| spell.is_available_to(class) && spell.level <= max_level)
+        .collect::<Vec<Spell>>();
+    spells.sort_by(|a, b| a.level.cmp(&b.level).then(a.name.cmp(&b.name)));
+    return spells;
+}
+
+/// The highest level of spell in the catalog. It only has cantrips and 1st-level spells so far,
+/// so characters who can cast higher-level spells learn spells of the levels it has.
+pub fn get_highest_spell_level() -> Level {
+    return get_spells()
+        .into_values()
+        .map(|spell| spell.level)
+        .max()
+        .unwrap_or(Level::Cantrip);
+}
 
-            spells.insert(name, spell);
-        }
+pub fn get_spells() -> HashMap<Name, Spell> {
+    let mut spells = HashMap::new();
+    for spell in cantrips().into_iter().chain(first_level_spells()) {
+        spells.insert(spell.name.clone(), spell);
     }
     return spells;
 }
+
+/// Cantrips from the System Reference Document and Player's Handbook.
+fn cantrips() -> Vec<Spell> {
+    use AvailableClasses::*;
+    return vec![
+        Spell {
+            name: "Acid Splash".to_string(),
+            school: School::Conjuration,
+            range: Range::Feet(60),
+            components: Components::new(true, true, None),
+            description: "Hurl a bubble of acid at one or two creatures within 5 feet of each other for 1d6 acid damage.".to_string(),
+            classes: vec![Sorcerer, Wizard],
+            effect: Some(Effect::Damage),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Chill Touch".to_string(),
+            school: School::Necromancy,
+            range: Range::Feet(120),
+            components: Components::new(true, true, None),
+            duration: Duration::Rounds(1),
+            description: "A ghostly hand deals 1d8 necrotic damage and prevents the target from regaining hit points.".to_string(),
+            classes: vec![Sorcerer, Warlock, Wizard],
+            effect: Some(Effect::Damage),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Dancing Lights".to_string(),
+            school: School::Evocation,
+            range: Range::Feet(120),
+            components: Components::new(true, true, Some("a bit of phosphorus or wychwood, or a glowworm")),
+            duration: Duration::Minutes(1),
+            description: "Create up to four torch-sized lights that hover in the air.".to_string(),
+            concentration: true,
+            classes: vec![Bard, Sorcerer, Wizard],
+            effect: Some(Effect::Other),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Druidcraft".to_string(),
+            school: School::Transmutation,
+            range: Range::Feet(30),
+            components: Components::new(true, true, None),
+            description: "Whisper to the spirits of nature to create a minor natural effect.".to_string(),
+            classes: vec![Druid],
+            effect: Some(Effect::Other),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Eldritch Blast".to_string(),
+            school: School::Evocation,
+            range: Range::Feet(120),
+            components: Components::new(true, true, None),
+            description: "A beam of crackling energy deals 1d10 force damage.".to_string(),
+            classes: vec![Warlock],
+            effect: Some(Effect::Damage),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Fire Bolt".to_string(),
+            school: School::Evocation,
+            range: Range::Feet(120),
+            components: Components::new(true, true, None),
+            description: "Hurl a mote of fire that deals 1d10 fire damage.".to_string(),
+            classes: vec![Sorcerer, Wizard],
+            effect: Some(Effect::Damage),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Guidance".to_string(),
+            school: School::Divination,
+            range: Range::Touch,
+            components: Components::new(true, true, None),
+            duration: Duration::Minutes(1),
+            description: "A willing creature can add 1d4 to one ability check of its choice.".to_string(),
+            concentration: true,
+            classes: vec![Cleric, Druid],
+            effect: Some(Effect::Buff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Light".to_string(),
+            school: School::Evocation,
+            range: Range::Touch,
+            components: Components::new(true, false, Some("a firefly or phosphorescent moss")),
+            duration: Duration::Hours(1),
+            description: "An object sheds bright light in a 20-foot radius.".to_string(),
+            classes: vec![Bard, Cleric, Sorcerer, Wizard],
+            effect: Some(Effect::Other),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Mage Hand".to_string(),
+            school: School::Conjuration,
+            range: Range::Feet(30),
+            components: Components::new(true, true, None),
+            duration: Duration::Minutes(1),
+            description: "A spectral, floating hand manipulates objects at a distance.".to_string(),
+            classes: vec![Bard, Sorcerer, Warlock, Wizard],
+            effect: Some(Effect::Other),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Mending".to_string(),
+            school: School::Transmutation,
+            casting_time: CastingTime::Minute,
+            range: Range::Touch,
+            components: Components::new(true, true, Some("two lodestones")),
+            description: "Repair a single break or tear in an object.".to_string(),
+            classes: vec![Bard, Cleric, Druid, Sorcerer, Wizard],
+            effect: Some(Effect::Other),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Message".to_string(),
+            school: School::Transmutation,
+            range: Range::Feet(120),
+            components: Components::new(true, true, Some("a short piece of copper wire")),
+            duration: Duration::Rounds(1),
+            description: "Whisper a message to a creature that only it can hear.".to_string(),
+            classes: vec![Bard, Sorcerer, Wizard],
+            effect: Some(Effect::Other),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Minor Illusion".to_string(),
+            school: School::Illusion,
+            range: Range::Feet(30),
+            components: Components::new(false, true, Some("a bit of fleece")),
+            duration: Duration::Minutes(1),
+            description: "Create a sound or an image of an object.".to_string(),
+            classes: vec![Bard, Sorcerer, Warlock, Wizard],
+            effect: Some(Effect::Other),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Poison Spray".to_string(),
+            school: School::Conjuration,
+            range: Range::Feet(10),
+            components: Components::new(true, true, None),
+            description: "Project a puff of noxious gas that deals 1d12 poison damage.".to_string(),
+            classes: vec![Druid, Sorcerer, Warlock, Wizard],
+            effect: Some(Effect::Damage),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Prestidigitation".to_string(),
+            school: School::Transmutation,
+            range: Range::Feet(10),
+            components: Components::new(true, true, None),
+            duration: Duration::Hours(1),
+            description: "Perform a minor magical trick.".to_string(),
+            classes: vec![Bard, Sorcerer, Warlock, Wizard],
+            effect: Some(Effect::Other),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Produce Flame".to_string(),
+            school: School::Conjuration,
+            range: Range::OnSelf,
+            components: Components::new(true, true, None),
+            duration: Duration::Minutes(10),
+            description: "A flickering flame sheds light and can be hurled for 1d8 fire damage.".to_string(),
+            classes: vec![Druid],
+            effect: Some(Effect::Damage),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Ray of Frost".to_string(),
+            school: School::Evocation,
+            range: Range::Feet(60),
+            components: Components::new(true, true, None),
+            description: "A frigid beam deals 1d8 cold damage and reduces the target's speed by 10 feet.".to_string(),
+            classes: vec![Sorcerer, Wizard],
+            effect: Some(Effect::Damage),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Resistance".to_string(),
+            school: School::Abjuration,
+            range: Range::Touch,
+            components: Components::new(true, true, Some("a miniature cloak")),
+            duration: Duration::Minutes(1),
+            description: "A willing creature can add 1d4 to one saving throw of its choice.".to_string(),
+            concentration: true,
+            classes: vec![Cleric, Druid],
+            effect: Some(Effect::Buff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Sacred Flame".to_string(),
+            school: School::Evocation,
+            range: Range::Feet(60),
+            components: Components::new(true, true, None),
+            description: "Flame-like radiance deals 1d8 radiant damage on a failed Dexterity save.".to_string(),
+            classes: vec![Cleric],
+            effect: Some(Effect::Damage),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Shillelagh".to_string(),
+            school: School::Transmutation,
+            casting_time: CastingTime::BonusAction,
+            range: Range::Touch,
+            components: Components::new(true, true, Some("mistletoe, a shamrock leaf, and a club or quarterstaff")),
+            duration: Duration::Minutes(1),
+            description: "Your club or quarterstaff uses your spellcasting ability and deals 1d8 damage.".to_string(),
+            classes: vec![Druid],
+            effect: Some(Effect::Buff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Shocking Grasp".to_string(),
+            school: School::Evocation,
+            range: Range::Touch,
+            components: Components::new(true, true, None),
+            description: "Lightning deals 1d8 lightning damage and the target can't take reactions.".to_string(),
+            classes: vec![Sorcerer, Wizard],
+            effect: Some(Effect::Damage),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Spare the Dying".to_string(),
+            school: School::Necromancy,
+            range: Range::Touch,
+            components: Components::new(true, true, None),
+            description: "A living creature with 0 hit points becomes stable.".to_string(),
+            classes: vec![Cleric],
+            effect: Some(Effect::Healing),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Thaumaturgy".to_string(),
+            school: School::Transmutation,
+            range: Range::Feet(30),
+            components: Components::new(true, false, None),
+            duration: Duration::Minutes(1),
+            description: "Manifest a minor wonder, a sign of supernatural power.".to_string(),
+            classes: vec![Cleric],
+            effect: Some(Effect::Other),
+            ..Spell::new()
+        },
+        Spell {
+            name: "True Strike".to_string(),
+            school: School::Divination,
+            range: Range::Feet(30),
+            components: Components::new(false, true, None),
+            duration: Duration::Rounds(1),
+            description: "Gain advantage on your first attack roll against the target on your next turn.".to_string(),
+            concentration: true,
+            classes: vec![Bard, Sorcerer, Warlock, Wizard],
+            effect: Some(Effect::Buff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Vicious Mockery".to_string(),
+            school: School::Enchantment,
+            range: Range::Feet(60),
+            components: Components::new(true, false, None),
+            description: "Insults laced with enchantment deal 1d4 psychic damage and impose disadvantage on the next attack.".to_string(),
+            classes: vec![Bard],
+            effect: Some(Effect::Damage),
+            ..Spell::new()
+        },
+    ];
+}
+
+/// 1st-level spells from the System Reference Document and Player's Handbook.
+fn first_level_spells() -> Vec<Spell> {
+    use AvailableClasses::*;
+    return vec![
+        Spell {
+            name: "Alarm".to_string(),
+            level: Level::Level1,
+            school: School::Abjuration,
+            casting_time: CastingTime::Minute,
+            range: Range::Feet(30),
+            components: Components::new(true, true, Some("a tiny bell and a piece of fine silver wire")),
+            duration: Duration::Hours(8),
+            description: "Set an alarm against unwanted intrusion.".to_string(),
+            ritual: true,
+            classes: vec![Ranger, Wizard],
+            effect: Some(Effect::Other),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Animal Friendship".to_string(),
+            level: Level::Level1,
+            school: School::Enchantment,
+            range: Range::Feet(30),
+            components: Components::new(true, true, Some("a morsel of food")),
+            duration: Duration::Hours(24),
+            description: "Convince a beast that you mean it no harm.".to_string(),
+            higher_level: Some("Affect one additional beast for each slot level above 1st.".to_string()),
+            classes: vec![Bard, Druid, Ranger],
+            effect: Some(Effect::Debuff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Armor of Agathys".to_string(),
+            level: Level::Level1,
+            school: School::Abjuration,
+            range: Range::OnSelf,
+            components: Components::new(true, true, Some("a cup of water")),
+            duration: Duration::Hours(1),
+            description: "Gain 5 temporary hit points and deal 5 cold damage to creatures that hit you.".to_string(),
+            higher_level: Some("Temporary hit points and damage increase by 5 for each slot level above 1st.".to_string()),
+            classes: vec![Warlock],
+            effect: Some(Effect::Buff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Bane".to_string(),
+            level: Level::Level1,
+            school: School::Enchantment,
+            range: Range::Feet(30),
+            components: Components::new(true, true, Some("a drop of blood")),
+            duration: Duration::Minutes(1),
+            description: "Up to three creatures subtract 1d4 from attack rolls and saving throws.".to_string(),
+            higher_level: Some("Target one additional creature for each slot level above 1st.".to_string()),
+            concentration: true,
+            classes: vec![Bard, Cleric],
+            effect: Some(Effect::Debuff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Bless".to_string(),
+            level: Level::Level1,
+            school: School::Enchantment,
+            range: Range::Feet(30),
+            components: Components::new(true, true, Some("a sprinkling of holy water")),
+            duration: Duration::Minutes(1),
+            description: "Up to three creatures add 1d4 to attack rolls and saving throws.".to_string(),
+            higher_level: Some("Target one additional creature for each slot level above 1st.".to_string()),
+            concentration: true,
+            classes: vec![Cleric, Paladin],
+            effect: Some(Effect::Buff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Burning Hands".to_string(),
+            level: Level::Level1,
+            school: School::Evocation,
+            range: Range::OnSelf,
+            components: Components::new(true, true, None),
+            description: "A 15-foot cone of flame deals 3d6 fire damage.".to_string(),
+            higher_level: Some("Damage increases by 1d6 for each slot level above 1st.".to_string()),
+            classes: vec![Sorcerer, Wizard],
+            effect: Some(Effect::Damage),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Charm Person".to_string(),
+            level: Level::Level1,
+            school: School::Enchantment,
+            range: Range::Feet(30),
+            components: Components::new(true, true, None),
+            duration: Duration::Hours(1),
+            description: "A humanoid regards you as a friendly acquaintance.".to_string(),
+            higher_level: Some("Target one additional creature for each slot level above 1st.".to_string()),
+            classes: vec![Bard, Druid, Sorcerer, Warlock, Wizard],
+            effect: Some(Effect::Debuff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Chromatic Orb".to_string(),
+            level: Level::Level1,
+            school: School::Evocation,
+            range: Range::Feet(90),
+            components: Components::new(true, true, Some("a diamond worth at least 50 gp")),
+            description: "Hurl a sphere of energy that deals 3d8 damage of a type you choose.".to_string(),
+            higher_level: Some("Damage increases by 1d8 for each slot level above 1st.".to_string()),
+            classes: vec![Sorcerer, Wizard],
+            effect: Some(Effect::Damage),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Color Spray".to_string(),
+            level: Level::Level1,
+            school: School::Illusion,
+            range: Range::OnSelf,
+            components: Components::new(true, true, Some("a pinch of powder or sand colored red, yellow, and blue")),
+            duration: Duration::Rounds(1),
+            description: "Dazzling colored light blinds creatures with a total of 6d10 hit points.".to_string(),
+            higher_level: Some("Roll an additional 2d10 for each slot level above 1st.".to_string()),
+            classes: vec![Sorcerer, Wizard],
+            effect: Some(Effect::Debuff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Command".to_string(),
+            level: Level::Level1,
+            school: School::Enchantment,
+            range: Range::Feet(60),
+            components: Components::new(true, false, None),
+            duration: Duration::Rounds(1),
+            description: "Speak a one-word command to a creature that it must obey.".to_string(),
+            higher_level: Some("Target one additional creature for each slot level above 1st.".to_string()),
+            classes: vec![Cleric, Paladin],
+            effect: Some(Effect::Debuff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Comprehend Languages".to_string(),
+            level: Level::Level1,
+            school: School::Divination,
+            range: Range::OnSelf,
+            components: Components::new(true, true, Some("a pinch of soot and salt")),
+            duration: Duration::Hours(1),
+            description: "Understand the literal meaning of any spoken or written language.".to_string(),
+            ritual: true,
+            classes: vec![Bard, Sorcerer, Warlock, Wizard],
+            effect: Some(Effect::Other),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Create or Destroy Water".to_string(),
+            level: Level::Level1,
+            school: School::Transmutation,
+            range: Range::Feet(30),
+            components: Components::new(true, true, Some("a drop of water or a few grains of sand")),
+            description: "Create or destroy up to 10 gallons of water.".to_string(),
+            higher_level: Some("Create or destroy 10 additional gallons for each slot level above 1st.".to_string()),
+            classes: vec![Cleric, Druid],
+            effect: Some(Effect::Other),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Cure Wounds".to_string(),
+            level: Level::Level1,
+            school: School::Evocation,
+            range: Range::Touch,
+            components: Components::new(true, true, None),
+            description: "A creature you touch regains 1d8 + your spellcasting modifier hit points.".to_string(),
+            higher_level: Some("Healing increases by 1d8 for each slot level above 1st.".to_string()),
+            classes: vec![Bard, Cleric, Druid, Paladin, Ranger],
+            effect: Some(Effect::Healing),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Detect Evil and Good".to_string(),
+            level: Level::Level1,
+            school: School::Divination,
+            range: Range::OnSelf,
+            components: Components::new(true, true, None),
+            duration: Duration::Minutes(10),
+            description: "Sense aberrations, celestials, elementals, fey, fiends and undead within 30 feet.".to_string(),
+            concentration: true,
+            classes: vec![Cleric, Paladin],
+            effect: Some(Effect::Other),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Detect Magic".to_string(),
+            level: Level::Level1,
+            school: School::Divination,
+            range: Range::OnSelf,
+            components: Components::new(true, true, None),
+            duration: Duration::Minutes(10),
+            description: "Sense the presence of magic within 30 feet.".to_string(),
+            ritual: true,
+            concentration: true,
+            classes: vec![Bard, Cleric, Druid, Paladin, Ranger, Sorcerer, Wizard],
+            effect: Some(Effect::Other),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Detect Poison and Disease".to_string(),
+            level: Level::Level1,
+            school: School::Divination,
+            range: Range::OnSelf,
+            components: Components::new(true, true, Some("a yew leaf")),
+            duration: Duration::Minutes(10),
+            description: "Sense poison, poisonous creatures and disease within 30 feet.".to_string(),
+            ritual: true,
+            concentration: true,
+            classes: vec![Cleric, Druid, Paladin, Ranger],
+            effect: Some(Effect::Other),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Disguise Self".to_string(),
+            level: Level::Level1,
+            school: School::Illusion,
+            range: Range::OnSelf,
+            components: Components::new(true, true, None),
+            duration: Duration::Hours(1),
+            description: "Make yourself look different until the spell ends.".to_string(),
+            classes: vec![Bard, Sorcerer, Wizard],
+            effect: Some(Effect::Other),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Dissonant Whispers".to_string(),
+            level: Level::Level1,
+            school: School::Enchantment,
+            range: Range::Feet(60),
+            components: Components::new(true, false, None),
+            description: "A discordant melody deals 3d6 psychic damage and forces the target to flee.".to_string(),
+            higher_level: Some("Damage increases by 1d6 for each slot level above 1st.".to_string()),
+            classes: vec![Bard],
+            effect: Some(Effect::Damage),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Divine Favor".to_string(),
+            level: Level::Level1,
+            school: School::Evocation,
+            casting_time: CastingTime::BonusAction,
+            range: Range::OnSelf,
+            components: Components::new(true, true, None),
+            duration: Duration::Minutes(1),
+            description: "Your weapon attacks deal an extra 1d4 radiant damage.".to_string(),
+            concentration: true,
+            classes: vec![Paladin],
+            effect: Some(Effect::Buff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Ensnaring Strike".to_string(),
+            level: Level::Level1,
+            school: School::Conjuration,
+            casting_time: CastingTime::BonusAction,
+            range: Range::OnSelf,
+            components: Components::new(true, false, None),
+            duration: Duration::Minutes(1),
+            description: "Your next weapon hit restrains the target with grasping vines.".to_string(),
+            higher_level: Some("Damage increases by 1d6 for each slot level above 1st.".to_string()),
+            concentration: true,
+            classes: vec![Ranger],
+            effect: Some(Effect::Debuff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Entangle".to_string(),
+            level: Level::Level1,
+            school: School::Conjuration,
+            range: Range::Feet(90),
+            components: Components::new(true, true, None),
+            duration: Duration::Minutes(1),
+            description: "Grasping weeds and vines restrain creatures in a 20-foot square.".to_string(),
+            concentration: true,
+            classes: vec![Druid],
+            effect: Some(Effect::Debuff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Expeditious Retreat".to_string(),
+            level: Level::Level1,
+            school: School::Transmutation,
+            casting_time: CastingTime::BonusAction,
+            range: Range::OnSelf,
+            components: Components::new(true, true, None),
+            duration: Duration::Minutes(10),
+            description: "Take the Dash action as a bonus action on each of your turns.".to_string(),
+            concentration: true,
+            classes: vec![Sorcerer, Warlock, Wizard],
+            effect: Some(Effect::Buff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Faerie Fire".to_string(),
+            level: Level::Level1,
+            school: School::Evocation,
+            range: Range::Feet(60),
+            components: Components::new(true, false, None),
+            duration: Duration::Minutes(1),
+            description: "Outline creatures in light, granting advantage on attacks against them.".to_string(),
+            concentration: true,
+            classes: vec![Bard, Druid],
+            effect: Some(Effect::Debuff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "False Life".to_string(),
+            level: Level::Level1,
+            school: School::Necromancy,
+            range: Range::OnSelf,
+            components: Components::new(true, true, Some("a small amount of alcohol or distilled spirits")),
+            duration: Duration::Hours(1),
+            description: "Gain 1d4 + 4 temporary hit points.".to_string(),
+            higher_level: Some("Gain 5 additional temporary hit points for each slot level above 1st.".to_string()),
+            classes: vec![Sorcerer, Wizard],
+            effect: Some(Effect::Buff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Feather Fall".to_string(),
+            level: Level::Level1,
+            school: School::Transmutation,
+            casting_time: CastingTime::Reaction,
+            range: Range::Feet(60),
+            components: Components::new(true, false, Some("a small feather or piece of down")),
+            duration: Duration::Minutes(1),
+            description: "Up to five falling creatures descend slowly and take no falling damage.".to_string(),
+            classes: vec![Bard, Sorcerer, Wizard],
+            effect: Some(Effect::Buff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Find Familiar".to_string(),
+            level: Level::Level1,
+            school: School::Conjuration,
+            casting_time: CastingTime::Hour,
+            range: Range::Feet(10),
+            components: Components::new(true, true, Some("10 gp worth of charcoal, incense, and herbs")),
+            description: "Gain the service of a familiar, a spirit that takes an animal form.".to_string(),
+            ritual: true,
+            classes: vec![Wizard],
+            effect: Some(Effect::Summon),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Fog Cloud".to_string(),
+            level: Level::Level1,
+            school: School::Conjuration,
+            range: Range::Feet(120),
+            components: Components::new(true, true, None),
+            duration: Duration::Hours(1),
+            description: "Create a 20-foot-radius sphere of heavily obscuring fog.".to_string(),
+            higher_level: Some("The radius increases by 20 feet for each slot level above 1st.".to_string()),
+            concentration: true,
+            classes: vec![Druid, Ranger, Sorcerer, Wizard],
+            effect: Some(Effect::Other),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Goodberry".to_string(),
+            level: Level::Level1,
+            school: School::Transmutation,
+            range: Range::Touch,
+            components: Components::new(true, true, Some("a sprig of mistletoe")),
+            description: "Create ten berries that each restore 1 hit point and provide a day's nourishment.".to_string(),
+            classes: vec![Druid, Ranger],
+            effect: Some(Effect::Healing),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Grease".to_string(),
+            level: Level::Level1,
+            school: School::Conjuration,
+            range: Range::Feet(60),
+            components: Components::new(true, true, Some("a bit of pork rind or butter")),
+            duration: Duration::Minutes(1),
+            description: "Slick grease covers a 10-foot square, making it difficult terrain.".to_string(),
+            classes: vec![Wizard],
+            effect: Some(Effect::Debuff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Guiding Bolt".to_string(),
+            level: Level::Level1,
+            school: School::Evocation,
+            range: Range::Feet(120),
+            components: Components::new(true, true, None),
+            duration: Duration::Rounds(1),
+            description: "A flash of light deals 4d6 radiant damage and grants advantage on the next attack.".to_string(),
+            higher_level: Some("Damage increases by 1d6 for each slot level above 1st.".to_string()),
+            classes: vec![Cleric],
+            effect: Some(Effect::Damage),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Healing Word".to_string(),
+            level: Level::Level1,
+            school: School::Evocation,
+            casting_time: CastingTime::BonusAction,
+            range: Range::Feet(60),
+            components: Components::new(true, false, None),
+            description: "A creature you can see regains 1d4 + your spellcasting modifier hit points.".to_string(),
+            higher_level: Some("Healing increases by 1d4 for each slot level above 1st.".to_string()),
+            classes: vec![Bard, Cleric, Druid],
+            effect: Some(Effect::Healing),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Hellish Rebuke".to_string(),
+            level: Level::Level1,
+            school: School::Evocation,
+            casting_time: CastingTime::Reaction,
+            range: Range::Feet(60),
+            components: Components::new(true, true, None),
+            description: "The creature that damaged you is wreathed in flames for 2d10 fire damage.".to_string(),
+            higher_level: Some("Damage increases by 1d10 for each slot level above 1st.".to_string()),
+            classes: vec![Warlock],
+            effect: Some(Effect::Damage),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Heroism".to_string(),
+            level: Level::Level1,
+            school: School::Enchantment,
+            range: Range::Touch,
+            components: Components::new(true, true, None),
+            duration: Duration::Minutes(1),
+            description: "A willing creature is immune to being frightened and gains temporary hit points each turn.".to_string(),
+            higher_level: Some("Target one additional creature for each slot level above 1st.".to_string()),
+            concentration: true,
+            classes: vec![Bard, Paladin],
+            effect: Some(Effect::Buff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Hex".to_string(),
+            level: Level::Level1,
+            school: School::Enchantment,
+            casting_time: CastingTime::BonusAction,
+            range: Range::Feet(90),
+            components: Components::new(true, true, Some("the petrified eye of a newt")),
+            duration: Duration::Hours(1),
+            description: "Curse a creature to take an extra 1d6 necrotic damage from your attacks.".to_string(),
+            concentration: true,
+            classes: vec![Warlock],
+            effect: Some(Effect::Debuff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Hideous Laughter".to_string(),
+            level: Level::Level1,
+            school: School::Enchantment,
+            range: Range::Feet(30),
+            components: Components::new(true, true, Some("tiny tarts and a feather that is waved in the air")),
+            duration: Duration::Minutes(1),
+            description: "A creature falls prone in fits of laughter and becomes incapacitated.".to_string(),
+            concentration: true,
+            classes: vec![Bard, Wizard],
+            effect: Some(Effect::Debuff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Hunter's Mark".to_string(),
+            level: Level::Level1,
+            school: School::Divination,
+            casting_time: CastingTime::BonusAction,
+            range: Range::Feet(90),
+            components: Components::new(true, false, None),
+            duration: Duration::Hours(1),
+            description: "Mark a creature to deal an extra 1d6 damage to it with weapon attacks.".to_string(),
+            concentration: true,
+            classes: vec![Ranger],
+            effect: Some(Effect::Buff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Identify".to_string(),
+            level: Level::Level1,
+            school: School::Divination,
+            casting_time: CastingTime::Minute,
+            range: Range::Touch,
+            components: Components::new(true, true, Some("a pearl worth at least 100 gp and an owl feather")),
+            description: "Learn the properties of a magic item or the spells affecting it.".to_string(),
+            ritual: true,
+            classes: vec![Bard, Wizard],
+            effect: Some(Effect::Other),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Illusory Script".to_string(),
+            level: Level::Level1,
+            school: School::Illusion,
+            casting_time: CastingTime::Minute,
+            range: Range::Touch,
+            components: Components::new(false, true, Some("a lead-based ink worth at least 10 gp")),
+            duration: Duration::Days(10),
+            description: "Write a message that only designated creatures can read.".to_string(),
+            ritual: true,
+            classes: vec![Bard, Warlock, Wizard],
+            effect: Some(Effect::Other),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Inflict Wounds".to_string(),
+            level: Level::Level1,
+            school: School::Necromancy,
+            range: Range::Touch,
+            components: Components::new(true, true, None),
+            description: "A melee spell attack deals 3d10 necrotic damage.".to_string(),
+            higher_level: Some("Damage increases by 1d10 for each slot level above 1st.".to_string()),
+            classes: vec![Cleric],
+            effect: Some(Effect::Damage),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Jump".to_string(),
+            level: Level::Level1,
+            school: School::Transmutation,
+            range: Range::Touch,
+            components: Components::new(true, true, Some("a grasshopper's hind leg")),
+            duration: Duration::Minutes(1),
+            description: "Triple a creature's jump distance.".to_string(),
+            classes: vec![Druid, Ranger, Sorcerer, Wizard],
+            effect: Some(Effect::Buff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Longstrider".to_string(),
+            level: Level::Level1,
+            school: School::Transmutation,
+            range: Range::Touch,
+            components: Components::new(true, true, Some("a pinch of dirt")),
+            duration: Duration::Hours(1),
+            description: "A creature's speed increases by 10 feet.".to_string(),
+            higher_level: Some("Target one additional creature for each slot level above 1st.".to_string()),
+            classes: vec![Bard, Druid, Ranger, Wizard],
+            effect: Some(Effect::Buff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Mage Armor".to_string(),
+            level: Level::Level1,
+            school: School::Abjuration,
+            range: Range::Touch,
+            components: Components::new(true, true, Some("a piece of cured leather")),
+            duration: Duration::Hours(8),
+            description: "An unarmored creature's base AC becomes 13 + its Dexterity modifier.".to_string(),
+            classes: vec![Sorcerer, Wizard],
+            effect: Some(Effect::Buff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Magic Missile".to_string(),
+            level: Level::Level1,
+            school: School::Evocation,
+            range: Range::Feet(120),
+            components: Components::new(true, true, None),
+            description: "Three glowing darts each deal 1d4 + 1 force damage.".to_string(),
+            higher_level: Some("Create one additional dart for each slot level above 1st.".to_string()),
+            classes: vec![Sorcerer, Wizard],
+            effect: Some(Effect::Damage),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Protection from Evil and Good".to_string(),
+            level: Level::Level1,
+            school: School::Abjuration,
+            range: Range::Touch,
+            components: Components::new(true, true, Some("holy water or powdered silver and iron")),
+            duration: Duration::Minutes(10),
+            description: "Protect a creature against aberrations, celestials, elementals, fey, fiends and undead.".to_string(),
+            concentration: true,
+            classes: vec![Cleric, Paladin, Warlock, Wizard],
+            effect: Some(Effect::Buff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Purify Food and Drink".to_string(),
+            level: Level::Level1,
+            school: School::Transmutation,
+            range: Range::Feet(10),
+            components: Components::new(true, true, None),
+            description: "Remove poison and disease from food and drink in a 5-foot sphere.".to_string(),
+            ritual: true,
+            classes: vec![Cleric, Druid, Paladin],
+            effect: Some(Effect::Other),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Sanctuary".to_string(),
+            level: Level::Level1,
+            school: School::Abjuration,
+            casting_time: CastingTime::BonusAction,
+            range: Range::Feet(30),
+            components: Components::new(true, true, Some("a small silver mirror")),
+            duration: Duration::Minutes(1),
+            description: "Creatures must make a Wisdom save to attack the warded creature.".to_string(),
+            classes: vec![Cleric],
+            effect: Some(Effect::Buff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Searing Smite".to_string(),
+            level: Level::Level1,
+            school: School::Evocation,
+            casting_time: CastingTime::BonusAction,
+            range: Range::OnSelf,
+            components: Components::new(true, false, None),
+            duration: Duration::Minutes(1),
+            description: "Your next weapon hit deals an extra 1d6 fire damage and sets the target ablaze.".to_string(),
+            higher_level: Some("Damage increases by 1d6 for each slot level above 1st.".to_string()),
+            concentration: true,
+            classes: vec![Paladin],
+            effect: Some(Effect::Damage),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Shield".to_string(),
+            level: Level::Level1,
+            school: School::Abjuration,
+            casting_time: CastingTime::Reaction,
+            range: Range::OnSelf,
+            components: Components::new(true, true, None),
+            duration: Duration::Rounds(1),
+            description: "Gain +5 AC until the start of your next turn.".to_string(),
+            classes: vec![Sorcerer, Wizard],
+            effect: Some(Effect::Buff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Shield of Faith".to_string(),
+            level: Level::Level1,
+            school: School::Abjuration,
+            casting_time: CastingTime::BonusAction,
+            range: Range::Feet(60),
+            components: Components::new(true, true, Some("a small parchment with a bit of holy text written on it")),
+            duration: Duration::Minutes(10),
+            description: "A creature gains +2 AC for the duration.".to_string(),
+            concentration: true,
+            classes: vec![Cleric, Paladin],
+            effect: Some(Effect::Buff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Silent Image".to_string(),
+            level: Level::Level1,
+            school: School::Illusion,
+            range: Range::Feet(60),
+            components: Components::new(true, true, Some("a bit of fleece")),
+            duration: Duration::Minutes(10),
+            description: "Create the image of an object, creature or visible phenomenon.".to_string(),
+            concentration: true,
+            classes: vec![Bard, Sorcerer, Wizard],
+            effect: Some(Effect::Other),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Sleep".to_string(),
+            level: Level::Level1,
+            school: School::Enchantment,
+            range: Range::Feet(90),
+            components: Components::new(true, true, Some("a pinch of fine sand, rose petals, or a cricket")),
+            duration: Duration::Minutes(1),
+            description: "Creatures with a total of 5d8 hit points fall unconscious.".to_string(),
+            higher_level: Some("Roll an additional 2d8 for each slot level above 1st.".to_string()),
+            classes: vec![Bard, Sorcerer, Wizard],
+            effect: Some(Effect::Debuff),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Speak with Animals".to_string(),
+            level: Level::Level1,
+            school: School::Divination,
+            range: Range::OnSelf,
+            components: Components::new(true, true, None),
+            duration: Duration::Minutes(10),
+            description: "Comprehend and verbally communicate with beasts.".to_string(),
+            ritual: true,
+            classes: vec![Bard, Druid, Ranger],
+            effect: Some(Effect::Other),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Thunderous Smite".to_string(),
+            level: Level::Level1,
+            school: School::Evocation,
+            casting_time: CastingTime::BonusAction,
+            range: Range::OnSelf,
+            components: Components::new(true, false, None),
+            duration: Duration::Minutes(1),
+            description: "Your next weapon hit deals an extra 2d6 thunder damage and pushes the target.".to_string(),
+            concentration: true,
+            classes: vec![Paladin],
+            effect: Some(Effect::Damage),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Thunderwave".to_string(),
+            level: Level::Level1,
+            school: School::Evocation,
+            range: Range::OnSelf,
+            components: Components::new(true, true, None),
+            description: "A wave of force deals 2d8 thunder damage and pushes creatures away.".to_string(),
+            higher_level: Some("Damage increases by 1d8 for each slot level above 1st.".to_string()),
+            classes: vec![Bard, Druid, Sorcerer, Wizard],
+            effect: Some(Effect::Damage),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Unseen Servant".to_string(),
+            level: Level::Level1,
+            school: School::Conjuration,
+            range: Range::Feet(60),
+            components: Components::new(true, true, Some("a piece of string and a bit of wood")),
+            duration: Duration::Hours(1),
+            description: "Create an invisible, mindless force that performs simple tasks.".to_string(),
+            ritual: true,
+            classes: vec![Bard, Warlock, Wizard],
+            effect: Some(Effect::Summon),
+            ..Spell::new()
+        },
+        Spell {
+            name: "Witch Bolt".to_string(),
+            level: Level::Level1,
+            school: School::Evocation,
+            range: Range::Feet(30),
+            components: Components::new(true, true, Some("a twig from a tree that has been struck by lightning")),
+            duration: Duration::Minutes(1),
+            description: "A beam of crackling energy deals 1d12 lightning damage each turn.".to_string(),
+            higher_level: Some("Initial damage increases by 1d12 for each slot level above 1st.".to_string()),
+            concentration: true,
+            classes: vec![Sorcerer, Warlock, Wizard],
+            effect: Some(Effect::Damage),
+            ..Spell::new()
+        },
+    ];
+}
@@ -0,0 +1,200 @@
+use super::*;
+use character::CharacterSheet;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::{error::Error, fmt, fs, io, path::Path};
+
+/// The version of the save file format written by this build. Bump this whenever a change to
+/// `CharacterSheet` can't be read by older builds, and migrate older versions in `from_json`.
+pub const SAVE_FORMAT_VERSION: u32 = 3;
+
+#[derive(Serialize, Deserialize)]
+struct SaveFile<C> {
+    version: u32,
+    character: C,
+}
+
+#[derive(Debug)]
+pub enum StorageError {
+    Io(io::Error),
+    Json(serde_json::Error),
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StorageError::Io(error) => write!(f, "{}", error),
+            StorageError::Json(error) => write!(f, "invalid character file: {}", error),
+            StorageError::UnsupportedVersion(version) => write!(
+                f,
+                "character file version {} is newer than the supported version {}",
+                version, SAVE_FORMAT_VERSION
+            ),
+        }
+    }
+}
+
+impl Error for StorageError {}
+
+impl From<io::Error> for StorageError {
+    fn from(error: io::Error) -> Self {
+        return StorageError::Io(error);
+    }
+}
+
+impl From<serde_json::Error> for StorageError {
+    fn from(error: serde_json::Error) -> Self {
+        return StorageError::Json(error);
+    }
+}
+
+pub fn to_json(character_sheet: &CharacterSheet) -> Result<String, StorageError> {
+    let save_file = SaveFile {
+        version: SAVE_FORMAT_VERSION,
+        character: character_sheet.clone(),
+    };
+    let mut json = serde_json::to_string_pretty(&save_file)?;
+    json.push('\n');
+    return Ok(json);
+}
+
+pub fn from_json(json: &str) -> Result<CharacterSheet, StorageError> {
+    let save_file: SaveFile<Value> = serde_json::from_str(json)?;
+    if save_file.version > SAVE_FORMAT_VERSION {
+        return Err(StorageError::UnsupportedVersion(save_file.version));
+    }
+
+    let mut character = save_file.character;
+    migrate(save_file.version, &mut character);
+    let mut character_sheet: CharacterSheet = serde_json::from_value(character)?;
+    character_sheet.recompute();
+    return Ok(character_sheet);
+}
+
+/// Bring a character saved by an older version of the format up to date.
+fn migrate(version: u32, character: &mut Value) {
+    // version 1 kept equipment as a list of names rather than an inventory of items
+    if version < 2 {
+        if let Some(Value::Array(equipment)) = character.get_mut("equipment").map(Value::take) {
+            let items = equipment
+                .into_iter()
+                .filter_map(|name| match name {
+                    Value::String(name) => Some(json!({"item": {"Gear": name}, "quantity": 1})),
+                    _ => None,
+                })
+                .collect::<Vec<Value>>();
+            character["inventory"] = json!({ "items": items });
+        }
+    }
+
+    // version 2 had a single class and level rather than a level in each class
+    if version < 3 {
+        if let Some(class) = character.get_mut("class").map(Value::take) {
+            let level = match character.get_mut("level").map(Value::take) {
+                Some(Value::Number(level)) => Value::Number(level),
+                _ => json!(1),
+            };
+            character["classes"] = json!([{ "class": class, "level": level }]);
+        }
+    }
+}
+
+pub fn save_character<P: AsRef<Path>>(
+    character_sheet: &CharacterSheet,
+    path: P,
+) -> Result<(), StorageError> {
+    fs::write(path, to_json(character_sheet)?)?;
+    return Ok(());
+}
+
+pub fn load_character<P: AsRef<Path>>(path: P) -> Result<CharacterSheet, StorageError> {
+    return from_json(&fs::read_to_string(path)?);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use classes::AvailableClasses;
+
+    const VERSION_1: &str = r#"{
+        "version": 1,
+        "character": {
+            "name": "Tordek",
+            "race": "Dwarf",
+            "subrace": "HillDwarf",
+            "class": "Fighter",
+            "level": 3,
+            "equipment": ["Backpack", "Bedroll"]
+        }
+    }"#;
+
+    const VERSION_2: &str = r#"{
+        "version": 2,
+        "character": {
+            "name": "Tordek",
+            "race": "Dwarf",
+            "subrace": "HillDwarf",
+            "class": "Fighter",
+            "level": 3,
+            "inventory": {
+                "items": [
+                    {"item": {"Gear": "Backpack"}, "quantity": 1},
+                    {"item": {"Gear": "Bedroll"}, "quantity": 1}
+                ]
+            }
+        }
+    }"#;
+
+    const VERSION_3: &str = r#"{
+        "version": 3,
+        "character": {
+            "name": "Tordek",
+            "race": "Dwarf",
+            "subrace": "HillDwarf",
+            "classes": [{"class": "Fighter", "level": 3}],
+            "inventory": {
+                "items": [
+                    {"item": {"Gear": "Backpack"}, "quantity": 1},
+                    {"item": {"Gear": "Bedroll"}, "quantity": 1}
+                ]
+            }
+        }
+    }"#;
+
+    fn resave(json: &str) -> String {
+        return to_json(&from_json(json).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn migrates_version_1() {
+        assert_eq!(resave(VERSION_1), resave(VERSION_3));
+    }
+
+    #[test]
+    fn migrates_version_2() {
+        assert_eq!(resave(VERSION_2), resave(VERSION_3));
+    }
+
+    #[test]
+    fn migrated_character_keeps_its_levels() {
+        let character_sheet = from_json(VERSION_1).unwrap();
+        assert_eq!(
+            character_sheet.get_class_level(AvailableClasses::Fighter),
+            3
+        );
+        assert_eq!(character_sheet.inventory.items.len(), 2);
+    }
+
+    #[test]
+    fn rejects_newer_versions() {
+        let json = VERSION_3.replace(
+            "\"version\": 3",
+            &format!("\"version\": {}", SAVE_FORMAT_VERSION + 1),
+        );
+        assert!(matches!(
+            from_json(&json),
+            Err(StorageError::UnsupportedVersion(version)) if version == SAVE_FORMAT_VERSION + 1
+        ));
+    }
+}